@@ -1,10 +1,19 @@
-use crate::ComparableVersion;
+use crate::{ComparableVersion, Qualifier, VersionItem, VersionRange};
 use std::cmp::Ordering;
+use std::error::Error;
 use std::fmt;
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
+use std::ops::Range;
 use std::str::FromStr;
 
+/// The major, minor and incremental versions, build number and qualifier of an ArtifactVersion,
+/// along with where each [VersionComponent] is in the string.
+type Components = (u64, u64, u64, u64, Option<String>, Spans);
+
+/// The byte range of each [VersionComponent] in the string, indexed by the component.
+type Spans = [Option<Range<usize>>; 4];
+
 /// This is an implementation of Maven's DefaultArtifactVersion.
 ///
 /// ArtifactVersion parses a version into major, minor, incremental, build, and qualifier
@@ -17,11 +26,13 @@ use std::str::FromStr;
 /// comparison and equality. It's used here internally for comparison operations.
 #[derive(Debug, Eq, Clone)]
 pub struct ArtifactVersion {
-    major: u32,
-    minor: u32,
-    incremental: u32,
-    build: u32,
+    major: u64,
+    minor: u64,
+    incremental: u64,
+    build: u64,
     qualifier: Option<String>,
+    fallback: Option<ArtifactVersionParseError>,
+    spans: Spans,
     comparable: ComparableVersion,
 }
 
@@ -41,7 +52,9 @@ impl<'de> serde::Deserialize<'de> for ArtifactVersion {
     where
         D: serde::Deserializer<'de>,
     {
-        Ok(Self::new(&<String as serde::Deserialize>::deserialize(deserializer)?))
+        Ok(Self::new_owned(
+            <String as serde::Deserialize>::deserialize(deserializer)?,
+        ))
     }
 }
 
@@ -52,6 +65,114 @@ impl ArtifactVersion {
     /// any other text. A empty string will parse as 0.0.0.0-, with an empty string in the qualifier
     /// section.
     pub fn new(s: &str) -> Self {
+        Self::new_owned(s.to_string())
+    }
+
+    /// Constructs an ArtifactVersion from the given string like [`Self::new`], but takes ownership
+    /// of the string instead of copying it.
+    pub fn new_owned(s: String) -> Self {
+        Self::from_comparable(
+            ComparableVersion::new_owned(s),
+            LeadingZeroPolicy::MavenStrict,
+        )
+    }
+
+    /// Constructs an ArtifactVersion from the given string like [`Self::new`], treating numeric
+    /// components with leading zeroes according to the given policy.
+    ///
+    /// ```
+    /// # use mvn_version::{ArtifactVersion, LeadingZeroPolicy};
+    /// let version = ArtifactVersion::new_with_policy("0.09.1", LeadingZeroPolicy::Lenient);
+    /// assert_eq!((version.major(), version.minor(), version.incremental()), (0, 9, 1));
+    /// assert!(version.is_structured());
+    ///
+    /// let version = ArtifactVersion::new_with_policy("0.09.1", LeadingZeroPolicy::MavenStrict);
+    /// assert_eq!(version.qualifier().as_deref(), Some("0.09.1"));
+    /// ```
+    pub fn new_with_policy(s: &str, policy: LeadingZeroPolicy) -> Self {
+        Self::from_comparable(ComparableVersion::new(s), policy)
+    }
+
+    /// Breaks down the string of an already parsed ComparableVersion into components.
+    fn from_comparable(comparable: ComparableVersion, policy: LeadingZeroPolicy) -> Self {
+        let s = comparable.as_str();
+        let ((major, minor, incremental, build, qualifier, spans), fallback) =
+            match Self::parse_components(s, policy) {
+                Ok(components) => (components, None),
+                Err(e) => {
+                    let spans = [None, None, None, Some(0..s.len())];
+                    ((0, 0, 0, 0, Some(s.to_string()), spans), Some(e))
+                }
+            };
+
+        ArtifactVersion {
+            major,
+            minor,
+            incremental,
+            build,
+            qualifier,
+            fallback,
+            spans,
+            comparable,
+        }
+    }
+
+    /// Constructs an ArtifactVersion from the given string, failing instead of falling back to
+    /// using the whole string as the qualifier when it isn't of the form
+    /// `major[.minor[.incremental]][-build|-qualifier]` or
+    /// `major.minor.incremental.qualifier`.
+    ///
+    /// ```
+    /// # use mvn_version::{ArtifactVersion, ArtifactVersionParseErrorKind, VersionComponent};
+    /// let version = ArtifactVersion::try_new("1.2.3-beta-1").unwrap();
+    /// assert_eq!(version.qualifier().as_deref(), Some("beta-1"));
+    ///
+    /// let error = ArtifactVersion::try_new("1.0RC2").unwrap_err();
+    /// assert_eq!(error.kind(), ArtifactVersionParseErrorKind::InvalidCharacter('R'));
+    /// assert_eq!(error.component(), VersionComponent::Minor);
+    /// assert_eq!(error.offset(), 3);
+    /// ```
+    pub fn try_new(s: &str) -> Result<Self, ArtifactVersionParseError> {
+        Self::try_new_with_policy(s, LeadingZeroPolicy::MavenStrict)
+    }
+
+    /// Constructs an ArtifactVersion from the given string like [`Self::try_new`], treating
+    /// numeric components with leading zeroes according to the given policy.
+    ///
+    /// ```
+    /// # use mvn_version::{ArtifactVersion, ArtifactVersionParseErrorKind, LeadingZeroPolicy};
+    /// let version = ArtifactVersion::try_new_with_policy("02-01", LeadingZeroPolicy::Lenient);
+    /// assert_eq!(version.map(|v| (v.major(), v.build())), Ok((2, 1)));
+    ///
+    /// let error = ArtifactVersion::try_new_with_policy("02-01", LeadingZeroPolicy::MavenStrict);
+    /// assert_eq!(error.unwrap_err().kind(), ArtifactVersionParseErrorKind::LeadingZero);
+    /// ```
+    pub fn try_new_with_policy(
+        s: &str,
+        policy: LeadingZeroPolicy,
+    ) -> Result<Self, ArtifactVersionParseError> {
+        let (major, minor, incremental, build, qualifier, spans) =
+            Self::parse_components(s, policy)?;
+
+        Ok(ArtifactVersion {
+            major,
+            minor,
+            incremental,
+            build,
+            qualifier,
+            fallback: None,
+            spans,
+            comparable: ComparableVersion::new(s),
+        })
+    }
+
+    /// Parses the major, minor and incremental versions, build number and qualifier, failing
+    /// where Maven would fall back to using the whole string as the qualifier, or where the policy
+    /// says to.
+    fn parse_components(
+        s: &str,
+        policy: LeadingZeroPolicy,
+    ) -> Result<Components, ArtifactVersionParseError> {
         // Which section we are in currently
         #[derive(PartialEq, Eq, Clone, Copy)]
         enum Section {
@@ -62,13 +183,57 @@ impl ArtifactVersion {
             DottedQualifier,
         }
 
-        let fallback = || ArtifactVersion {
-            major: 0,
-            minor: 0,
-            incremental: 0,
-            build: 0,
-            qualifier: Some(s.to_string()),
-            comparable: ComparableVersion::new(s),
+        impl Section {
+            fn component(self) -> VersionComponent {
+                match self {
+                    Section::Major => VersionComponent::Major,
+                    Section::Minor => VersionComponent::Minor,
+                    Section::Incremental => VersionComponent::Incremental,
+                    Section::BuildOrQualifier | Section::DottedQualifier => {
+                        VersionComponent::Qualifier
+                    }
+                }
+            }
+        }
+
+        let error = |kind, section: Section, offset| {
+            ArtifactVersionParseError::new(kind, section.component(), offset)
+        };
+
+        let mut spans = Spans::default();
+
+        // Parses a numeric component, which is known to consist of digits only
+        let mut number = |section: Section, start: usize, end: usize| {
+            spans[section.component() as usize] = Some(start..end);
+
+            if start == end {
+                Err(error(
+                    ArtifactVersionParseErrorKind::EmptyComponent,
+                    section,
+                    start,
+                ))
+            } else {
+                s[start..end].parse::<u64>().map_err(|_| {
+                    error(
+                        ArtifactVersionParseErrorKind::NumberTooLarge,
+                        section,
+                        start,
+                    )
+                })
+            }
+        };
+
+        // Maven refuses a major version with leading zeroes, and even a lone zero
+        let leading_zero = |start_index: usize| {
+            if policy == LeadingZeroPolicy::MavenStrict && s[start_index..].starts_with('0') {
+                Err(error(
+                    ArtifactVersionParseErrorKind::LeadingZero,
+                    Section::Major,
+                    start_index,
+                ))
+            } else {
+                Ok(())
+            }
         };
 
         let mut major = 0;
@@ -90,137 +255,94 @@ impl ArtifactVersion {
                     && c == b'.'
                     && last == b'.')
             {
-                return fallback();
+                return Err(error(
+                    ArtifactVersionParseErrorKind::EmptyComponent,
+                    section,
+                    i,
+                ));
             }
 
             match (section, c) {
                 (Section::Major, b'.') => {
+                    leading_zero(start_index)?;
+                    major = number(section, start_index, i)?;
                     section = Section::Minor;
-
-                    if s.bytes().nth(start_index).unwrap_or(b'_') == b'0' {
-                        qualifier = Some(s[start_index..s.len()].to_string());
-                        return ArtifactVersion {
-                            major,
-                            minor,
-                            incremental,
-                            build,
-                            qualifier,
-                            comparable: ComparableVersion::new(s),
-                        };
-                    }
-
-                    if let Ok(i) = &s[start_index..i].parse::<i32>() {
-                        major = *i as u32;
-                    } else {
-                        return fallback();
-                    }
-
                     start_index = i + 1;
                 }
                 (Section::Minor, b'.') => {
+                    minor = number(section, start_index, i)?;
                     section = Section::Incremental;
-
-                    if let Ok(i) = &s[start_index..i].parse::<i32>() {
-                        minor = *i as u32;
-                    } else {
-                        return fallback();
-                    }
-
                     start_index = i + 1;
                 }
                 (Section::Incremental, b'.') => {
+                    incremental = number(section, start_index, i)?;
                     section = Section::DottedQualifier;
-
-                    if let Ok(i) = &s[start_index..i].parse::<i32>() {
-                        incremental = *i as u32;
-                    } else {
-                        return fallback();
-                    }
-
                     start_index = i + 1;
                 }
                 (Section::Major, b'-') | (Section::Minor, b'-') | (Section::Incremental, b'-') => {
-                    if section == Section::Major
-                        && s.bytes().nth(start_index).unwrap_or(b'_') == b'0'
-                    {
-                        qualifier = Some(s[start_index..s.len()].to_string());
-                        return ArtifactVersion {
-                            major,
-                            minor,
-                            incremental,
-                            build,
-                            qualifier,
-                            comparable: ComparableVersion::new(s),
-                        };
+                    if section == Section::Major {
+                        leading_zero(start_index)?;
                     }
 
-                    if let Ok(i) = &s[start_index..i].parse::<i32>() {
-                        match section {
-                            Section::Major => major = *i as u32,
-                            Section::Minor => minor = *i as u32,
-                            Section::Incremental => incremental = *i as u32,
-                            _ => unreachable!(),
-                        }
-                    } else {
-                        return fallback();
+                    let n = number(section, start_index, i)?;
+                    match section {
+                        Section::Major => major = n,
+                        Section::Minor => minor = n,
+                        Section::Incremental => incremental = n,
+                        _ => unreachable!(),
                     }
 
                     section = Section::BuildOrQualifier;
                     start_index = i + 1;
                 }
                 (Section::Major, _) | (Section::Minor, _) | (Section::Incremental, _)
-                    if c < b'0' && c > b'9' =>
+                    if !c.is_ascii_digit() =>
                 {
-                    return fallback();
+                    let c = s[i..].chars().next().unwrap();
+                    return Err(error(
+                        ArtifactVersionParseErrorKind::InvalidCharacter(c),
+                        section,
+                        i,
+                    ));
+                }
+                (Section::DottedQualifier, b'-') => {
+                    return Err(error(
+                        ArtifactVersionParseErrorKind::InvalidCharacter('-'),
+                        section,
+                        i,
+                    ))
                 }
-                (Section::DottedQualifier, b'-') => return fallback(),
-                (Section::BuildOrQualifier, _) | (Section::DottedQualifier, _) | _ => {}
+                _ => {}
             }
         }
 
         // Parse last section
         match section {
             Section::Major => {
-                if s.bytes().nth(start_index).unwrap_or(b'_') == b'0' {
-                    qualifier = Some(s[start_index..s.len()].to_string());
-                    return ArtifactVersion {
-                        major,
-                        minor,
-                        incremental,
-                        build,
-                        qualifier,
-                        comparable: ComparableVersion::new(s),
-                    };
-                }
-
-                if let Ok(i) = &s[start_index..s.len()].parse::<i32>() {
-                    major = *i as u32;
-                } else {
-                    return fallback();
-                }
-            }
-            Section::Minor => {
-                if let Ok(i) = &s[start_index..s.len()].parse::<i32>() {
-                    minor = *i as u32;
-                } else {
-                    return fallback();
-                }
-            }
-            Section::Incremental => {
-                if let Ok(i) = &s[start_index..s.len()].parse::<i32>() {
-                    incremental = *i as u32;
-                } else {
-                    return fallback();
-                }
+                leading_zero(start_index)?;
+                major = number(section, start_index, s.len())?;
             }
+            Section::Minor => minor = number(section, start_index, s.len())?,
+            Section::Incremental => incremental = number(section, start_index, s.len())?,
             Section::BuildOrQualifier => {
                 let sec = &s[start_index..s.len()];
+                spans[VersionComponent::Qualifier as usize] = Some(start_index..s.len());
 
-                if sec.bytes().nth(0).unwrap_or(b'_') == b'0' {
+                let unsigned = sec.strip_prefix(|c| c == '-' || c == '+');
+                if unsigned.is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+                {
+                    // Maven would read this as a (possibly negative) build number, which we
+                    // can't represent
+                    return Err(error(
+                        ArtifactVersionParseErrorKind::SignedNumber,
+                        section,
+                        start_index,
+                    ));
+                } else if policy == LeadingZeroPolicy::MavenStrict && sec.starts_with('0') {
                     qualifier = Some(sec.to_string());
                 } else {
-                    if let Ok(i) = sec.parse::<i32>() {
-                        build = i as u32;
+                    if let Ok(i) = sec.parse::<u64>() {
+                        build = i;
                     } else {
                         qualifier = Some(sec.to_string());
                     }
@@ -228,39 +350,37 @@ impl ArtifactVersion {
             }
             Section::DottedQualifier => {
                 let sec = &s[start_index..s.len()];
-                if sec.bytes().all(|b| b >= b'0' && b <= b'9') {
-                    return fallback();
+                spans[VersionComponent::Qualifier as usize] = Some(start_index..s.len());
+                if sec.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(error(
+                        ArtifactVersionParseErrorKind::TooManyComponents,
+                        section,
+                        start_index,
+                    ));
                 } else {
                     qualifier = Some(sec.to_string());
                 }
             }
         }
 
-        ArtifactVersion {
-            major,
-            minor,
-            incremental,
-            build,
-            qualifier,
-            comparable: ComparableVersion::new(s),
-        }
+        Ok((major, minor, incremental, build, qualifier, spans))
     }
 
     /// The major version, or 0 if not specified.
-    pub fn major(&self) -> u32 {
+    pub fn major(&self) -> u64 {
         self.major
     }
     /// The minor version, or 0 if not specified.
-    pub fn minor(&self) -> u32 {
+    pub fn minor(&self) -> u64 {
         self.minor
     }
     /// The incremental version, or 0 if not specified.
-    pub fn incremental(&self) -> u32 {
+    pub fn incremental(&self) -> u64 {
         self.incremental
     }
     /// The build number, or 0 if not specified. If this is nonzero, qualifier will always be
     /// `None`.
-    pub fn build(&self) -> u32 {
+    pub fn build(&self) -> u64 {
         self.build
     }
     /// The qualifier, or `None` if not specified. If this is `Some`, build will always be zero.
@@ -268,11 +388,176 @@ impl ArtifactVersion {
         &self.qualifier
     }
 
+    /// Classifies the qualifier, if there is one. Well-known qualifiers optionally followed by a
+    /// number, such as `alpha-1`, `RC2` or `SNAPSHOT`, are recognised in the same way as by
+    /// [ComparableVersion], with a missing number being 0. Anything else is
+    /// [`QualifierKind::Other`].
+    ///
+    /// ```
+    /// # use mvn_version::{ArtifactVersion, QualifierKind};
+    /// assert_eq!(ArtifactVersion::new("1.0-beta-2").qualifier_kind(), Some(QualifierKind::Beta(2)));
+    /// assert_eq!(ArtifactVersion::new("1.0-CR1").qualifier_kind(), Some(QualifierKind::Rc(1)));
+    /// assert_eq!(ArtifactVersion::new("1.0").qualifier_kind(), None);
+    /// ```
+    pub fn qualifier_kind(&self) -> Option<QualifierKind> {
+        self.qualifier.as_deref().map(QualifierKind::new)
+    }
+
     /// Returns the original string representation of the version, the same as the one passed as
     /// the argument to [`Self::new`]
     pub fn as_str(&self) -> &str {
         &self.comparable.orig
     }
+
+    /// Whether the original strings of both versions are exactly the same. See
+    /// [`ComparableVersion::eq_strict`].
+    pub fn eq_strict(&self, other: &ArtifactVersion) -> bool {
+        self.comparable.eq_strict(&other.comparable)
+    }
+
+    /// Whether both versions are equal but written differently. See
+    /// [`ComparableVersion::is_respelling_of`].
+    pub fn is_respelling_of(&self, other: &ArtifactVersion) -> bool {
+        self.comparable.is_respelling_of(&other.comparable)
+    }
+
+    /// Whether the version was broken down into its components. If it wasn't, because it's not of
+    /// a form Maven understands, the whole version is the qualifier and all numeric components
+    /// are zero. [`Self::try_new`] fails for such versions.
+    ///
+    /// ```
+    /// # use mvn_version::ArtifactVersion;
+    /// assert!(ArtifactVersion::new("1.2.3-beta-1").is_structured());
+    /// assert!(!ArtifactVersion::new("1.0RC2").is_structured());
+    /// assert_eq!(ArtifactVersion::new("1.0RC2").qualifier().as_deref(), Some("1.0RC2"));
+    /// ```
+    pub fn is_structured(&self) -> bool {
+        self.fallback.is_none()
+    }
+
+    /// Why the version wasn't broken down into its components, or `None` if it was. This is the
+    /// error [`Self::try_new`] fails with.
+    ///
+    /// ```
+    /// # use mvn_version::{ArtifactVersion, ArtifactVersionParseErrorKind};
+    /// let version = ArtifactVersion::new("1.0--1");
+    /// assert_eq!(version.qualifier().as_deref(), Some("1.0--1"));
+    ///
+    /// let reason = version.fallback_reason().unwrap();
+    /// assert_eq!(reason.kind(), ArtifactVersionParseErrorKind::SignedNumber);
+    /// assert_eq!(reason.offset(), 4);
+    /// ```
+    pub fn fallback_reason(&self) -> Option<&ArtifactVersionParseError> {
+        self.fallback.as_ref()
+    }
+
+    /// The byte range of a component in the version string, or `None` if the component is
+    /// missing. The build number counts as the [`VersionComponent::Qualifier`]. If the version
+    /// wasn't broken down into its components, the whole string is the qualifier, and
+    /// [`Self::fallback_reason`] tells where parsing stopped.
+    ///
+    /// ```
+    /// # use mvn_version::{ArtifactVersion, VersionComponent};
+    /// let version = ArtifactVersion::new("1.12-beta-1");
+    /// assert_eq!(version.span(VersionComponent::Minor), Some(2..4));
+    /// assert_eq!(version.span(VersionComponent::Incremental), None);
+    /// assert_eq!(version.span(VersionComponent::Qualifier), Some(5..11));
+    /// ```
+    pub fn span(&self, component: VersionComponent) -> Option<Range<usize>> {
+        self.spans[component as usize].clone()
+    }
+
+    /// The components present in the version string along with their byte ranges, in the order
+    /// they appear. See [`Self::span`].
+    pub fn sections(&self) -> impl Iterator<Item = (VersionComponent, Range<usize>)> + '_ {
+        [
+            VersionComponent::Major,
+            VersionComponent::Minor,
+            VersionComponent::Incremental,
+            VersionComponent::Qualifier,
+        ]
+        .iter()
+        .filter_map(move |&component| self.span(component).map(|span| (component, span)))
+    }
+
+    /// The [ComparableVersion] this version is compared with, which is parsed from the same
+    /// string.
+    pub fn comparable(&self) -> &ComparableVersion {
+        &self.comparable
+    }
+
+    /// Converts this into the [ComparableVersion] it's compared with, without parsing it again.
+    pub fn into_comparable(self) -> ComparableVersion {
+        self.comparable
+    }
+
+    /// Whether this is a SNAPSHOT version. See [`ComparableVersion::is_snapshot`].
+    pub fn is_snapshot(&self) -> bool {
+        self.comparable.is_snapshot()
+    }
+
+    /// Whether this is a prerelease version. See [`ComparableVersion::is_prerelease`].
+    pub fn is_prerelease(&self) -> bool {
+        self.comparable.is_prerelease()
+    }
+
+    /// Whether this is a release version. See [`ComparableVersion::is_release`].
+    pub fn is_release(&self) -> bool {
+        self.comparable.is_release()
+    }
+
+    /// Whether this version satisfies the given range. See [`ComparableVersion::matches`].
+    pub fn matches(&self, range: &VersionRange) -> bool {
+        self.comparable.matches(range)
+    }
+
+    /// Whether the numeric core of this version equals that of the given one, ignoring
+    /// qualifiers. See [`ComparableVersion::eq_ignoring_qualifier`].
+    pub fn eq_ignoring_qualifier(&self, other: &ArtifactVersion) -> bool {
+        self.comparable.eq_ignoring_qualifier(&other.comparable)
+    }
+
+    /// Compares the numeric cores of both versions, ignoring qualifiers. See
+    /// [`ComparableVersion::cmp_ignoring_qualifier`].
+    pub fn cmp_ignoring_qualifier(&self, other: &ArtifactVersion) -> Ordering {
+        self.comparable.cmp_ignoring_qualifier(&other.comparable)
+    }
+
+    /// Whether both versions have the same major version. See [`ComparableVersion::same_major`].
+    pub fn same_major(&self, other: &ArtifactVersion) -> bool {
+        self.comparable.same_major(&other.comparable)
+    }
+
+    /// Whether both versions have the same major and minor versions. See
+    /// [`ComparableVersion::same_minor`].
+    pub fn same_minor(&self, other: &ArtifactVersion) -> bool {
+        self.comparable.same_minor(&other.comparable)
+    }
+
+    /// Compares with another version as if neither had a trailing SNAPSHOT qualifier. See
+    /// [`ComparableVersion::cmp_ignoring_snapshot`].
+    pub fn cmp_ignoring_snapshot(&self, other: &ArtifactVersion) -> Ordering {
+        self.comparable.cmp_ignoring_snapshot(&other.comparable)
+    }
+
+    /// Whether this version equals another if neither had a trailing SNAPSHOT qualifier. See
+    /// [`ComparableVersion::cmp_ignoring_snapshot`].
+    pub fn eq_ignoring_snapshot(&self, other: &ArtifactVersion) -> bool {
+        self.comparable.eq_ignoring_snapshot(&other.comparable)
+    }
+
+    /// Returns the base version, which is the same as this one except for unique SNAPSHOT
+    /// versions, whose timestamp and build number are replaced with `SNAPSHOT`. See
+    /// [`ComparableVersion::base_version`].
+    pub fn base_version(&self) -> ArtifactVersion {
+        let base = self.comparable.base_version();
+
+        if base.orig == self.comparable.orig {
+            self.clone()
+        } else {
+            ArtifactVersion::new_owned(base.orig)
+        }
+    }
 }
 
 impl AsRef<str> for ArtifactVersion {
@@ -305,18 +590,77 @@ impl PartialOrd for ArtifactVersion {
     }
 }
 
+/// Compares with a version string by parsing it. See [ComparableVersion]'s implementation.
+impl PartialEq<str> for ArtifactVersion {
+    fn eq(&self, other: &str) -> bool {
+        self.comparable == *other
+    }
+}
+
+impl PartialEq<&str> for ArtifactVersion {
+    fn eq(&self, other: &&str) -> bool {
+        self.comparable == *other
+    }
+}
+
+impl PartialOrd<str> for ArtifactVersion {
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        self.comparable.partial_cmp(other)
+    }
+}
+
+impl PartialOrd<&str> for ArtifactVersion {
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        self.comparable.partial_cmp(other)
+    }
+}
+
 impl Ord for ArtifactVersion {
     fn cmp(&self, other: &Self) -> Ordering {
         self.comparable.cmp(&other.comparable)
     }
 }
 
+impl Default for ArtifactVersion {
+    /// Returns the version `0`, parsed like [`ArtifactVersion::new`] would, so that Maven's
+    /// rules make it a qualifier rather than a major version.
+    fn default() -> Self {
+        Self::new("0")
+    }
+}
+
 impl From<&str> for ArtifactVersion {
     fn from(s: &str) -> Self {
         Self::new(s)
     }
 }
 
+impl From<String> for ArtifactVersion {
+    fn from(s: String) -> Self {
+        Self::new_owned(s)
+    }
+}
+
+impl From<ComparableVersion> for ArtifactVersion {
+    /// Breaks down the version into components, without parsing it for comparison again.
+    fn from(version: ComparableVersion) -> Self {
+        Self::from_comparable(version, LeadingZeroPolicy::MavenStrict)
+    }
+}
+
+impl From<ArtifactVersion> for ComparableVersion {
+    fn from(version: ArtifactVersion) -> Self {
+        version.into_comparable()
+    }
+}
+
+impl From<ArtifactVersion> for String {
+    /// Returns the original string representation of the version, without copying it.
+    fn from(version: ArtifactVersion) -> Self {
+        version.comparable.orig
+    }
+}
+
 impl FromStr for ArtifactVersion {
     type Err = core::convert::Infallible;
 
@@ -324,3 +668,279 @@ impl FromStr for ArtifactVersion {
         Ok(Self::new(s))
     }
 }
+
+/// The kind of qualifier of an [ArtifactVersion], as returned by
+/// [`ArtifactVersion::qualifier_kind`].
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub enum QualifierKind {
+    /// `alpha-n`, `alphan` or `an`.
+    Alpha(u32),
+    /// `beta-n`, `betan` or `bn`.
+    Beta(u32),
+    /// `milestone-n`, `milestonen` or `mn`.
+    Milestone(u32),
+    /// `rc-n` or `cr-n`, with or without the dash.
+    Rc(u32),
+    /// `SNAPSHOT`.
+    Snapshot,
+    /// `sp-n`, with or without the dash.
+    Sp(u32),
+    /// Any other qualifier, as written.
+    Other(String),
+}
+
+impl QualifierKind {
+    fn new(qualifier: &str) -> Self {
+        let parsed = ComparableVersion::new(qualifier);
+        let mut items = parsed.segments().flat_map(|s| s.items());
+
+        let kind = match items.next() {
+            Some(VersionItem::Qualifier(q)) => q,
+            _ => return QualifierKind::Other(qualifier.to_string()),
+        };
+        let number = match (items.next(), items.next()) {
+            (None, _) => 0,
+            (Some(VersionItem::Int(n)), None) => n as u32,
+            _ => return QualifierKind::Other(qualifier.to_string()),
+        };
+
+        match (kind, number) {
+            (Qualifier::Alpha, n) => QualifierKind::Alpha(n),
+            (Qualifier::Beta, n) => QualifierKind::Beta(n),
+            (Qualifier::Milestone, n) => QualifierKind::Milestone(n),
+            (Qualifier::Rc, n) => QualifierKind::Rc(n),
+            (Qualifier::Snapshot, 0) => QualifierKind::Snapshot,
+            (Qualifier::Sp, n) => QualifierKind::Sp(n),
+            _ => QualifierKind::Other(qualifier.to_string()),
+        }
+    }
+}
+
+/// Assembles an [ArtifactVersion] from its components, producing both the version string and the
+/// parsed version. Only the numeric components up to the last one which was set are written, and a
+/// build number and qualifier replace each other.
+///
+/// ```
+/// # use mvn_version::ArtifactVersionBuilder;
+/// let version = ArtifactVersionBuilder::new().major(1).minor(2).qualifier("beta-1").finish();
+/// assert_eq!(version.as_str(), "1.2-beta-1");
+/// assert_eq!(version.minor(), 2);
+///
+/// let version = ArtifactVersionBuilder::new().major(2).incremental(1).build(3).finish();
+/// assert_eq!(version.as_str(), "2.0.1-3");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ArtifactVersionBuilder {
+    major: u64,
+    minor: Option<u64>,
+    incremental: Option<u64>,
+    build: u64,
+    qualifier: Option<String>,
+}
+
+impl ArtifactVersionBuilder {
+    /// Constructs a builder for version `0`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the major version.
+    pub fn major(mut self, major: u64) -> Self {
+        self.major = major;
+        self
+    }
+
+    /// Sets the minor version.
+    pub fn minor(mut self, minor: u64) -> Self {
+        self.minor = Some(minor);
+        self
+    }
+
+    /// Sets the incremental version.
+    pub fn incremental(mut self, incremental: u64) -> Self {
+        self.incremental = Some(incremental);
+        self
+    }
+
+    /// Sets the build number, removing the qualifier. A build number of 0 isn't written.
+    pub fn build(mut self, build: u64) -> Self {
+        self.build = build;
+        self.qualifier = None;
+        self
+    }
+
+    /// Sets the qualifier, removing the build number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the qualifier would be read as a build number, i.e. it's a number without a sign
+    /// or leading zeroes which fits into a [u64]. A signed number such as `+5` is allowed, and
+    /// makes the whole version a qualifier like it does when parsing.
+    pub fn qualifier(mut self, qualifier: &str) -> Self {
+        assert!(
+            !qualifier.starts_with(|c: char| c.is_ascii_digit())
+                || qualifier.starts_with('0')
+                || qualifier.parse::<u64>().is_err(),
+            "qualifier {:?} would be a build number",
+            qualifier
+        );
+
+        self.build = 0;
+        self.qualifier = Some(qualifier.to_string());
+        self
+    }
+
+    /// Builds the version. It's parsed from the string it's written as, exactly like
+    /// [`ArtifactVersion::new`] would, so a major version of 0 makes the whole version a
+    /// qualifier, as it does in Maven.
+    ///
+    /// ```
+    /// # use mvn_version::ArtifactVersionBuilder;
+    /// let version = ArtifactVersionBuilder::new().major(0).minor(1).finish();
+    /// assert_eq!(version.qualifier().as_deref(), Some("0.1"));
+    /// assert!(!version.is_structured());
+    /// ```
+    pub fn finish(&self) -> ArtifactVersion {
+        let mut s = self.major.to_string();
+        if self.minor.is_some() || self.incremental.is_some() {
+            s.push_str(&format!(".{}", self.minor.unwrap_or(0)));
+        }
+        if let Some(incremental) = self.incremental {
+            s.push_str(&format!(".{}", incremental));
+        }
+
+        match &self.qualifier {
+            Some(qualifier) => s.push_str(&format!("-{}", qualifier)),
+            None if self.build != 0 => s.push_str(&format!("-{}", self.build)),
+            None => {}
+        }
+
+        ArtifactVersion::new_owned(s)
+    }
+}
+
+/// How [ArtifactVersion] treats numeric components with leading zeroes, such as the major version
+/// of `0.9` or the build number of `1.0-01`.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy, Default)]
+pub enum LeadingZeroPolicy {
+    /// What Maven does: a major version starting with a zero, even a lone `0`, makes the whole
+    /// version a qualifier, and a build number starting with a zero is a qualifier instead.
+    #[default]
+    MavenStrict,
+    /// Numbers are read as numbers, leading zeroes or not, so `0.9.0` has a minor version of 9 and
+    /// `1.0-01` has a build number of 1.
+    Lenient,
+}
+
+/// The error returned when a string isn't a well-formed ArtifactVersion. It carries the kind of
+/// problem encountered, the component of the version it was found in, and the byte offset into
+/// the string at which it was detected.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ArtifactVersionParseError {
+    kind: ArtifactVersionParseErrorKind,
+    component: VersionComponent,
+    offset: usize,
+}
+
+/// The specific reason a string was rejected as an ArtifactVersion.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[non_exhaustive]
+pub enum ArtifactVersionParseErrorKind {
+    /// A numeric component was empty, e.g. in `1..2` or `.1`.
+    EmptyComponent,
+    /// The major version started with a zero, e.g. `01` or `0.1`. Never returned with
+    /// [`LeadingZeroPolicy::Lenient`].
+    LeadingZero,
+    /// A numeric component didn't fit into an unsigned 64-bit integer.
+    NumberTooLarge,
+    /// A numeric component contained something other than digits, e.g. `1.0RC2`, or a qualifier
+    /// following the incremental version after a dot contained a dash.
+    InvalidCharacter(char),
+    /// There were more than three numeric components, e.g. `1.2.3.4`.
+    TooManyComponents,
+    /// The build number had a sign, e.g. `1.0--1` or `1.0-+1`. Maven would read it as a number,
+    /// possibly a negative one.
+    SignedNumber,
+}
+
+/// A component of an ArtifactVersion.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum VersionComponent {
+    /// The major version.
+    Major,
+    /// The minor version.
+    Minor,
+    /// The incremental version.
+    Incremental,
+    /// The build number or qualifier.
+    Qualifier,
+}
+
+impl ArtifactVersionParseError {
+    fn new(
+        kind: ArtifactVersionParseErrorKind,
+        component: VersionComponent,
+        offset: usize,
+    ) -> Self {
+        ArtifactVersionParseError {
+            kind,
+            component,
+            offset,
+        }
+    }
+
+    /// What went wrong.
+    pub fn kind(&self) -> ArtifactVersionParseErrorKind {
+        self.kind
+    }
+
+    /// The component of the version in which the problem was detected.
+    pub fn component(&self) -> VersionComponent {
+        self.component
+    }
+
+    /// The byte offset into the string at which the problem was detected.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Display for ArtifactVersionParseErrorKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ArtifactVersionParseErrorKind::EmptyComponent => f.write_str("empty component"),
+            ArtifactVersionParseErrorKind::LeadingZero => f.write_str("leading zero"),
+            ArtifactVersionParseErrorKind::NumberTooLarge => f.write_str("number too large"),
+            ArtifactVersionParseErrorKind::InvalidCharacter(c) => {
+                write!(f, "invalid character {:?}", c)
+            }
+            ArtifactVersionParseErrorKind::TooManyComponents => {
+                f.write_str("too many numeric components")
+            }
+            ArtifactVersionParseErrorKind::SignedNumber => f.write_str("signed number"),
+        }
+    }
+}
+
+impl Display for VersionComponent {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            VersionComponent::Major => "major version",
+            VersionComponent::Minor => "minor version",
+            VersionComponent::Incremental => "incremental version",
+            VersionComponent::Qualifier => "qualifier",
+        })
+    }
+}
+
+impl Display for ArtifactVersionParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} in {} at byte {}",
+            self.kind, self.component, self.offset
+        )
+    }
+}
+
+impl Error for ArtifactVersionParseError {}
@@ -0,0 +1,95 @@
+// Numbers too long for a u32, which need num-bigint unless the `bigint` feature is disabled.
+
+#[cfg(feature = "bigint")]
+pub use num_bigint::BigUint;
+
+#[cfg(not(feature = "bigint"))]
+pub use fallback::BigUint;
+
+#[cfg(not(feature = "bigint"))]
+mod fallback {
+    use std::convert::TryFrom;
+    use std::error::Error;
+    use std::fmt;
+    use std::fmt::{Display, Formatter};
+    use std::num::TryFromIntError;
+    use std::str::FromStr;
+
+    /// An unsigned number of arbitrary size, standing in for `num_bigint::BigUint` when the
+    /// `bigint` feature is disabled. Numbers which fit into a [u128] are stored as such, and
+    /// longer ones as their digits without leading zeroes, which are compared by their length
+    /// first and then lexically. That orders them just like numbers, but only parsing, formatting,
+    /// comparison and conversion to [u64] are supported.
+    #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Clone)]
+    pub struct BigUint(Repr);
+
+    // The variants are in ascending order, since anything too long for a u128 is larger
+    #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Clone)]
+    enum Repr {
+        Small(u128),
+        Large(usize, Box<str>),
+    }
+
+    /// The error returned when parsing a [BigUint] from a string which isn't made of ASCII digits.
+    /// It isn't exported, so that disabling the `bigint` feature doesn't add to the public API.
+    #[derive(Debug, Eq, PartialEq, Clone)]
+    pub struct ParseBigUintError(());
+
+    impl FromStr for BigUint {
+        type Err = ParseBigUintError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(ParseBigUintError(()));
+            }
+
+            let digits = s.trim_start_matches('0');
+            Ok(BigUint(match digits.parse() {
+                Ok(n) => Repr::Small(n),
+                Err(_) if digits.is_empty() => Repr::Small(0),
+                Err(_) => Repr::Large(digits.len(), digits.into()),
+            }))
+        }
+    }
+
+    impl From<u64> for BigUint {
+        fn from(n: u64) -> Self {
+            BigUint(Repr::Small(u128::from(n)))
+        }
+    }
+
+    impl From<u128> for BigUint {
+        fn from(n: u128) -> Self {
+            BigUint(Repr::Small(n))
+        }
+    }
+
+    impl TryFrom<&BigUint> for u64 {
+        type Error = TryFromIntError;
+
+        fn try_from(n: &BigUint) -> Result<Self, Self::Error> {
+            match n.0 {
+                Repr::Small(n) => u64::try_from(n),
+                // Doesn't fit either, and there's no other way to construct the error
+                Repr::Large(..) => u64::try_from(u128::MAX),
+            }
+        }
+    }
+
+    impl Display for BigUint {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            match &self.0 {
+                Repr::Small(n) => n.fmt(f),
+                Repr::Large(_, digits) => f.pad_integral(true, "", digits),
+            }
+        }
+    }
+
+    impl Display for ParseBigUintError {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("invalid digit found in string")
+        }
+    }
+
+    impl Error for ParseBigUintError {}
+}
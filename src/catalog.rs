@@ -0,0 +1,185 @@
+use crate::{MavenCoordinates, VersionRange};
+use std::collections::{BTreeMap, BTreeSet};
+use std::iter::FromIterator;
+
+/// A lookup table of managed versions keyed by group and artifact ID, like the
+/// `dependencyManagement` section of a BOM.
+///
+/// Managed versions are [VersionRange]s, so they can be either soft requirements on a single
+/// version, as usual in BOMs, or actual ranges. The catalog is made up of layers, each of which
+/// overrides the ones below it: looking up a group and artifact ID yields the entry in the topmost
+/// layer containing it.
+///
+/// ```
+/// # use mvn_version::{MavenCoordinates, VersionCatalog, VersionRange};
+/// let bom: VersionCatalog = ["com.example:foo:1.0", "com.example:bar:2.0"]
+///     .iter()
+///     .map(|c| MavenCoordinates::new(c).unwrap())
+///     .collect();
+///
+/// let mut overrides = VersionCatalog::new();
+/// overrides.insert("com.example", "foo", VersionRange::new("[1.1,2.0)").unwrap());
+///
+/// let mut catalog = bom;
+/// catalog.push_overrides(overrides);
+/// assert_eq!(catalog.get("com.example", "foo").unwrap().to_string(), "[1.1,2.0)");
+/// assert_eq!(catalog.get("com.example", "bar").unwrap().to_string(), "2.0");
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct VersionCatalog {
+    layers: Vec<BTreeMap<(String, String), VersionRange>>,
+}
+
+/// An entry of a [VersionCatalog], along with the layer it's in.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct CatalogEntry<'a> {
+    layer: usize,
+    version: &'a VersionRange,
+}
+
+impl VersionCatalog {
+    /// Constructs an empty catalog with a single layer.
+    pub fn new() -> Self {
+        VersionCatalog {
+            layers: vec![BTreeMap::new()],
+        }
+    }
+
+    /// Sets the managed version for a group and artifact ID in the topmost layer, returning the
+    /// version it previously had in that layer.
+    pub fn insert(
+        &mut self,
+        group_id: &str,
+        artifact_id: &str,
+        version: VersionRange,
+    ) -> Option<VersionRange> {
+        self.top_layer()
+            .insert((group_id.to_string(), artifact_id.to_string()), version)
+    }
+
+    /// Sets the version of the given coordinates as the managed version for their group and
+    /// artifact ID in the topmost layer. The version becomes a soft requirement, just like a
+    /// version in a BOM.
+    pub fn insert_coordinates(&mut self, coords: &MavenCoordinates) -> Option<VersionRange> {
+        self.insert(
+            coords.group_id(),
+            coords.artifact_id(),
+            VersionRange::from_version(coords.version().clone()),
+        )
+    }
+
+    /// Adds an empty layer on top, whose entries will override those of all current layers.
+    pub fn push_layer(&mut self) {
+        self.layers.push(BTreeMap::new());
+    }
+
+    /// Places the layers of another catalog on top of this one's, so that its entries override
+    /// those of this catalog.
+    pub fn push_overrides(&mut self, overrides: VersionCatalog) {
+        self.layers.extend(overrides.layers);
+    }
+
+    /// The number of layers, which is always at least one.
+    pub fn layer_count(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Looks up the managed version for a group and artifact ID.
+    pub fn get(&self, group_id: &str, artifact_id: &str) -> Option<&VersionRange> {
+        self.winning_entry(group_id, artifact_id)
+            .map(|entry| entry.version())
+    }
+
+    /// Looks up the managed version for the group and artifact ID of the given coordinates.
+    pub fn get_coordinates(&self, coords: &MavenCoordinates) -> Option<&VersionRange> {
+        self.get(coords.group_id(), coords.artifact_id())
+    }
+
+    /// Finds the entry which determines the managed version for a group and artifact ID, i.e.
+    /// the one in the topmost layer containing them.
+    pub fn winning_entry(&self, group_id: &str, artifact_id: &str) -> Option<CatalogEntry<'_>> {
+        self.entries(group_id, artifact_id).next()
+    }
+
+    /// Returns all entries for a group and artifact ID, starting with the winning one and
+    /// followed by the ones it overrides, from the top layer down.
+    pub fn entries(
+        &self,
+        group_id: &str,
+        artifact_id: &str,
+    ) -> impl Iterator<Item = CatalogEntry<'_>> + '_ {
+        let key = (group_id.to_string(), artifact_id.to_string());
+
+        self.layers
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(move |(layer, entries)| {
+                entries
+                    .get(&key)
+                    .map(|version| CatalogEntry { layer, version })
+            })
+    }
+
+    /// Returns every managed group and artifact ID along with the version it's managed at, in
+    /// order of group and artifact ID.
+    pub fn iter(&self) -> impl Iterator<Item = ((&str, &str), &VersionRange)> {
+        let keys = self
+            .layers
+            .iter()
+            .flat_map(|entries| entries.keys())
+            .collect::<BTreeSet<_>>();
+
+        keys.into_iter().map(move |(g, a)| {
+            let version = self.get(g, a).expect("key comes from one of the layers");
+            ((g.as_str(), a.as_str()), version)
+        })
+    }
+
+    /// The number of managed group and artifact IDs.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Whether no versions are managed at all.
+    pub fn is_empty(&self) -> bool {
+        self.layers.iter().all(BTreeMap::is_empty)
+    }
+
+    fn top_layer(&mut self) -> &mut BTreeMap<(String, String), VersionRange> {
+        self.layers
+            .last_mut()
+            .expect("a catalog always has a layer")
+    }
+}
+
+impl<'a> CatalogEntry<'a> {
+    /// The layer the entry is in, counting up from 0 for the bottom layer.
+    pub fn layer(&self) -> usize {
+        self.layer
+    }
+
+    /// The managed version.
+    pub fn version(&self) -> &'a VersionRange {
+        self.version
+    }
+}
+
+impl Default for VersionCatalog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FromIterator<MavenCoordinates> for VersionCatalog {
+    /// Collects coordinates into a single-layer catalog, as if by
+    /// [`VersionCatalog::insert_coordinates`]. Later coordinates replace earlier ones with the
+    /// same group and artifact ID.
+    fn from_iter<T: IntoIterator<Item = MavenCoordinates>>(iter: T) -> Self {
+        let mut catalog = VersionCatalog::new();
+        for coords in iter {
+            catalog.insert_coordinates(&coords);
+        }
+        catalog
+    }
+}
@@ -0,0 +1,139 @@
+use super::item::{self, Segment, SegmentVec};
+use super::{ComparableVersion, ParseOptions};
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::str;
+use std::str::Utf8Error;
+
+/// A [ComparableVersion] which borrows the string it's parsed from instead of copying it.
+///
+/// Qualifiers point into the borrowed string, even ones in upper case, which are only lowercased
+/// as they're compared. So parsing one of these is cheaper when lots of versions are compared and
+/// thrown away right after, such as when scanning a repository index. It compares, and hashes,
+/// exactly like the equivalent ComparableVersion, and the two can be compared with each other.
+///
+/// ```
+/// # use mvn_version::{ComparableVersion, ComparableVersionRef};
+/// let index = "1.0-beta-1 1.0 1.1-SNAPSHOT 0.9";
+/// let latest = index.split(' ').map(ComparableVersionRef::new).max().unwrap();
+/// assert_eq!(latest.as_str(), "1.1-SNAPSHOT");
+/// assert!(latest > ComparableVersion::new("1.0"));
+///
+/// let owned: ComparableVersion = latest.into_owned();
+/// assert_eq!(owned, ComparableVersion::new("1.1-snapshot"));
+/// ```
+#[derive(Debug, Eq, Clone)]
+pub struct ComparableVersionRef<'a> {
+    orig: &'a str,
+    segments: SegmentVec<'a>,
+}
+
+impl<'a> ComparableVersionRef<'a> {
+    /// Parses the given string like [`ComparableVersion::new`], borrowing it instead of copying
+    /// it.
+    pub fn new(s: &'a str) -> Self {
+        let mut segments = ComparableVersion::parse_segments(s, &ParseOptions::new());
+        item::normalize(&mut segments);
+
+        ComparableVersionRef { orig: s, segments }
+    }
+
+    /// Parses the given bytes like [`ComparableVersion::from_bytes`], borrowing them instead of
+    /// copying them, so versions can be compared straight out of a buffer. Fails if the bytes
+    /// aren't valid UTF-8.
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersionRef;
+    /// let buffer = b"1.0-beta-1\n1.0\n1.1-SNAPSHOT\n";
+    /// let latest = buffer
+    ///     .split(|b| *b == b'\n')
+    ///     .filter(|line| !line.is_empty())
+    ///     .map(|line| ComparableVersionRef::from_bytes(line).unwrap())
+    ///     .max()
+    ///     .unwrap();
+    /// assert_eq!(latest.as_str(), "1.1-SNAPSHOT");
+    /// ```
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, Utf8Error> {
+        str::from_utf8(bytes).map(Self::new)
+    }
+
+    /// Returns the original string this version was parsed from.
+    pub fn as_str(&self) -> &'a str {
+        self.orig
+    }
+
+    /// Copies the version into a [ComparableVersion] which doesn't borrow the string, without
+    /// parsing it again.
+    pub fn into_owned(self) -> ComparableVersion {
+        let segments = self.segments.into_iter().map(Segment::into_owned).collect();
+        ComparableVersion::from_segments(self.orig.to_string(), segments)
+    }
+}
+
+impl Display for ComparableVersionRef<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.orig)
+    }
+}
+
+impl Hash for ComparableVersionRef<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.segments.hash(state)
+    }
+}
+
+impl PartialEq for ComparableVersionRef<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.segments == other.segments
+    }
+}
+
+impl PartialOrd for ComparableVersionRef<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ComparableVersionRef<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        item::cmp_segments(&self.segments, &other.segments)
+    }
+}
+
+impl PartialEq<ComparableVersion> for ComparableVersionRef<'_> {
+    fn eq(&self, other: &ComparableVersion) -> bool {
+        self.segments[..] == other.segments[..]
+    }
+}
+
+impl PartialEq<ComparableVersionRef<'_>> for ComparableVersion {
+    fn eq(&self, other: &ComparableVersionRef<'_>) -> bool {
+        self.segments[..] == other.segments[..]
+    }
+}
+
+impl PartialOrd<ComparableVersion> for ComparableVersionRef<'_> {
+    fn partial_cmp(&self, other: &ComparableVersion) -> Option<Ordering> {
+        Some(item::cmp_segments(&self.segments, &other.segments))
+    }
+}
+
+impl PartialOrd<ComparableVersionRef<'_>> for ComparableVersion {
+    fn partial_cmp(&self, other: &ComparableVersionRef<'_>) -> Option<Ordering> {
+        Some(item::cmp_segments(&self.segments, &other.segments))
+    }
+}
+
+impl<'a> From<&'a str> for ComparableVersionRef<'a> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<ComparableVersionRef<'_>> for ComparableVersion {
+    fn from(version: ComparableVersionRef<'_>) -> Self {
+        version.into_owned()
+    }
+}
@@ -0,0 +1,96 @@
+use super::item::{Item, ItemVec, Segment, SegmentVec};
+use super::ComparableVersion;
+use crate::BigUint;
+use std::borrow::Cow;
+
+/// Assembles a [ComparableVersion] item by item, producing the same version as parsing the string
+/// form it builds along the way. Items within a segment are joined with `.`, and segments with
+/// `-`.
+///
+/// ```
+/// # use mvn_version::{ComparableVersion, ComparableVersionBuilder};
+/// let version = ComparableVersionBuilder::new()
+///     .number(1)
+///     .number(2)
+///     .segment()
+///     .qualifier("beta")
+///     .segment()
+///     .number(3)
+///     .build();
+///
+/// assert_eq!(version.as_str(), "1.2-beta-3");
+/// assert_eq!(version, ComparableVersion::new("1.2-beta-3"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ComparableVersionBuilder {
+    orig: String,
+    segments: SegmentVec<'static>,
+    cur_segment: ItemVec<'static>,
+}
+
+impl ComparableVersionBuilder {
+    /// Constructs a builder for an empty version.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a number to the current segment.
+    pub fn number(self, n: u64) -> Self {
+        let digits = n.to_string();
+        let item = Item::from_digits(&digits);
+        self.push(&digits, item)
+    }
+
+    /// Appends a number of arbitrary size to the current segment.
+    pub fn big_number(self, n: &BigUint) -> Self {
+        let digits = n.to_string();
+        let item = Item::from_digits(&digits);
+        self.push(&digits, item)
+    }
+
+    /// Appends a qualifier to the current segment. Qualifiers are case-insensitive, and aliases
+    /// such as `cr` for `rc` are expanded just like when parsing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the qualifier is empty or contains digits, `.` or `-`, since it would parse as
+    /// something else.
+    pub fn qualifier(self, qualifier: &str) -> Self {
+        assert!(
+            !qualifier.is_empty()
+                && !qualifier
+                    .bytes()
+                    .any(|b| b.is_ascii_digit() || b == b'.' || b == b'-'),
+            "invalid qualifier {:?}",
+            qualifier
+        );
+
+        let item = Item::from_str(Cow::Owned(qualifier.to_ascii_lowercase()), false);
+        self.push(qualifier, item)
+    }
+
+    /// Starts a new segment.
+    pub fn segment(mut self) -> Self {
+        self.orig.push('-');
+        let items = std::mem::take(&mut self.cur_segment);
+        self.segments.push(Segment::new(items));
+        self
+    }
+
+    /// Builds the version.
+    pub fn build(&self) -> ComparableVersion {
+        let mut segments = self.segments.clone();
+        segments.push(Segment::new(self.cur_segment.clone()));
+        ComparableVersion::from_segments(self.orig.clone(), segments)
+    }
+
+    fn push(mut self, s: &str, item: Item<'static>) -> Self {
+        if !self.cur_segment.is_empty() {
+            self.orig.push('.');
+        }
+
+        self.orig.push_str(s);
+        self.cur_segment.push(item);
+        self
+    }
+}
@@ -0,0 +1,92 @@
+// The grammar of version strings, as winnow combinators. The lexer behind [Tokens], the parser and
+// the limit checks are all built from these, so that they can't disagree about where an item ends.
+//
+// [Tokens]: super::Tokens
+
+use super::TokenKind;
+use winnow::combinator::{alt, cond, empty, eof};
+use winnow::token::{take_till, take_while};
+use winnow::Parser;
+
+/// How an item of a version string is written.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub(super) enum Shape {
+    Number,
+    Qualifier,
+    /// A Maven 4 combination of a qualifier and a number, with the number starting at the given
+    /// byte.
+    Combination(usize),
+}
+
+/// What ends an item of a version string.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub(super) enum End {
+    Dot,
+    Dash,
+    /// The start of an item of the other kind, as between `1` and `RC` in `1RC`.
+    Transition,
+    Eof,
+}
+
+/// A run of digits, where `digit` decides what counts as one.
+fn number<'a>(digit: fn(char) -> bool) -> impl Parser<&'a str, &'a str, ()> {
+    take_while(1.., digit)
+}
+
+/// A run of anything other than digits and separators.
+fn qualifier<'a>(digit: fn(char) -> bool) -> impl Parser<&'a str, &'a str, ()> {
+    take_till(1.., move |c| c == '.' || c == '-' || digit(c))
+}
+
+/// A `.` or `-`.
+fn separator<'a>() -> impl Parser<&'a str, End, ()> {
+    alt(('.'.value(End::Dot), '-'.value(End::Dash)))
+}
+
+/// A single token, along with its text.
+pub(super) fn token<'a>(digit: fn(char) -> bool) -> impl Parser<&'a str, (TokenKind, &'a str), ()> {
+    alt((
+        '.'.value(TokenKind::Dot),
+        '-'.value(TokenKind::Dash),
+        number(digit).value(TokenKind::Number),
+        qualifier(digit).value(TokenKind::Qualifier),
+    ))
+    .with_taken()
+}
+
+/// A single item. With `combinations`, a qualifier directly followed by a number is read as one
+/// item, as Maven 4 does.
+fn item<'a>(
+    digit: fn(char) -> bool,
+    combinations: bool,
+) -> impl Parser<&'a str, (&'a str, Shape), ()> {
+    let combination = (qualifier(digit), number(digit))
+        .with_taken()
+        .map(|((qualifier, _), s)| (s, Shape::Combination(qualifier.len())));
+
+    alt((
+        number(digit).map(|s| (s, Shape::Number)),
+        cond(combinations, combination).verify_map(|combination| combination),
+        qualifier(digit).map(|s| (s, Shape::Qualifier)),
+    ))
+}
+
+/// An item along with what ends it, or a separator on its own where an item was left out, as
+/// between the dots of `1..2`. A version string is any number of these.
+pub(super) fn piece<'a>(
+    digit: fn(char) -> bool,
+    combinations: bool,
+) -> impl Parser<&'a str, (Option<(&'a str, Shape)>, End), ()> {
+    // Items are as long as they can be, so anything after one which isn't a separator starts an
+    // item of the other kind
+    let end = alt((
+        separator(),
+        eof.value(End::Eof),
+        empty.value(End::Transition),
+    ));
+
+    alt((
+        (item(digit, combinations), end).map(|(item, end)| (Some(item), end)),
+        separator().map(|end| (None, end)),
+    ))
+}
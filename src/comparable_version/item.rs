@@ -1,163 +1,438 @@
-use num_bigint::BigUint;
+use super::Qualifier;
+use crate::BigUint;
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::fmt;
-use std::fmt::{Display, Formatter};
+use std::fmt::{Display, Formatter, Write};
+use std::hash::{Hash, Hasher};
 
-/// A single token in a version spec. For example, "1" or "foo".
-#[derive(Debug, Hash, Clone)]
-pub(super) enum Item {
+/// The order of qualifiers, which have already been lowercased and had their aliases expanded.
+/// The empty qualifier is a release.
+pub(super) trait Ranking {
+    fn cmp_qualifiers(&self, s: &str, t: &str) -> Ordering;
+
+    /// Compares two qualifiers Maven knows, which rankings may do without comparing strings.
+    fn cmp_known(&self, q: Qualifier, r: Qualifier) -> Ordering {
+        self.cmp_qualifiers(q.as_str(), r.as_str())
+    }
+
+    /// Compares two qualifiers, each along with whether it's [unfolded](Item::Unfolded) and
+    /// still has to be lowercased.
+    fn cmp_unfolded(&self, s: (&str, bool), t: (&str, bool)) -> Ordering {
+        let lowercase = |(s, unfolded): (&str, bool)| {
+            if unfolded {
+                s.to_ascii_lowercase()
+            } else {
+                s.to_string()
+            }
+        };
+        self.cmp_qualifiers(&lowercase(s), &lowercase(t))
+    }
+}
+
+/// The order Maven puts qualifiers in.
+pub(super) struct MavenRanking;
+
+impl Ranking for MavenRanking {
+    fn cmp_qualifiers(&self, s: &str, t: &str) -> Ordering {
+        Qualifier::from_item(s).cmp(&Qualifier::from_item(t))
+    }
+
+    fn cmp_known(&self, q: Qualifier, r: Qualifier) -> Ordering {
+        q.cmp(&r)
+    }
+
+    fn cmp_unfolded(&self, s: (&str, bool), t: (&str, bool)) -> Ordering {
+        // Unfolded qualifiers are never known ones, so only the lexical order needs lowercasing
+        match (Qualifier::from_item(s.0), Qualifier::from_item(t.0)) {
+            (Qualifier::Other(_), Qualifier::Other(_)) => folded_bytes(s).cmp(folded_bytes(t)),
+            (q, r) => q.cmp(&r),
+        }
+    }
+}
+
+/// A single token in a version spec. For example, "1" or "foo". Qualifiers borrow from the version
+/// string where they can.
+#[derive(Debug, Clone)]
+pub(super) enum Item<'a> {
     Int(u32),
     BigInt(BigUint),
-    String(String),
+    /// A qualifier Maven knows, including the empty one, which costs nothing to copy or compare.
+    Known(Qualifier<'static>),
+    /// Any other qualifier.
+    String(Cow<'a, str>),
+    /// Any other qualifier with upper case ASCII letters, as written, which compares and hashes
+    /// as if they were lowercase. Parsing with the default options produces these instead of
+    /// copying the qualifier to lowercase it, and they're only lowercased once they're copied
+    /// anyway by [`Item::into_owned`].
+    Unfolded(&'a str),
+    /// A qualifier directly followed by a number, as Maven 4 parses `rc1`. Never produced when
+    /// parsing like Maven 3, which splits the two into separate segments.
+    Combination(Cow<'a, str>, Box<Item<'a>>),
 }
 
-impl Item {
-    pub fn from_str(s: &str, followed_by_digit: bool) -> Self {
-        let s = &s[s.bytes().take_while(|c| c == &b'0').count()..s.len()];
+impl<'a> Item<'a> {
+    /// Constructs a numeric item from a string of ASCII digits.
+    pub fn from_digits(s: &str) -> Self {
+        let s = s.trim_start_matches('0');
 
-        match (followed_by_digit, s) {
-            (true, "a") => Item::String("alpha".to_string()),
-            (true, "b") => Item::String("beta".to_string()),
-            (true, "m") => Item::String("milestone".to_string()),
-            (_, "ga") => Item::String("".to_string()),
-            (_, "final") => Item::String("".to_string()),
-            (_, "release") => Item::String("".to_string()),
-            (_, "cr") => Item::String("rc".to_string()),
-            (_, _) => Item::String(s.to_string()),
+        if s.len() <= super::MAX_U32_LEN {
+            // This can fail if we stripped everything off
+            Item::Int(s.parse().unwrap_or(0))
+        } else {
+            Item::BigInt(s.parse().unwrap())
         }
     }
 
-    pub fn is_null(&self) -> bool {
+    /// Constructs a qualifier item from a lowercase string, expanding aliases.
+    pub fn from_str(s: Cow<'a, str>, followed_by_digit: bool) -> Self {
+        let s = expand_alias(s, followed_by_digit);
+
+        match Qualifier::from_item(&s).known() {
+            Some(q) => Item::Known(q),
+            None => Item::String(s),
+        }
+    }
+
+    /// Constructs a qualifier item from a qualifier as written, when ASCII letters are the only
+    /// case-insensitive ones and there are no aliases besides Maven's. Upper case qualifiers
+    /// Maven knows are replaced by their lowercase forms, and others are kept [unfolded].
+    ///
+    /// [unfolded]: Item::Unfolded
+    pub fn from_written(s: &'a str, followed_by_digit: bool) -> Self {
+        if !s.bytes().any(|b| b.is_ascii_uppercase()) {
+            return Item::from_str(Cow::Borrowed(s), followed_by_digit);
+        }
+
+        match known_word(s) {
+            Some(word) => Item::from_str(Cow::Borrowed(word), followed_by_digit),
+            None => Item::Unfolded(s),
+        }
+    }
+
+    /// Constructs a combination item from a lowercase qualifier and the ASCII digits following it.
+    pub fn combination(s: Cow<'a, str>, digits: &str) -> Self {
+        Item::Combination(expand_alias(s, true), Box::new(Item::from_digits(digits)))
+    }
+
+    /// Puts back the case of a qualifier Maven doesn't know, given its original string, for
+    /// case-sensitive parsing. Known qualifiers and whatever aliases expanded to stay lowercase.
+    /// `unicode` is how the qualifier was lowercased, see [fold_case].
+    pub fn preserve_case(self, original: &'a str, unicode: bool) -> Self {
+        let folded = |q: &str| {
+            if unicode && !original.is_ascii() {
+                q == fold_case(original, unicode)
+            } else {
+                q.len() == original.len()
+                    && q.bytes()
+                        .zip(original.bytes())
+                        .all(|(a, b)| a == b.to_ascii_lowercase())
+            }
+        };
+
         match self {
-            Item::Int(0) => true,
-            Item::String(s) if s.is_empty() => true,
-            _ => false,
+            Item::String(q) if folded(&q) => Item::String(Cow::Borrowed(original)),
+            // Unlike strings, combinations still hold known qualifiers
+            Item::Combination(q, i)
+                if folded(&q) && matches!(Qualifier::from_item(&q), Qualifier::Other(_)) =>
+            {
+                Item::Combination(Cow::Borrowed(original), i)
+            }
+            item => item,
+        }
+    }
+
+    /// Copies whatever the item borrows, so it no longer depends on the version string.
+    pub fn into_owned(self) -> Item<'static> {
+        match self {
+            Item::Int(i) => Item::Int(i),
+            Item::BigInt(i) => Item::BigInt(i),
+            Item::Known(q) => Item::Known(q),
+            Item::String(s) => Item::String(Cow::Owned(s.into_owned())),
+            Item::Unfolded(s) => Item::String(Cow::Owned(s.to_ascii_lowercase())),
+            Item::Combination(s, i) => {
+                Item::Combination(Cow::Owned(s.into_owned()), Box::new(i.into_owned()))
+            }
         }
     }
 
+    pub fn is_null(&self) -> bool {
+        matches!(self, Item::Int(0) | Item::Known(Qualifier::Release))
+    }
+
     /// Where this item stands in comparison to no item at all. `more_segments` is whether there are
     /// more segments after the one containing this item on the version spec we're comparing with.
     /// Yes, this is really weird and specific. It's What Maven Does™.
     pub fn better_than_nothing(&self, more_segments: bool) -> Ordering {
+        self.better_than_nothing_by(more_segments, &MavenRanking)
+    }
+
+    fn better_than_nothing_by(&self, more_segments: bool, ranking: &impl Ranking) -> Ordering {
         match self {
             Item::Int(_) if more_segments => Ordering::Greater,
             Item::Int(0) => Ordering::Equal,
             Item::Int(_) => Ordering::Greater,
             Item::BigInt(_) => Ordering::Greater,
-            Item::String(_) if more_segments => Ordering::Less,
-            Item::String(s) => match s.as_str() {
-                "alpha" | "beta" | "milestone" | "rc" | "snapshot" => Ordering::Less,
-                "" => Ordering::Equal,
-                _ => Ordering::Greater,
-            },
+            Item::Known(_) | Item::String(_) | Item::Unfolded(_) | Item::Combination(..)
+                if more_segments =>
+            {
+                Ordering::Less
+            }
+            Item::Known(q) => ranking.cmp_known(*q, Qualifier::Release),
+            Item::String(s) | Item::Combination(s, _) => ranking.cmp_qualifiers(s, ""),
+            Item::Unfolded(s) => ranking.cmp_unfolded((s, true), ("", false)),
+        }
+    }
+
+    /// The text of a qualifier, or nothing for a number.
+    fn qualifier_str(&self) -> &str {
+        match self {
+            Item::Int(_) | Item::BigInt(_) => "",
+            Item::Known(q) => q.as_str(),
+            Item::String(s) | Item::Combination(s, _) => s,
+            Item::Unfolded(s) => s,
+        }
+    }
+
+    fn cmp_by(&self, other: &Self, ranking: &impl Ranking) -> Ordering {
+        match (self, other) {
+            (Item::Int(i), Item::Int(j)) => i.cmp(j),
+            (Item::BigInt(i), Item::BigInt(j)) => i.cmp(j),
+            (Item::Int(_), Item::BigInt(_)) => Ordering::Less,
+            (Item::BigInt(_), Item::Int(_)) => Ordering::Greater,
+            (Item::Int(_) | Item::BigInt(_), _) => Ordering::Greater,
+            (_, Item::Int(_) | Item::BigInt(_)) => Ordering::Less,
+            (Item::Known(q), Item::Known(r)) => ranking.cmp_known(*q, *r),
+            (Item::Combination(s, i), Item::Combination(t, j)) => {
+                ranking.cmp_qualifiers(s, t).then_with(|| i.cmp(j))
+            }
+            _ => {
+                let (s, t) = (self.qualifier_str(), other.qualifier_str());
+                let order = match (self, other) {
+                    (Item::Unfolded(_), _) | (_, Item::Unfolded(_)) => ranking.cmp_unfolded(
+                        (s, matches!(self, Item::Unfolded(_))),
+                        (t, matches!(other, Item::Unfolded(_))),
+                    ),
+                    _ => ranking.cmp_qualifiers(s, t),
+                };
+
+                // `rc1` comes after `rc`, whatever follows the latter
+                match (self, other) {
+                    (Item::Combination(..), _) => order.then(Ordering::Greater),
+                    (_, Item::Combination(..)) => order.then(Ordering::Less),
+                    _ => order,
+                }
+            }
+        }
+    }
+}
+
+/// Qualifiers Maven knows and the aliases it expands, which are common enough in upper case that
+/// they're worth not allocating a lowercase copy for even when other qualifiers need one.
+const KNOWN_WORDS: [&str; 13] = [
+    "alpha",
+    "beta",
+    "milestone",
+    "rc",
+    "snapshot",
+    "sp",
+    "a",
+    "b",
+    "m",
+    "cr",
+    "ga",
+    "final",
+    "release",
+];
+
+/// Lowercases a qualifier. By default only ASCII letters are lowercased, so the result doesn't
+/// depend on Unicode case mappings; with `unicode`, all letters are, like Java's
+/// `toLowerCase(Locale.ENGLISH)`, which Maven uses. Only qualifiers which actually have upper case
+/// letters, and which aren't known to Maven, are copied. See [`Item::from_written`] for how the
+/// default options avoid that.
+pub(super) fn fold_case(s: &str, unicode: bool) -> Cow<'_, str> {
+    if unicode && !s.is_ascii() {
+        Cow::Owned(s.to_lowercase())
+    } else if s.bytes().any(|b| b.is_ascii_uppercase()) {
+        match known_word(s) {
+            Some(word) => Cow::Borrowed(word),
+            None => Cow::Owned(s.to_ascii_lowercase()),
         }
+    } else {
+        Cow::Borrowed(s)
     }
 }
 
-impl Display for Item {
+/// The lowercase form of a qualifier Maven knows or an alias, in any case.
+fn known_word(s: &str) -> Option<&'static str> {
+    KNOWN_WORDS
+        .iter()
+        .find(|word| s.eq_ignore_ascii_case(word))
+        .copied()
+}
+
+/// The bytes of a qualifier, along with whether it's [unfolded](Item::Unfolded), lowercased if so.
+fn folded_bytes((s, unfolded): (&str, bool)) -> impl Iterator<Item = u8> + '_ {
+    s.bytes()
+        .map(move |b| if unfolded { b.to_ascii_lowercase() } else { b })
+}
+
+pub(super) fn expand_alias(s: Cow<'_, str>, followed_by_digit: bool) -> Cow<'_, str> {
+    let alias = match (followed_by_digit, &*s) {
+        (true, "a") => "alpha",
+        (true, "b") => "beta",
+        (true, "m") => "milestone",
+        (_, "ga") | (_, "final") | (_, "release") => "",
+        (_, "cr") => "rc",
+        (_, _) => return s,
+    };
+
+    Cow::Borrowed(alias)
+}
+
+impl Display for Item<'_> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
             Item::Int(i) => f.write_str(&i.to_string()),
             Item::BigInt(i) => f.write_str(&i.to_string()),
+            Item::Known(q) => f.write_str(q.as_str()),
             Item::String(s) => f.write_str(s),
+            Item::Unfolded(s) => s
+                .chars()
+                .try_for_each(|c| f.write_char(c.to_ascii_lowercase())),
+            Item::Combination(s, i) => write!(f, "{}{}", s, i),
         }
     }
 }
 
-impl PartialEq for Item {
+impl PartialEq for Item<'_> {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Item::Int(i), Item::Int(j)) => i == j,
+            (Item::BigInt(i), Item::BigInt(j)) => i == j,
+            (Item::Known(q), Item::Known(r)) => q == r,
             (Item::String(s), Item::String(t)) => s == t,
+            (Item::Unfolded(s), Item::Unfolded(t)) => s.eq_ignore_ascii_case(t),
+            (Item::Unfolded(s), Item::String(t)) | (Item::String(t), Item::Unfolded(s)) => {
+                folded_bytes((s, true)).eq(t.bytes())
+            }
+            (Item::Combination(s, i), Item::Combination(t, j)) => s == t && i == j,
             _ => false,
         }
     }
 }
 
-impl Eq for Item {}
+impl Eq for Item<'_> {}
+
+impl Hash for Item<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Item::Int(i) => i.hash(state),
+            Item::BigInt(i) => i.hash(state),
+            Item::Known(q) => q.hash(state),
+            // Byte by byte, so that unfolded qualifiers can be lowercased on the way
+            Item::String(s) => hash_folded((s, false), state),
+            Item::Unfolded(s) => hash_folded((s, true), state),
+            Item::Combination(s, i) => {
+                s.hash(state);
+                i.hash(state);
+            }
+        }
+    }
+}
+
+/// Hashes a qualifier like [folded_bytes] compares it, terminated like a [str] so that
+/// consecutive qualifiers can't run into each other.
+fn hash_folded<H: Hasher>(s: (&str, bool), state: &mut H) {
+    folded_bytes(s).for_each(|b| state.write_u8(b));
+    state.write_u8(0xff);
+}
 
-impl PartialOrd for Item {
+impl PartialOrd for Item<'_> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl Ord for Item {
+impl Ord for Item<'_> {
     fn cmp(&self, other: &Self) -> Ordering {
-        fn ranking(s: &str) -> i8 {
-            match s {
-                "alpha" => 0,
-                "beta" => 1,
-                "milestone" => 2,
-                "rc" => 3,
-                "snapshot" => 4,
-                "" => 5,
-                "sp" => 6,
-                _ => 7,
-            }
-        }
-
-        match (self, other) {
-            (Item::Int(i), Item::Int(j)) => i.cmp(j),
-            (Item::BigInt(i), Item::BigInt(j)) => i.cmp(j),
-            (Item::String(s), Item::String(t)) => {
-                let s_rank = ranking(s);
-                let t_rank = ranking(t);
-
-                if s_rank == 7 && t_rank == 7 {
-                    s.cmp(t)
-                } else {
-                    s_rank.cmp(&t_rank)
-                }
-            }
-            (Item::Int(_), Item::BigInt(_))
-            | (Item::String(_), Item::Int(_))
-            | (Item::String(_), Item::BigInt(_)) => Ordering::Less,
-            (Item::BigInt(_), Item::Int(_))
-            | (Item::Int(_), Item::String(_))
-            | (Item::BigInt(_), Item::String(_)) => Ordering::Greater,
-        }
+        self.cmp_by(other, &MavenRanking)
     }
 }
 
+/// The items of a segment. Most segments have no more than a handful, which `smallvec` keeps
+/// inline instead of allocating.
+#[cfg(feature = "smallvec")]
+pub(super) type ItemVec<'a> = smallvec::SmallVec<[Item<'a>; 4]>;
+#[cfg(not(feature = "smallvec"))]
+pub(super) type ItemVec<'a> = Vec<Item<'a>>;
+
+/// The segments of a version while it's parsed, and of a borrowed version, kept inline like
+/// [ItemVec] when there are only a few.
+#[cfg(feature = "smallvec")]
+pub(super) type SegmentVec<'a> = smallvec::SmallVec<[Segment<'a>; 4]>;
+#[cfg(not(feature = "smallvec"))]
+pub(super) type SegmentVec<'a> = Vec<Segment<'a>>;
+
 /// A segment of Items that auto-normalizes its contents. One segment looks something like "1.0.0",
 /// "foo", "foo.bar", or "1.foo.bar". `last_segment` is whether we are the last segment. This is
 /// needed for comparison purposes because Maven is weird.
-#[derive(Debug, Hash, Clone)]
-pub(super) struct Segment {
-    items: Vec<Item>,
+#[derive(Debug, Clone)]
+pub(super) struct Segment<'a> {
+    items: ItemVec<'a>,
     last_segment: bool,
 }
 
-impl Segment {
-    pub fn new(mut items: Vec<Item>) -> Self {
-        // Strip trailing empty items
-        for i in (0..items.len()).rev() {
-            if items[i].is_null() {
-                items.remove(i);
-            } else {
-                break;
-            }
-        }
+impl<'a> Segment<'a> {
+    pub fn new(items: ItemVec<'a>) -> Self {
+        let mut segment = Self::unnormalized(items);
+        segment.strip_nulls();
+        segment
+    }
 
+    /// Constructs a segment which keeps its trailing empty items, as they were written.
+    pub fn unnormalized(items: ItemVec<'a>) -> Self {
         Segment {
             items,
             last_segment: false,
         }
     }
 
+    /// Strips trailing empty items
+    fn strip_nulls(&mut self) {
+        while self.items.last().is_some_and(Item::is_null) {
+            self.items.pop();
+        }
+    }
+
+    pub fn items(&self) -> &[Item<'a>] {
+        &self.items
+    }
+
+    /// Copies whatever the segment's items borrow, so it no longer depends on the version string.
+    pub fn into_owned(self) -> Segment<'static> {
+        Segment {
+            items: self.items.into_iter().map(Item::into_owned).collect(),
+            last_segment: self.last_segment,
+        }
+    }
+
     pub fn is_null(&self) -> bool {
         self.items.is_empty() || self.items.iter().all(|i| i.is_null())
     }
 
+    pub fn is_last_segment(&self) -> bool {
+        self.last_segment
+    }
+
     pub fn set_last_segment(&mut self) {
         self.last_segment = true;
     }
 
     /// Where this segment stands in comparison to no segment at all
-    pub fn better_than_nothing(&self) -> Ordering {
+    fn better_than_nothing(&self, ranking: &impl Ranking) -> Ordering {
         for i in &self.items {
-            let better = i.better_than_nothing(false);
+            let better = i.better_than_nothing_by(false, ranking);
             if better != Ordering::Equal {
                 return better;
             }
@@ -167,7 +442,7 @@ impl Segment {
     }
 }
 
-impl Display for Segment {
+impl Display for Segment<'_> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         f.write_str(
             &self
@@ -180,22 +455,38 @@ impl Display for Segment {
     }
 }
 
-impl PartialEq for Segment {
-    fn eq(&self, other: &Self) -> bool {
-        self.items == other.items
+// Segments may be invariant in their lifetime, depending on how their items are stored, so they
+// are compared across lifetimes explicitly
+impl<'b> PartialEq<Segment<'b>> for Segment<'_> {
+    fn eq(&self, other: &Segment<'b>) -> bool {
+        let (left, right): (&[Item], &[Item]) = (&self.items, &other.items);
+        left == right
     }
 }
 
-impl Eq for Segment {}
+impl Eq for Segment<'_> {}
+
+impl Hash for Segment<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // `last_segment` doesn't take part in equality, so it mustn't be hashed either
+        self.items.hash(state)
+    }
+}
 
-impl PartialOrd for Segment {
+impl PartialOrd for Segment<'_> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl Ord for Segment {
+impl Ord for Segment<'_> {
     fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_by(other, &MavenRanking)
+    }
+}
+
+impl Segment<'_> {
+    fn cmp_by(&self, other: &Segment<'_>, ranking: &impl Ranking) -> Ordering {
         let mut left = self.items.iter();
         let mut right = other.items.iter();
 
@@ -203,9 +494,11 @@ impl Ord for Segment {
             let l = left.next();
             let r = right.next();
             let order = match (l, r) {
-                (Some(li), Some(ri)) => li.cmp(ri),
-                (Some(li), None) => li.better_than_nothing(!other.last_segment),
-                (None, Some(ri)) => ri.better_than_nothing(!self.last_segment).reverse(),
+                (Some(li), Some(ri)) => li.cmp_by(ri, ranking),
+                (Some(li), None) => li.better_than_nothing_by(!other.last_segment, ranking),
+                (None, Some(ri)) => ri
+                    .better_than_nothing_by(!self.last_segment, ranking)
+                    .reverse(),
                 (None, None) => break,
             };
 
@@ -217,3 +510,48 @@ impl Ord for Segment {
         Ordering::Equal
     }
 }
+
+/// Strips trailing null items off each segment of a parsed version and then trailing null
+/// segments off the version, and flags the last remaining one.
+pub(super) fn normalize(segments: &mut SegmentVec) {
+    segments.iter_mut().for_each(Segment::strip_nulls);
+    while segments.last().is_some_and(Segment::is_null) {
+        segments.pop();
+    }
+
+    if let Some(seg) = segments.last_mut() {
+        seg.set_last_segment();
+    }
+}
+
+/// Compares the normalized segments of two versions.
+pub(super) fn cmp_segments(left: &[Segment], right: &[Segment]) -> Ordering {
+    cmp_segments_by(left, right, &MavenRanking)
+}
+
+/// Compares the normalized segments of two versions, ranking qualifiers the given way.
+pub(super) fn cmp_segments_by(
+    left: &[Segment],
+    right: &[Segment],
+    ranking: &impl Ranking,
+) -> Ordering {
+    let mut left = left.iter();
+    let mut right = right.iter();
+
+    loop {
+        let l = left.next();
+        let r = right.next();
+        let order = match (l, r) {
+            (Some(li), Some(ri)) => li.cmp_by(ri, ranking),
+            (Some(li), None) => li.better_than_nothing(ranking),
+            (None, Some(ri)) => ri.better_than_nothing(ranking).reverse(),
+            (None, None) => break,
+        };
+
+        if order != Ordering::Equal {
+            return order;
+        }
+    }
+
+    Ordering::Equal
+}
@@ -5,22 +5,48 @@
 // by the boundary between an integer and a string. For example, "1.2.3foo" parses identically to
 // "1.2.3-foo". For each of these segments, we use a Vec of Items (hence the nested Vecs).
 
+mod borrowed;
+mod builder;
+mod digits;
+mod grammar;
 mod item;
-
+mod options;
+mod pattern;
+mod ranking;
+mod stream;
+mod structure;
+mod token;
+
+pub use borrowed::ComparableVersionRef;
+pub use builder::ComparableVersionBuilder;
+pub use options::{ParseOptions, QualifierTable, VersionParseError, VersionParseErrorKind};
+pub use pattern::{VersionPattern, VersionPatternError};
+pub use ranking::QualifierRanking;
+pub use stream::{cmp_str, eq_str, hash_str};
+pub use structure::{Decomposition, PaddedItem, Padding, Qualifier, VersionItem, VersionSegment};
+pub use token::{Separator, Token, TokenKind, Tokens};
+
+use crate::{SnapshotVersion, VersionRange};
+use grammar::{End, Shape};
 use item::Item;
-use item::Segment;
+use item::{ItemVec, Segment, SegmentVec};
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::fmt;
-use std::fmt::{Display, Formatter};
+use std::fmt::{Display, Formatter, Write};
 use std::hash::{Hash, Hasher};
-use std::str::{from_utf8_unchecked, FromStr};
+use std::mem;
+use std::str;
+use std::str::{FromStr, Utf8Error};
+use std::sync::OnceLock;
+use winnow::combinator::repeat;
+use winnow::Parser;
 
 /// This is an implementation of Maven's ComparableVersion.
 ///
 /// ComparableVersions are made up of segments separated by dashes, or by boundary between character
 /// and digit. Each of these segments is comprised of an arbitrary number of dot-separated numbers
-/// or characters. They are fully case-insensitive and trailing zeroes are discarded. For some
-/// examples:
+/// or characters. They are case-insensitive and trailing zeroes are discarded. For some examples:
 ///
 /// - `1.0` parses as `[[1]]`
 /// - `1.0-1` parses as `[[1], [1]]`
@@ -39,11 +65,24 @@ use std::str::{from_utf8_unchecked, FromStr};
 ///
 /// Other qualifiers are ordered lexically.
 ///
+/// Only ASCII letters are case-insensitive, so `1.0-FOO` equals `1.0-foo` but `1.0-ÉTÉ` doesn't
+/// equal `1.0-été`, and the result never depends on Unicode case mappings such as the Turkish
+/// dotted and dotless I. Maven lowercases all letters, which [`ParseOptions::unicode_case`]
+/// opts into.
+///
 /// To compare ComparableVersions, use the built-in comparison and equality operators.
+///
+/// ComparableVersions are [Clone], [Send] and [Sync], so parsed versions can be handed to other
+/// threads without parsing them again. Cloning copies the parsed segments, so wrap versions in an
+/// [Arc](std::sync::Arc) to share them when that's too costly.
 #[derive(Debug, Eq, Clone)]
 pub struct ComparableVersion {
     pub(crate) orig: String,
-    segments: Vec<Segment>,
+    // A plain Vec, unlike while parsing, so that versions stay small enough to move around cheaply
+    segments: Vec<Segment<'static>>,
+    // Computed the first time it's asked for. Equality and hashing never look at it, so versions
+    // are still fine as keys despite the cell
+    canonical: OnceLock<Box<str>>,
 }
 
 #[cfg(feature = "serde")]
@@ -62,7 +101,9 @@ impl<'de> serde::Deserialize<'de> for ComparableVersion {
     where
         D: serde::Deserializer<'de>,
     {
-        Ok(Self::new(&<String as serde::Deserialize>::deserialize(deserializer)?))
+        Ok(Self::new_owned(
+            <String as serde::Deserialize>::deserialize(deserializer)?,
+        ))
     }
 }
 
@@ -72,127 +113,508 @@ static MAX_U32_LEN: usize = 9;
 impl ComparableVersion {
     /// Constructs a ComparableVersion from the given string. This function cannot fail for any
     /// reason, and will always return a valid ComparableVersion. Make sure to strip whitespace
-    /// before passing your input to this function. Otherwise, whitespace is treated identically to
-    /// any other text.
+    /// before passing your input to this function, or use [`Self::new_trimmed`]. Otherwise,
+    /// whitespace is treated identically to any other text.
     pub fn new(s: &str) -> Self {
-        fn parse_item(s: &str, is_digit: bool, followed_by_digit: bool) -> Item {
-            // Strip leading zeroes so we won't get a small BigInt
-            let bytes = s.bytes().skip_while(|b| b == &b'0').collect::<Vec<u8>>();
-            let s: &str = unsafe { from_utf8_unchecked(&bytes) };
-
-            if is_digit && s.len() <= MAX_U32_LEN {
-                // This can fail if we stripped everything off
-                Item::Int(s.parse().unwrap_or(0))
-            } else if is_digit {
-                Item::BigInt(s.parse().unwrap())
+        Self::new_owned(s.to_string())
+    }
+
+    /// Constructs a ComparableVersion from the given string like [`Self::new`], after removing
+    /// leading and trailing whitespace. Whitespace inside the version is kept; use
+    /// [`ParseOptions::reject_whitespace`] to refuse it instead.
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// let version = ComparableVersion::new_trimmed("\n  1.0-RC1\t");
+    /// assert_eq!(version.as_str(), "1.0-RC1");
+    /// assert_eq!(version, ComparableVersion::new("1.0-rc-1"));
+    /// ```
+    pub fn new_trimmed(s: &str) -> Self {
+        Self::new(s.trim())
+    }
+
+    /// Constructs a ComparableVersion from the given string like [`Self::new`], but takes
+    /// ownership of the string instead of copying it.
+    pub fn new_owned(s: String) -> Self {
+        let segments = Self::parse_segments(&s, &ParseOptions::new())
+            .into_iter()
+            .map(Segment::into_owned)
+            .collect();
+        Self::from_segments(s, segments)
+    }
+
+    /// Constructs a ComparableVersion from the given bytes, failing if they aren't valid UTF-8.
+    /// The bytes are only copied once they've been validated, so this is no more expensive than
+    /// [`Self::new`].
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// let version = ComparableVersion::from_bytes(b"1.0-RC1").unwrap();
+    /// assert_eq!(version, ComparableVersion::new("1.0-rc-1"));
+    ///
+    /// assert!(ComparableVersion::from_bytes(b"1.0-\xff").is_err());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Utf8Error> {
+        str::from_utf8(bytes).map(Self::new)
+    }
+
+    /// Constructs a ComparableVersion from the given bytes, replacing any invalid UTF-8 with
+    /// `U+FFFD REPLACEMENT CHARACTER`, which then counts as part of a qualifier. Valid UTF-8 is
+    /// copied only once, like in [`Self::new`].
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// let version = ComparableVersion::from_bytes_lossy(b"1.0-\xff");
+    /// assert_eq!(version.as_str(), "1.0-\u{fffd}");
+    /// assert!(version > ComparableVersion::new("1.0"));
+    /// ```
+    pub fn from_bytes_lossy(bytes: &[u8]) -> Self {
+        match String::from_utf8_lossy(bytes) {
+            Cow::Borrowed(s) => Self::new(s),
+            Cow::Owned(s) => Self::new_owned(s),
+        }
+    }
+
+    /// Splits a version string into segments, which still need normalizing. Items borrow from the
+    /// string unless they need lowercasing.
+    fn parse_segments<'a>(s: &'a str, options: &ParseOptions) -> SegmentVec<'a> {
+        fn ascii_digits(s: &str) -> Cow<'_, str> {
+            if s.is_ascii() {
+                Cow::Borrowed(s)
             } else {
-                Item::from_str(s, followed_by_digit)
+                Cow::Owned(digits::to_ascii(s))
             }
         }
 
-        let mut segments = Vec::new();
-        let mut cur_segment = Vec::new();
-        let lower = s.to_lowercase();
-
-        let mut is_digit = false;
-        let mut start_index = 0;
-
-        // We can go byte-by-byte here instead of char-by-char because we transparently copy over
-        // strings anyways. This is not technically compliant with the Java implementation, because
-        // Java will happily accept any UTF-16 "numbery" character as a number, but if anyone is
-        // using २ instead of 2 they are Doing It Wrong.
-        for (i, c) in lower.bytes().enumerate() {
-            match c {
-                b'.' | b'-' => {
-                    if i == start_index {
-                        cur_segment.push(Item::Int(0));
-                    } else {
-                        cur_segment.push(parse_item(&lower[start_index..i], is_digit, false));
-                    }
+        let qualifier = |s: &'a str| {
+            options
+                .qualifiers
+                .expand(item::fold_case(s, options.unicode_case))
+        };
 
-                    start_index = i + 1;
+        // With the default options, qualifiers needn't be lowercased up front
+        let as_written =
+            !options.case_sensitive && !options.unicode_case && options.qualifiers.is_empty();
 
-                    if c == b'-' {
-                        segments.push(Segment::new(cur_segment));
-                        cur_segment = Vec::new();
-                    }
+        let parse_item = |s: &'a str, shape: Shape, followed_by_digit: bool| {
+            let item = match shape {
+                Shape::Combination(i) => {
+                    Item::combination(qualifier(&s[..i]), &ascii_digits(&s[i..]))
                 }
-                _ => {
-                    let will_be_digit = c >= b'0' && c <= b'9';
-
-                    if (i > start_index)
-                        && ((will_be_digit && !is_digit) || (!will_be_digit && is_digit))
-                    {
-                        cur_segment.push(parse_item(
-                            &lower[start_index..i],
-                            is_digit,
-                            will_be_digit,
-                        ));
-                        start_index = i;
-
-                        // Boundary between digit and non-digit
-                        segments.push(Segment::new(cur_segment));
-                        cur_segment = Vec::new();
-                    }
+                Shape::Number => Item::from_digits(&ascii_digits(s)),
+                Shape::Qualifier if as_written => Item::from_written(s, followed_by_digit),
+                Shape::Qualifier => Item::from_str(qualifier(s), followed_by_digit),
+            };
 
-                    is_digit = will_be_digit;
-                }
+            if !options.case_sensitive {
+                return item;
             }
-        }
 
-        if lower.len() > start_index {
-            cur_segment.push(parse_item(
-                &lower[start_index..lower.len()],
-                is_digit,
-                false,
-            ));
-        }
+            let written = match shape {
+                Shape::Combination(i) => &s[..i],
+                _ => s,
+            };
+            item.preserve_case(written, options.unicode_case)
+        };
+
+        let push_piece = |(mut segments, mut cur_segment): (SegmentVec<'a>, ItemVec<'a>),
+                          (item, end): (Option<(&'a str, Shape)>, End)| {
+            let item = match item {
+                Some((s, shape)) => {
+                    // Maven 4 keeps a qualifier and the number following it together, in a
+                    // segment of their own
+                    if matches!(shape, Shape::Combination(_)) && !cur_segment.is_empty() {
+                        segments.push(Segment::unnormalized(mem::take(&mut cur_segment)));
+                    }
 
-        segments.push(Segment::new(cur_segment));
+                    // Only a number can directly follow a qualifier
+                    parse_item(s, shape, end == End::Transition)
+                }
+                None => Item::Int(0),
+            };
+            cur_segment.push(item);
 
-        // Strip trailing empty segments
-        for i in (0..segments.len()).rev() {
-            if segments[i].is_null() {
-                segments.remove(i);
-            } else {
-                break;
+            if let End::Dash | End::Transition = end {
+                segments.push(Segment::unnormalized(mem::take(&mut cur_segment)));
             }
-        }
+            (segments, cur_segment)
+        };
+
+        // Items are sliced out of the original string and only lowercased afterwards, since
+        // Unicode lowercasing can change the length of a string (`İ` becomes `i̇`) and throw the
+        // spans off
+        let (mut segments, cur_segment) =
+            repeat(0.., grammar::piece(options.digit(), options.combinations))
+                .fold(|| (SegmentVec::new(), ItemVec::new()), push_piece)
+                .parse(s)
+                .expect("every string is a version");
+
+        segments.push(Segment::unnormalized(cur_segment));
+        segments
+    }
 
-        // Set the flag on the last segment
-        if let Some(seg) = segments.last_mut() {
-            seg.set_last_segment();
-        }
+    /// Constructs a ComparableVersion from its original string and the segments it parses into,
+    /// normalizing the segments.
+    fn from_segments(orig: String, mut segments: SegmentVec<'static>) -> Self {
+        item::normalize(&mut segments);
+        let segments = segments.into_iter().collect::<Vec<_>>();
 
         ComparableVersion {
-            orig: s.to_string(),
+            orig,
             segments,
+            canonical: OnceLock::new(),
         }
     }
 
     /// Returns the canonical representation of this version string. The canonical representation is
     /// one in which all separators between segments are converted into dashes and all shortened or
     /// aliased qualifiers are expanded. In addition, the entire version string is lowercased and
-    /// trailing zeroes are stripped. For example:
+    /// trailing zeroes are stripped. It's computed the first time it's needed. For example:
     ///
     /// ```
     /// # use mvn_version::ComparableVersion;
     /// let a = ComparableVersion::new("1.0A1");
     /// assert_eq!(a.canonical(), "1-alpha-1");
     /// ```
-    pub fn canonical(&self) -> String {
-        self.segments
-            .iter()
-            .map(|s| s.to_string())
-            .collect::<Vec<String>>()
-            .join("-")
+    ///
+    /// The alternate form of [Display], i.e. `{:#}`, also prints the canonical representation.
+    pub fn canonical(&self) -> &str {
+        self.canonical.get_or_init(|| {
+            let mut canonical = String::new();
+            for (i, segment) in self.segments.iter().enumerate() {
+                if i > 0 {
+                    canonical.push('-');
+                }
+                let _ = write!(canonical, "{}", segment);
+            }
+            canonical.into_boxed_str()
+        })
+    }
+
+    /// Returns the [canonical] representation of this version as an owned key. Two versions are
+    /// equal exactly when their keys are, so unlike the original strings, keys can stand in for
+    /// versions in maps and databases.
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// let a = ComparableVersion::new("1.0.0-CR1");
+    /// let b = ComparableVersion::new("1-rc-1");
+    /// assert_eq!(a, b);
+    /// assert_eq!(a.canonical_key(), b.canonical_key());
+    /// ```
+    ///
+    /// [canonical]: Self::canonical
+    pub fn canonical_key(&self) -> String {
+        self.canonical().to_string()
     }
 
     /// Returns the original string representation of the version, the same as the one passed as
-    /// the argument to [`Self::new`]
+    /// the argument to [`Self::new`]. This is also available through [AsRef], and unlike
+    /// [`ToString::to_string`] doesn't allocate.
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// let version = ComparableVersion::new("1.0-RC1");
+    /// assert_eq!(version.as_str(), "1.0-RC1");
+    /// assert_eq!(AsRef::<str>::as_ref(&version), "1.0-RC1");
+    /// ```
     pub fn as_str(&self) -> &str {
         &self.orig
     }
+
+    /// Whether the original strings of both versions are exactly the same. Unlike `==`, which
+    /// compares versions the way Maven does, this tells `1.0` and `1.0.0` apart.
+    pub fn eq_strict(&self, other: &ComparableVersion) -> bool {
+        self.orig == other.orig
+    }
+
+    /// Whether both versions are equal but written differently, such as `1.0` and `1-0` or
+    /// `1.0-RC1` and `1.0-cr1`. These are the versions [dedup_versions] merges.
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// let version = ComparableVersion::new("1.0");
+    /// assert!(version.is_respelling_of(&ComparableVersion::new("1.0.0")));
+    /// assert!(!version.is_respelling_of(&ComparableVersion::new("1.0")));
+    /// assert!(!version.is_respelling_of(&ComparableVersion::new("1.1")));
+    /// ```
+    ///
+    /// [dedup_versions]: crate::dedup_versions
+    pub fn is_respelling_of(&self, other: &ComparableVersion) -> bool {
+        self == other && !self.eq_strict(other)
+    }
+
+    /// Returns the base version, which is the same as this one except for unique SNAPSHOT
+    /// versions, whose timestamp and build number are replaced with `SNAPSHOT`. This mirrors
+    /// Maven's `Artifact.getBaseVersion`.
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// let unique = ComparableVersion::new("1.0-20050205.060708-1");
+    /// assert_eq!(unique.base_version().as_str(), "1.0-SNAPSHOT");
+    /// ```
+    pub fn base_version(&self) -> ComparableVersion {
+        match SnapshotVersion::new(&self.orig) {
+            Some(snapshot) if snapshot.is_unique() => {
+                ComparableVersion::new(&snapshot.base_version())
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Whether any segment contains a qualifier that sorts below a release, i.e. `alpha`, `beta`,
+    /// `milestone`, `rc` or `snapshot`, or one of their aliases.
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// assert!(ComparableVersion::new("1.0-RC1").is_prerelease());
+    /// assert!(ComparableVersion::new("1.0-beta-1-foo").is_prerelease());
+    /// assert!(!ComparableVersion::new("1.0-sp-1").is_prerelease());
+    /// assert!(!ComparableVersion::new("1.0.Final").is_prerelease());
+    /// ```
+    pub fn is_prerelease(&self) -> bool {
+        self.segments.iter().any(|s| {
+            s.items()
+                .iter()
+                .any(|i| i.better_than_nothing(false) == Ordering::Less)
+        })
+    }
+
+    /// Whether this is a release, i.e. not a [prerelease]. Versions with qualifiers sorting
+    /// after a release such as `sp` or unknown qualifiers are releases.
+    ///
+    /// [prerelease]: Self::is_prerelease
+    pub fn is_release(&self) -> bool {
+        !self.is_prerelease()
+    }
+
+    /// Whether the items of the first segment start with the given ones, padding with zeroes if
+    /// the segment is too short.
+    fn has_numeric_prefix(&self, prefix: &[Item]) -> bool {
+        let items = self.segments.first().map(|s| s.items()).unwrap_or(&[]);
+
+        prefix
+            .iter()
+            .enumerate()
+            .all(|(i, p)| items.get(i).unwrap_or(&Item::Int(0)) == p)
+    }
+
+    /// The leading numbers of the first segment, without trailing zeroes.
+    fn numeric_core(&self) -> &[Item<'static>] {
+        let items = self.segments.first().map(|s| s.items()).unwrap_or(&[]);
+        let numeric = items
+            .iter()
+            .take_while(|i| !matches!(i, Item::Known(_) | Item::String(_) | Item::Unfolded(_)))
+            .count();
+        let significant = items[..numeric]
+            .iter()
+            .rposition(|i| !i.is_null())
+            .map_or(0, |i| i + 1);
+
+        &items[..significant]
+    }
+
+    /// Whether the numeric core of this version, i.e. the numbers its first segment starts with,
+    /// equals that of the given one. Qualifiers and anything after them, including build numbers,
+    /// are ignored, which makes release candidates match their final releases.
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// let rc = ComparableVersion::new("1.2.3-rc1");
+    /// assert!(rc.eq_ignoring_qualifier(&ComparableVersion::new("1.2.3")));
+    /// assert!(rc.eq_ignoring_qualifier(&ComparableVersion::new("1.2.3.Final")));
+    /// assert!(!rc.eq_ignoring_qualifier(&ComparableVersion::new("1.2")));
+    /// ```
+    pub fn eq_ignoring_qualifier(&self, other: &ComparableVersion) -> bool {
+        self.numeric_core() == other.numeric_core()
+    }
+
+    /// Compares the numeric cores of both versions, i.e. the numbers their first segments start
+    /// with, ignoring qualifiers and anything after them. This orders versions by the release they
+    /// lead up to, and is consistent with [`Self::eq_ignoring_qualifier`].
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// # use std::cmp::Ordering;
+    /// let rc = ComparableVersion::new("1.2.3-rc1");
+    /// let cmp = |s| rc.cmp_ignoring_qualifier(&ComparableVersion::new(s));
+    /// assert_eq!(cmp("1.2.3-beta-2"), Ordering::Equal);
+    /// assert_eq!(cmp("1.2.2-sp1"), Ordering::Greater);
+    /// assert_eq!(cmp("1.10"), Ordering::Less);
+    /// ```
+    pub fn cmp_ignoring_qualifier(&self, other: &ComparableVersion) -> Ordering {
+        // Trailing zeroes are stripped, so shorter cores with the same prefix are lower
+        self.numeric_core().cmp(other.numeric_core())
+    }
+
+    /// Whether the parsed items of the given version, as it was written, are a prefix of this
+    /// one's, segment by segment. Unlike a string prefix check, this respects item boundaries and
+    /// ignores insignificant differences such as case. Zeroes written in the prefix have to be
+    /// matched, so `1.0` is a prefix of `1.0.3` but not of `1.2.3`, while zeroes this version
+    /// leaves out are implied. The prefix is read again with the default [ParseOptions].
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// let version = ComparableVersion::new("1.2.3-RC1");
+    /// assert!(version.starts_with(&ComparableVersion::new("1.2")));
+    /// assert!(version.starts_with(&ComparableVersion::new("1.2.3-rc")));
+    /// assert!(!version.starts_with(&ComparableVersion::new("1.0")));
+    /// assert!(!ComparableVersion::new("1.20").starts_with(&ComparableVersion::new("1.2")));
+    /// ```
+    pub fn starts_with(&self, prefix: &ComparableVersion) -> bool {
+        // Whether the items are equal, with missing items standing in for trailing zeroes
+        fn matches(item: Option<&Item>, other: Option<&Item>) -> bool {
+            match (item, other) {
+                (Some(a), Some(b)) => a == b,
+                (Some(a), None) | (None, Some(a)) => a.is_null(),
+                (None, None) => true,
+            }
+        }
+
+        let prefix = Self::parse_segments(prefix.as_str(), &ParseOptions::new());
+        let (last, init) = match prefix.split_last() {
+            Some(split) => split,
+            None => return true,
+        };
+        let segment = |i: usize| self.segments.get(i).map_or(&[][..], Segment::items);
+
+        init.iter().enumerate().all(|(i, expected)| {
+            let items = segment(i);
+            let len = items.len().max(expected.items().len());
+            (0..len).all(|j| matches(items.get(j), expected.items().get(j)))
+        }) && last
+            .items()
+            .iter()
+            .enumerate()
+            .all(|(j, expected)| matches(segment(init.len()).get(j), Some(expected)))
+    }
+
+    /// Returns the longest version which both this and the given version [start with], written in
+    /// its canonical form.
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// let a = ComparableVersion::new("1.2.3");
+    /// let b = ComparableVersion::new("1.2.9-rc1");
+    /// assert_eq!(a.common_prefix(&b).as_str(), "1.2");
+    /// ```
+    ///
+    /// [start with]: Self::starts_with
+    pub fn common_prefix(&self, other: &ComparableVersion) -> ComparableVersion {
+        let mut segments = SegmentVec::new();
+
+        for (a, b) in self.segments.iter().zip(&other.segments) {
+            if a == b {
+                segments.push(a.clone());
+            } else {
+                let items = a
+                    .items()
+                    .iter()
+                    .zip(b.items())
+                    .take_while(|(i, j)| i == j)
+                    .map(|(i, _)| i.clone())
+                    .collect();
+                segments.push(Segment::new(items));
+                break;
+            }
+        }
+
+        while let Some(true) = segments.last().map(Segment::is_null) {
+            segments.pop();
+        }
+
+        let orig = segments
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<String>>()
+            .join("-");
+        Self::from_segments(orig, segments)
+    }
+
+    /// Whether the trailing segments are equal to all segments of the given version.
+    fn has_suffix(&self, suffix: &ComparableVersion) -> bool {
+        self.segments.ends_with(&suffix.segments)
+    }
+
+    /// Whether this is a SNAPSHOT version, i.e. it either ends with a `SNAPSHOT` qualifier in any
+    /// case, or it's a unique SNAPSHOT version such as `1.0-20050205.060708-1`.
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// assert!(ComparableVersion::new("1.0-SNAPSHOT").is_snapshot());
+    /// assert!(ComparableVersion::new("1.0-snapshot").is_snapshot());
+    /// assert!(ComparableVersion::new("1.0-20050205.060708-1").is_snapshot());
+    /// assert!(!ComparableVersion::new("1.0-SNAPSHOT-1").is_snapshot());
+    /// ```
+    pub fn is_snapshot(&self) -> bool {
+        match self.segments.last().and_then(|s| s.items().last()) {
+            Some(Item::Known(Qualifier::Snapshot)) => true,
+            _ => SnapshotVersion::new(&self.orig)
+                .filter(SnapshotVersion::is_unique)
+                .is_some(),
+        }
+    }
+
+    /// The segments of the [base version] with a trailing `snapshot` qualifier removed.
+    ///
+    /// [base version]: Self::base_version
+    fn release_segments(&self) -> SegmentVec<'static> {
+        let mut segments = self
+            .base_version()
+            .segments
+            .into_iter()
+            .collect::<SegmentVec>();
+
+        if let Some(last) = segments.pop() {
+            match last.items() {
+                [init @ .., Item::Known(Qualifier::Snapshot)] => {
+                    segments.push(Segment::new(ItemVec::from(init)))
+                }
+                _ => segments.push(last),
+            }
+        }
+
+        item::normalize(&mut segments);
+        segments
+    }
+
+    /// Compares with another version as if neither had a trailing SNAPSHOT qualifier, so that
+    /// `X-SNAPSHOT` is equal to `X`. Unique SNAPSHOT versions such as `1.0-20050205.060708-1` count
+    /// as their [base version]. This is meant for matching builds with the releases they lead up
+    /// to; the usual ordering still has SNAPSHOTs before their releases.
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// # use std::cmp::Ordering;
+    /// let snapshot = ComparableVersion::new("1.0-SNAPSHOT");
+    /// let release = ComparableVersion::new("1.0");
+    /// assert_eq!(snapshot.cmp_ignoring_snapshot(&release), Ordering::Equal);
+    /// assert!(snapshot < release);
+    /// ```
+    ///
+    /// [base version]: Self::base_version
+    pub fn cmp_ignoring_snapshot(&self, other: &ComparableVersion) -> Ordering {
+        item::cmp_segments(&self.release_segments(), &other.release_segments())
+    }
+
+    /// Whether this version equals another if neither had a trailing SNAPSHOT qualifier. See
+    /// [`Self::cmp_ignoring_snapshot`].
+    pub fn eq_ignoring_snapshot(&self, other: &ComparableVersion) -> bool {
+        self.release_segments() == other.release_segments()
+    }
+
+    /// Whether this version satisfies the given range. This is the same as
+    /// [`VersionRange::contains`].
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, VersionRange};
+    /// let range = VersionRange::new("[1.0,2.0)").unwrap();
+    /// assert!(ComparableVersion::new("1.5").matches(&range));
+    /// assert!(!ComparableVersion::new("2.0").matches(&range));
+    /// ```
+    pub fn matches(&self, range: &VersionRange) -> bool {
+        range.contains(self)
+    }
 }
 
 impl AsRef<str> for ComparableVersion {
@@ -202,8 +624,15 @@ impl AsRef<str> for ComparableVersion {
 }
 
 impl Display for ComparableVersion {
+    /// Writes the original string representation, or the [canonical] one with `{:#}`.
+    ///
+    /// [canonical]: Self::canonical
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        f.write_str(&self.orig)
+        if f.alternate() {
+            f.write_str(self.canonical())
+        } else {
+            f.write_str(&self.orig)
+        }
     }
 }
 
@@ -225,27 +654,49 @@ impl PartialOrd for ComparableVersion {
     }
 }
 
+/// Compares with a version string by parsing it, so `1.0` is equal to `"1.0.0"`. Use
+/// [`ComparableVersion::as_str`] to compare with the original string instead.
+///
+/// ```
+/// # use mvn_version::ComparableVersion;
+/// let version = ComparableVersion::new("1.0-RC1");
+/// assert!(version == "1-cr-1");
+/// assert!(version < "1.0" && version > "1.0-beta");
+/// ```
+impl PartialEq<str> for ComparableVersion {
+    fn eq(&self, other: &str) -> bool {
+        self.segments == Self::new(other).segments
+    }
+}
+
+impl PartialEq<&str> for ComparableVersion {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialOrd<str> for ComparableVersion {
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        Some(self.cmp(&Self::new(other)))
+    }
+}
+
+impl PartialOrd<&str> for ComparableVersion {
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        self.partial_cmp(*other)
+    }
+}
+
 impl Ord for ComparableVersion {
     fn cmp(&self, other: &Self) -> Ordering {
-        let mut left = self.segments.iter();
-        let mut right = other.segments.iter();
-
-        loop {
-            let l = left.next();
-            let r = right.next();
-            let order = match (l, r) {
-                (Some(li), Some(ri)) => li.cmp(ri),
-                (Some(li), None) => li.better_than_nothing(),
-                (None, Some(ri)) => ri.better_than_nothing().reverse(),
-                (None, None) => break,
-            };
-
-            if order != Ordering::Equal {
-                return order;
-            }
-        }
+        item::cmp_segments(&self.segments, &other.segments)
+    }
+}
 
-        Ordering::Equal
+impl Default for ComparableVersion {
+    /// Returns the version `0`.
+    fn default() -> Self {
+        Self::new("0")
     }
 }
 
@@ -255,6 +706,19 @@ impl From<&str> for ComparableVersion {
     }
 }
 
+impl From<String> for ComparableVersion {
+    fn from(s: String) -> Self {
+        Self::new_owned(s)
+    }
+}
+
+impl From<ComparableVersion> for String {
+    /// Returns the original string representation of the version, without copying it.
+    fn from(version: ComparableVersion) -> Self {
+        version.orig
+    }
+}
+
 impl FromStr for ComparableVersion {
     type Err = core::convert::Infallible;
 
@@ -262,3 +726,18 @@ impl FromStr for ComparableVersion {
         Ok(Self::new(s))
     }
 }
+
+/// Compares two version strings the way Maven 4 does, which keeps qualifiers and the numbers
+/// directly following them together. See [`crate::VersionScheme::Maven4`].
+pub(crate) fn cmp_maven4(a: &str, b: &str) -> Ordering {
+    let mut options = ParseOptions::new();
+    options.combinations = true;
+
+    let parse = |s| {
+        let mut segments = ComparableVersion::parse_segments(s, &options);
+        item::normalize(&mut segments);
+        segments
+    };
+
+    item::cmp_segments(&parse(a), &parse(b))
+}
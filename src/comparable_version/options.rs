@@ -0,0 +1,476 @@
+// Configurable construction of ComparableVersions, for the knobs which don't fit into `new`.
+
+use super::item::Segment;
+use super::{digits, ComparableVersion, TokenKind, Tokens};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+/// Options for [`ComparableVersion::with_options`]. The defaults parse exactly like
+/// [`ComparableVersion::new`].
+///
+/// ```
+/// # use mvn_version::{ComparableVersion, ParseOptions, VersionParseErrorKind};
+/// let options = ParseOptions::new().trim_whitespace(true).max_length(Some(16));
+///
+/// let version = ComparableVersion::with_options(" 1.0-RC1\n", &options).unwrap();
+/// assert_eq!(version.as_str(), "1.0-RC1");
+///
+/// let error = ComparableVersion::with_options("1.0-20240101.123456-1", &options).unwrap_err();
+/// assert_eq!(error.kind(), VersionParseErrorKind::TooLong);
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Default)]
+pub struct ParseOptions {
+    trim_whitespace: bool,
+    max_length: Option<usize>,
+    max_segments: Option<usize>,
+    max_digits: Option<usize>,
+    reject_whitespace: bool,
+    strict: bool,
+    pub(super) unicode_digits: bool,
+    pub(super) qualifiers: QualifierTable,
+    pub(super) case_sensitive: bool,
+    pub(super) unicode_case: bool,
+    // Only used to compare under Maven 4 rules for now, see `cmp_maven4`
+    pub(super) combinations: bool,
+}
+
+/// Qualifier aliases for [`ParseOptions::qualifiers`], on top of the ones Maven knows, such as
+/// `cr` for `rc` and `ga` for a release. Aliases are case-insensitive and are expanded before
+/// versions are compared, so an alias for a known qualifier ranks like it.
+///
+/// ```
+/// # use mvn_version::{ComparableVersion, ParseOptions, Qualifier, QualifierTable};
+/// let table = QualifierTable::new().alias("stable", "").alias("preview", "beta");
+/// let options = ParseOptions::new().qualifiers(table);
+/// let parse = |s| ComparableVersion::with_options(s, &options).unwrap();
+///
+/// assert_eq!(parse("1.0-STABLE"), ComparableVersion::new("1.0"));
+/// assert!(parse("1.0-preview") < parse("1.0-rc"));
+/// assert_eq!(parse("1.0-preview-2").qualifiers().next(), Some((Qualifier::Beta, Some(2))));
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Default)]
+pub struct QualifierTable {
+    aliases: BTreeMap<String, String>,
+}
+
+/// The error returned when a version string is rejected by [`ComparableVersion::with_options`].
+/// It carries the kind of problem encountered and the range of bytes of the string at fault.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct VersionParseError {
+    kind: VersionParseErrorKind,
+    span: Range<usize>,
+}
+
+/// The specific reason a version string was rejected.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[non_exhaustive]
+pub enum VersionParseErrorKind {
+    /// The version was longer than [`ParseOptions::max_length`].
+    TooLong,
+    /// The version was empty, which is rejected in [strict] mode.
+    ///
+    /// [strict]: ParseOptions::strict
+    Empty,
+    /// The version contained whitespace, which is rejected in [strict] mode and with
+    /// [`ParseOptions::reject_whitespace`].
+    ///
+    /// [strict]: ParseOptions::strict
+    Whitespace,
+    /// The version contained a control character other than whitespace, which is rejected in
+    /// [strict] mode.
+    ///
+    /// [strict]: ParseOptions::strict
+    ControlCharacter(char),
+    /// The version had more segments than [`ParseOptions::max_segments`].
+    TooManySegments,
+    /// A number in the version had more digits than [`ParseOptions::max_digits`].
+    TooManyDigits,
+}
+
+impl ParseOptions {
+    /// Constructs the default options, which parse exactly like [`ComparableVersion::new`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether leading and trailing whitespace is removed before parsing, in which case the
+    /// [original string] of the version doesn't include it either. Off by default, so whitespace
+    /// is treated like any other text.
+    ///
+    /// [original string]: ComparableVersion::as_str
+    pub fn trim_whitespace(mut self, trim: bool) -> Self {
+        self.trim_whitespace = trim;
+        self
+    }
+
+    /// Sets the maximum length of a version in bytes, after trimming whitespace. Longer versions
+    /// are rejected with [`VersionParseErrorKind::TooLong`]. Unlimited by default.
+    pub fn max_length(mut self, max_length: Option<usize>) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    /// Sets the maximum number of segments of a version, i.e. parts separated by dashes or by
+    /// transitions between digits and letters. Segments are counted as written, including empty
+    /// ones such as the middle one of `1--2`. More segments are rejected with
+    /// [`VersionParseErrorKind::TooManySegments`]. Unlimited by default.
+    pub fn max_segments(mut self, max_segments: Option<usize>) -> Self {
+        self.max_segments = max_segments;
+        self
+    }
+
+    /// Sets the maximum number of digits of each number in a version, including leading zeroes.
+    /// Longer numbers are rejected with [`VersionParseErrorKind::TooManyDigits`]. Unlimited by
+    /// default.
+    pub fn max_digits(mut self, max_digits: Option<usize>) -> Self {
+        self.max_digits = max_digits;
+        self
+    }
+
+    /// Sets whether versions containing whitespace are rejected with
+    /// [`VersionParseErrorKind::Whitespace`]. Combined with [`Self::trim_whitespace`], this
+    /// accepts stray whitespace around a version, as found in XML and properties files, but not
+    /// inside it. Off by default, and implied by [`Self::strict`].
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, ParseOptions, VersionParseErrorKind};
+    /// let options = ParseOptions::new().trim_whitespace(true).reject_whitespace(true);
+    /// assert!(ComparableVersion::with_options("\t1.0-RC1 \n", &options).is_ok());
+    ///
+    /// let error = ComparableVersion::with_options(" 1.0 RC1 ", &options).unwrap_err();
+    /// assert_eq!(error.kind(), VersionParseErrorKind::Whitespace);
+    /// assert_eq!(error.span(), 4..5);
+    /// ```
+    pub fn reject_whitespace(mut self, reject: bool) -> Self {
+        self.reject_whitespace = reject;
+        self
+    }
+
+    /// Sets whether versions which Maven accepts but which are almost certainly mistakes are
+    /// rejected: empty versions, and ones containing whitespace or control characters. Whitespace
+    /// removed by [`Self::trim_whitespace`] doesn't count. Off by default.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Sets whether any Unicode decimal digit, such as `२` or `٣`, counts as a digit, like in
+    /// Maven's Java implementation. Otherwise only ASCII digits do, and other digits are parts of
+    /// qualifiers. Off by default. Only the parsed segments are affected, not
+    /// [`ComparableVersion::tokens`].
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, ParseOptions};
+    /// let options = ParseOptions::new().unicode_digits(true);
+    /// let version = ComparableVersion::with_options("१.२-RC३", &options).unwrap();
+    /// assert_eq!(version, ComparableVersion::new("1.2-RC3"));
+    /// ```
+    pub fn unicode_digits(mut self, unicode_digits: bool) -> Self {
+        self.unicode_digits = unicode_digits;
+        self
+    }
+
+    /// Sets whether qualifiers are lowercased with full Unicode case mappings, like in Maven's Java
+    /// implementation, which uses `toLowerCase(Locale.ENGLISH)`. Otherwise only ASCII letters are
+    /// lowercased, and other letters are compared exactly as written. Off by default. Aliases in
+    /// a [QualifierTable] and qualifiers given to a [QualifierRanking] are only ASCII-lowercased
+    /// either way, so with this option, write other letters in them in lowercase.
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, ParseOptions};
+    /// let options = ParseOptions::new().unicode_case(true);
+    /// let parse = |s| ComparableVersion::with_options(s, &options).unwrap();
+    /// assert_eq!(parse("1.0-ÉTÉ"), parse("1.0-été"));
+    /// assert_ne!(ComparableVersion::new("1.0-ÉTÉ"), ComparableVersion::new("1.0-été"));
+    ///
+    /// // Dotted capital I lowercases to an `i` and a combining dot, like in Java
+    /// assert_eq!(parse("1-İ").canonical(), "1-i\u{307}");
+    /// ```
+    ///
+    /// [QualifierRanking]: crate::QualifierRanking
+    pub fn unicode_case(mut self, unicode_case: bool) -> Self {
+        self.unicode_case = unicode_case;
+        self
+    }
+
+    /// Sets whether qualifiers Maven doesn't know keep their case, so that `Foo` and `foo` are
+    /// different qualifiers, with `Foo` coming first. Maven's own qualifiers and aliases, along
+    /// with those in [`Self::qualifiers`], are still recognized in any case. Off by default, so
+    /// all qualifiers are lowercased.
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, ParseOptions};
+    /// let options = ParseOptions::new().case_sensitive(true);
+    /// let parse = |s| ComparableVersion::with_options(s, &options).unwrap();
+    ///
+    /// assert_ne!(parse("1.0-Preview"), parse("1.0-preview"));
+    /// assert!(parse("1.0-Preview") < parse("1.0-preview"));
+    /// assert_eq!(parse("1.0-Beta"), parse("1.0-beta"));
+    /// assert_eq!(parse("1.0-Preview").canonical(), "1-Preview");
+    /// ```
+    pub fn case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Sets extra qualifier aliases, on top of the ones Maven knows. See [QualifierTable]. Only
+    /// the parsed segments are affected, not [`ComparableVersion::tokens`]. None by default.
+    pub fn qualifiers(mut self, qualifiers: QualifierTable) -> Self {
+        self.qualifiers = qualifiers;
+        self
+    }
+}
+
+impl ParseOptions {
+    /// Options which refuse pathological versions, for parsing strings from untrusted sources.
+    /// Versions are limited to 256 bytes, 32 segments and 64 digits per number, which is far more
+    /// than any real version needs.
+    pub fn limited() -> Self {
+        Self::new()
+            .max_length(Some(256))
+            .max_segments(Some(32))
+            .max_digits(Some(64))
+    }
+
+    /// Whether a character counts as a digit.
+    pub(super) fn digit(&self) -> fn(char) -> bool {
+        // Java will happily accept any Unicode digit as a number, but if anyone is using २ instead
+        // of 2 they are Doing It Wrong, so that's opt-in
+        if self.unicode_digits {
+            digits::is_decimal
+        } else {
+            |c| c.is_ascii_digit()
+        }
+    }
+
+    /// Checks the segment and digit limits without allocating anything, so that pathological
+    /// versions are refused before they're parsed.
+    fn check_limits(&self, s: &str) -> Result<(), (VersionParseErrorKind, Range<usize>)> {
+        let too_many = |limit: Option<usize>, n: usize| limit.is_some_and(|limit| n > limit);
+        let mut segments = 1;
+        let mut after_item = false;
+
+        for token in Tokens::new(s, self.digit()) {
+            let span = token.span();
+
+            if token.kind() == TokenKind::Number
+                && too_many(self.max_digits, token.as_str().chars().count())
+            {
+                return Err((VersionParseErrorKind::TooManyDigits, span));
+            }
+
+            // Segments start after dashes and where numbers and qualifiers meet
+            let segment_start = match token.kind() {
+                TokenKind::Dash => Some(span.end),
+                TokenKind::Dot => None,
+                _ if after_item => Some(span.start),
+                _ => None,
+            };
+            after_item = !token.is_separator();
+
+            if let Some(start) = segment_start {
+                segments += 1;
+                if too_many(self.max_segments, segments) {
+                    return Err((VersionParseErrorKind::TooManySegments, start..s.len()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl QualifierTable {
+    /// Constructs a table without any aliases of its own.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an alias for a qualifier. The empty qualifier stands for a release. The qualifier can
+    /// itself be one of Maven's aliases, like `ga`, but not one from this table. An alias which
+    /// is also one of Maven's, like `cr`, takes precedence over it.
+    pub fn alias(mut self, alias: &str, qualifier: &str) -> Self {
+        self.aliases
+            .insert(alias.to_ascii_lowercase(), qualifier.to_ascii_lowercase());
+        self
+    }
+
+    /// Returns the qualifier the given alias stands for, if it's in this table.
+    ///
+    /// ```
+    /// # use mvn_version::QualifierTable;
+    /// let table = QualifierTable::new().alias("Stable", "");
+    /// assert_eq!(table.get("STABLE"), Some(""));
+    /// assert_eq!(table.get("ga"), None);
+    /// ```
+    pub fn get(&self, alias: &str) -> Option<&str> {
+        self.aliases
+            .get(&alias.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    /// Whether the table has no aliases of its own.
+    pub fn is_empty(&self) -> bool {
+        self.aliases.is_empty()
+    }
+
+    /// Replaces a lowercase qualifier by what it stands for, if it's in this table.
+    pub(super) fn expand<'a>(&self, s: Cow<'a, str>) -> Cow<'a, str> {
+        match self.aliases.get(&*s) {
+            Some(qualifier) => Cow::Owned(qualifier.clone()),
+            None => s,
+        }
+    }
+}
+
+impl ComparableVersion {
+    /// Constructs a ComparableVersion from the given string according to the given options,
+    /// failing if the string doesn't satisfy them. See [ParseOptions].
+    pub fn with_options(s: &str, options: &ParseOptions) -> Result<Self, VersionParseError> {
+        let (start, s) = if options.trim_whitespace {
+            let trimmed = s.trim_start();
+            (s.len() - trimmed.len(), trimmed.trim_end())
+        } else {
+            (0, s)
+        };
+
+        let error = |kind, span: Range<usize>| {
+            Err(VersionParseError::new(
+                kind,
+                start + span.start..start + span.end,
+            ))
+        };
+
+        match options.max_length {
+            Some(max_length) if s.len() > max_length => {
+                // Point at the first character which doesn't fit entirely
+                let end = (0..=max_length)
+                    .rev()
+                    .find(|i| s.is_char_boundary(*i))
+                    .unwrap_or(0);
+                return error(VersionParseErrorKind::TooLong, end..s.len());
+            }
+            _ => {}
+        }
+
+        if options.strict && s.is_empty() {
+            return error(VersionParseErrorKind::Empty, 0..0);
+        }
+
+        if options.strict || options.reject_whitespace {
+            for (i, c) in s.char_indices() {
+                if c.is_whitespace() {
+                    let len = s[i..]
+                        .chars()
+                        .take_while(|c| c.is_whitespace())
+                        .map(char::len_utf8)
+                        .sum::<usize>();
+                    return error(VersionParseErrorKind::Whitespace, i..i + len);
+                } else if c.is_control() && options.strict {
+                    return error(
+                        VersionParseErrorKind::ControlCharacter(c),
+                        i..i + c.len_utf8(),
+                    );
+                }
+            }
+        }
+
+        if let Err((kind, span)) = options.check_limits(s) {
+            return error(kind, span);
+        }
+
+        let segments = Self::parse_segments(s, options)
+            .into_iter()
+            .map(Segment::into_owned)
+            .collect();
+        Ok(Self::from_segments(s.to_string(), segments))
+    }
+
+    /// Constructs a ComparableVersion from the given string, rejecting versions which Maven
+    /// accepts but which are almost certainly mistakes. This is [`Self::with_options`] with
+    /// [strict] options.
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, VersionParseErrorKind};
+    /// assert!(ComparableVersion::try_new_strict("1.0-RC1").is_ok());
+    ///
+    /// let error = ComparableVersion::try_new_strict("1.0  RC1").unwrap_err();
+    /// assert_eq!(error.kind(), VersionParseErrorKind::Whitespace);
+    /// assert_eq!(error.span(), 3..5);
+    /// ```
+    ///
+    /// [strict]: ParseOptions::strict
+    pub fn try_new_strict(s: &str) -> Result<Self, VersionParseError> {
+        Self::with_options(s, &ParseOptions::new().strict(true))
+    }
+
+    /// Constructs a ComparableVersion from the given string, refusing pathological versions which
+    /// would take a lot of memory or time to parse. This is [`Self::with_options`] with
+    /// [`ParseOptions::limited`], and is meant for versions from untrusted sources, such as
+    /// uploaded POMs.
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, VersionParseErrorKind};
+    /// assert!(ComparableVersion::try_new_limited("1.0-20240101.123456-1").is_ok());
+    ///
+    /// let error = ComparableVersion::try_new_limited(&"1".repeat(100)).unwrap_err();
+    /// assert_eq!(error.kind(), VersionParseErrorKind::TooManyDigits);
+    ///
+    /// let error = ComparableVersion::try_new_limited(&"a1".repeat(20)).unwrap_err();
+    /// assert_eq!(error.kind(), VersionParseErrorKind::TooManySegments);
+    /// ```
+    pub fn try_new_limited(s: &str) -> Result<Self, VersionParseError> {
+        Self::with_options(s, &ParseOptions::limited())
+    }
+}
+
+impl VersionParseError {
+    fn new(kind: VersionParseErrorKind, span: Range<usize>) -> Self {
+        VersionParseError { kind, span }
+    }
+
+    /// What went wrong.
+    pub fn kind(&self) -> VersionParseErrorKind {
+        self.kind
+    }
+
+    /// The byte offset into the string at which the problem was detected, i.e. the start of
+    /// [`Self::span`].
+    pub fn offset(&self) -> usize {
+        self.span.start
+    }
+
+    /// The range of bytes of the string at fault. For [`VersionParseErrorKind::TooLong`], this
+    /// is the part beyond the maximum length, starting with the first character which doesn't
+    /// fit entirely.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl Display for VersionParseErrorKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            VersionParseErrorKind::TooLong => f.write_str("version too long"),
+            VersionParseErrorKind::Empty => f.write_str("empty version"),
+            VersionParseErrorKind::Whitespace => f.write_str("whitespace in version"),
+            VersionParseErrorKind::ControlCharacter(c) => {
+                write!(f, "control character {:?} in version", c)
+            }
+            VersionParseErrorKind::TooManySegments => f.write_str("too many segments in version"),
+            VersionParseErrorKind::TooManyDigits => f.write_str("too many digits in number"),
+        }
+    }
+}
+
+impl Display for VersionParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.offset())
+    }
+}
+
+impl Error for VersionParseError {}
@@ -0,0 +1,148 @@
+use super::item::Item;
+use super::ComparableVersion;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A version pattern containing a single `*` wildcard, such as `1.2.*` or `*-SNAPSHOT`.
+///
+/// Matching is performed on the parsed versions rather than on their strings:
+///
+/// - The numeric components before the wildcard must equal the leading components of the
+///   version, with missing components treated as zero. So `1.2.*` matches `1.2`, `1.2.0` and
+///   `1.2.5-beta-1`, but neither `1.20` nor `1`.
+/// - The part after the wildcard, if any, must equal the trailing segments of the version. So
+///   `*-SNAPSHOT` matches `1.0-SNAPSHOT` and `1.0-alpha-1-snapshot`, but not `1.0`.
+/// - A pattern without a wildcard matches versions equal to it, and `*` matches everything.
+///
+/// ```
+/// # use mvn_version::{ComparableVersion, VersionPattern};
+/// let pattern = VersionPattern::new("1.2.*").unwrap();
+/// assert!(pattern.matches(&ComparableVersion::new("1.2")));
+/// assert!(pattern.matches(&ComparableVersion::new("1.2.3")));
+/// assert!(!pattern.matches(&ComparableVersion::new("1.20")));
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct VersionPattern {
+    orig: String,
+    kind: PatternKind,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+enum PatternKind {
+    Exact(ComparableVersion),
+    Wildcard {
+        prefix: Vec<Item<'static>>,
+        suffix: Option<ComparableVersion>,
+    },
+}
+
+impl VersionPattern {
+    /// Parses a version pattern. The wildcard may be preceded by dot-separated numbers, e.g.
+    /// `1.2.*`, and followed by a dash and a qualifier, e.g. `*-SNAPSHOT`.
+    pub fn new(s: &str) -> Result<Self, VersionPatternError> {
+        let star = match s.find('*') {
+            Some(star) => star,
+            None => {
+                return Ok(VersionPattern {
+                    orig: s.to_string(),
+                    kind: PatternKind::Exact(ComparableVersion::new(s)),
+                })
+            }
+        };
+
+        if let Some(i) = s[star + 1..].find('*') {
+            return Err(VersionPatternError {
+                offset: star + 1 + i,
+            });
+        }
+
+        let mut prefix = Vec::new();
+        if star > 0 {
+            let numbers = match s[..star].strip_suffix('.') {
+                Some(numbers) => numbers,
+                None => return Err(VersionPatternError { offset: star }),
+            };
+
+            let mut start = 0;
+            for number in numbers.split('.') {
+                if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(VersionPatternError { offset: start });
+                }
+
+                prefix.push(Item::from_digits(number));
+                start += number.len() + 1;
+            }
+        }
+
+        let suffix = match &s[star + 1..] {
+            "" => None,
+            rest => match rest.strip_prefix('-') {
+                Some(qualifier) => Some(ComparableVersion::new(qualifier)),
+                None => return Err(VersionPatternError { offset: star + 1 }),
+            },
+        };
+
+        Ok(VersionPattern {
+            orig: s.to_string(),
+            kind: PatternKind::Wildcard { prefix, suffix },
+        })
+    }
+
+    /// Returns whether the given version matches this pattern.
+    pub fn matches(&self, version: &ComparableVersion) -> bool {
+        match &self.kind {
+            PatternKind::Exact(exact) => exact == version,
+            PatternKind::Wildcard { prefix, suffix } => {
+                let suffix_matches = match suffix {
+                    Some(suffix) => version.has_suffix(suffix),
+                    None => true,
+                };
+
+                version.has_numeric_prefix(prefix) && suffix_matches
+            }
+        }
+    }
+
+    /// Returns the original string representation of the pattern.
+    pub fn as_str(&self) -> &str {
+        &self.orig
+    }
+}
+
+impl Display for VersionPattern {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.orig)
+    }
+}
+
+impl FromStr for VersionPattern {
+    type Err = VersionPatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+/// The error returned when a version pattern is malformed, e.g. it has more than one wildcard or
+/// something other than numbers before the wildcard.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct VersionPatternError {
+    offset: usize,
+}
+
+impl VersionPatternError {
+    /// The byte offset into the pattern at which the problem was detected.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Display for VersionPatternError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "invalid version pattern at byte {}", self.offset)
+    }
+}
+
+impl Error for VersionPatternError {}
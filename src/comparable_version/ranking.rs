@@ -0,0 +1,129 @@
+// Custom precedence of qualifiers, for builds which redefine Maven's.
+
+use super::item::{self, Ranking};
+use super::{ComparableVersion, Qualifier};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+/// A precedence of qualifiers to compare versions by instead of Maven's, for
+/// [`ComparableVersion::cmp_with_ranking`]. Ranked qualifiers are ordered as given, and all others
+/// come after them in lexical order, like in Maven. Qualifiers are case-insensitive and Maven's
+/// aliases are expanded, so `CR` is the same as `rc` and `ga` the same as the empty qualifier,
+/// which is a release.
+///
+/// ```
+/// # use mvn_version::{ComparableVersion, QualifierRanking};
+/// # use std::cmp::Ordering;
+/// let ranking = QualifierRanking::new().insert_before("ea", "rc");
+/// let ea = ComparableVersion::new("21-ea");
+/// let rc = ComparableVersion::new("21-rc");
+/// let milestone = ComparableVersion::new("21-M1");
+///
+/// assert!(ea > rc);
+/// assert_eq!(ea.cmp_with_ranking(&rc, &ranking), Ordering::Less);
+/// assert_eq!(ea.cmp_with_ranking(&milestone, &ranking), Ordering::Greater);
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct QualifierRanking {
+    order: Vec<String>,
+}
+
+impl Default for QualifierRanking {
+    /// Returns Maven's own ranking.
+    fn default() -> Self {
+        Self::from_order(Qualifier::KNOWN.iter().map(|q| q.as_str()))
+    }
+}
+
+impl QualifierRanking {
+    /// Constructs Maven's own ranking, i.e. [`Qualifier::KNOWN`], to build on.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Constructs a ranking of the given qualifiers, in ascending order. Any qualifier which isn't
+    /// given comes after them, including ones Maven knows.
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, QualifierRanking};
+    /// # use std::cmp::Ordering;
+    /// let ranking = QualifierRanking::from_order(["dev", "rc", "", "sp"]);
+    /// let dev = ComparableVersion::new("1.0-dev");
+    /// let alpha = ComparableVersion::new("1.0-alpha");
+    /// assert_eq!(dev.cmp_with_ranking(&alpha, &ranking), Ordering::Less);
+    /// ```
+    pub fn from_order<I>(qualifiers: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut ranking = QualifierRanking { order: Vec::new() };
+        for qualifier in qualifiers {
+            let qualifier = normalize(qualifier.as_ref());
+            ranking.order.retain(|q| *q != qualifier);
+            ranking.order.push(qualifier);
+        }
+
+        ranking
+    }
+
+    /// Ranks a qualifier right before another one, or after all ranked qualifiers if the other one
+    /// isn't ranked. If the qualifier was already ranked, it's moved.
+    pub fn insert_before(mut self, qualifier: &str, before: &str) -> Self {
+        let qualifier = normalize(qualifier);
+        let before = normalize(before);
+
+        self.order.retain(|q| *q != qualifier);
+        let index = self
+            .order
+            .iter()
+            .position(|q| *q == before)
+            .unwrap_or(self.order.len());
+        self.order.insert(index, qualifier);
+        self
+    }
+
+    /// The position of a qualifier in the ranking, from 0 for the lowest, or `None` if it isn't
+    /// ranked.
+    ///
+    /// ```
+    /// # use mvn_version::QualifierRanking;
+    /// let ranking = QualifierRanking::new();
+    /// assert_eq!(ranking.rank("alpha"), Some(0));
+    /// assert_eq!(ranking.rank("GA"), Some(5));
+    /// assert_eq!(ranking.rank("foo"), None);
+    /// ```
+    pub fn rank(&self, qualifier: &str) -> Option<usize> {
+        let qualifier = normalize(qualifier);
+        self.order.iter().position(|q| *q == qualifier)
+    }
+
+    fn key<'a>(&self, qualifier: &'a str) -> (usize, &'a str) {
+        match self.order.iter().position(|q| q == qualifier) {
+            Some(rank) => (rank, ""),
+            None => (self.order.len(), qualifier),
+        }
+    }
+}
+
+impl Ranking for QualifierRanking {
+    fn cmp_qualifiers(&self, s: &str, t: &str) -> Ordering {
+        self.key(s).cmp(&self.key(t))
+    }
+}
+
+fn normalize(qualifier: &str) -> String {
+    item::expand_alias(Cow::Owned(qualifier.to_ascii_lowercase()), false).into_owned()
+}
+
+impl ComparableVersion {
+    /// Compares with another version, ranking qualifiers the given way instead of Maven's. See
+    /// [QualifierRanking].
+    pub fn cmp_with_ranking(
+        &self,
+        other: &ComparableVersion,
+        ranking: &QualifierRanking,
+    ) -> Ordering {
+        item::cmp_segments_by(&self.segments, &other.segments, ranking)
+    }
+}
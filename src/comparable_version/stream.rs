@@ -0,0 +1,356 @@
+// Comparison of version strings straight from their tokens, without parsing them into segments.
+// This mirrors `parse_segments`, `Segment::new` and `normalize` with the default options, so any
+// change to those has to be made here too.
+
+use super::{Padding, Qualifier, TokenKind, Tokens};
+use std::cmp::Ordering;
+use std::hash::Hasher;
+use std::mem;
+
+/// An item of a version, borrowed from the version string.
+#[derive(Clone, Copy)]
+enum RawItem<'a> {
+    /// ASCII digits without leading zeroes, so that longer numbers are larger ones.
+    Number(&'a str),
+    /// A qualifier with its aliases expanded. Other qualifiers are kept as written and only
+    /// lowercased when compared.
+    Qualifier(Qualifier<'a>),
+}
+
+#[derive(Clone, Copy)]
+enum Event<'a> {
+    Item(RawItem<'a>),
+    SegmentEnd,
+}
+
+/// The items of a version and the ends of its segments, in order, before trailing nulls are
+/// stripped.
+#[derive(Clone)]
+struct Events<'a> {
+    tokens: Tokens<'a>,
+    /// The item being read, if any
+    current: Option<(&'a str, TokenKind)>,
+    /// Whether the next event is the end of a segment, since some tokens end both an item and a
+    /// segment
+    segment_end: bool,
+}
+
+/// A normalized segment of a version.
+struct StreamSegment<'a> {
+    /// The events from the start of the segment
+    events: Events<'a>,
+    /// The number of items left after stripping trailing nulls
+    len: usize,
+    last: bool,
+}
+
+/// The normalized segments of a version.
+struct Segments<'a> {
+    events: Events<'a>,
+    /// The index of the next item
+    index: usize,
+    /// The index of the last item which isn't null, after which the version is all padding
+    last_item: Option<usize>,
+}
+
+impl<'a> RawItem<'a> {
+    fn new(s: &'a str, kind: TokenKind, followed_by_digit: bool) -> Self {
+        if kind == TokenKind::Number {
+            return RawItem::Number(s.trim_start_matches('0'));
+        }
+
+        let is = |q: &str| s.eq_ignore_ascii_case(q);
+        let qualifier = if is("alpha") || (followed_by_digit && is("a")) {
+            Qualifier::Alpha
+        } else if is("beta") || (followed_by_digit && is("b")) {
+            Qualifier::Beta
+        } else if is("milestone") || (followed_by_digit && is("m")) {
+            Qualifier::Milestone
+        } else if is("rc") || is("cr") {
+            Qualifier::Rc
+        } else if is("snapshot") {
+            Qualifier::Snapshot
+        } else if is("ga") || is("final") || is("release") {
+            Qualifier::Release
+        } else if is("sp") {
+            Qualifier::Sp
+        } else {
+            Qualifier::Other(s)
+        };
+
+        RawItem::Qualifier(qualifier)
+    }
+
+    fn is_null(&self) -> bool {
+        matches!(
+            self,
+            RawItem::Number("") | RawItem::Qualifier(Qualifier::Release)
+        )
+    }
+
+    fn cmp_padding(&self, padding: Padding) -> Ordering {
+        match (self, padding) {
+            (RawItem::Qualifier(_), Padding::NextSegment) => Ordering::Less,
+            (RawItem::Qualifier(q), Padding::Null) => q.cmp(&Qualifier::Release),
+            (RawItem::Number(""), Padding::Null) => Ordering::Equal,
+            (RawItem::Number(_), _) => Ordering::Greater,
+        }
+    }
+
+    /// Hashes the item consistently with how it compares, so qualifiers are lowercased.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            RawItem::Number(i) => {
+                state.write_u8(0);
+                state.write(i.as_bytes());
+            }
+            RawItem::Qualifier(Qualifier::Other(s)) => {
+                state.write_u8(1);
+                s.bytes()
+                    .for_each(|b| state.write_u8(b.to_ascii_lowercase()));
+            }
+            RawItem::Qualifier(q) => state.write_u8(2 + q.rank()),
+        }
+
+        // Neither numbers nor qualifiers can contain a dot, so this keeps `1.11` and `11.1` apart
+        state.write_u8(b'.');
+    }
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (RawItem::Number(i), RawItem::Number(j)) => i.len().cmp(&j.len()).then(i.cmp(j)),
+            (RawItem::Qualifier(Qualifier::Other(s)), RawItem::Qualifier(Qualifier::Other(t))) => {
+                let lowercase = |s: &'a str| s.bytes().map(|b| b.to_ascii_lowercase());
+                lowercase(s).cmp(lowercase(t))
+            }
+            (RawItem::Qualifier(q), RawItem::Qualifier(r)) => q.rank().cmp(&r.rank()),
+            (RawItem::Qualifier(_), RawItem::Number(_)) => Ordering::Less,
+            (RawItem::Number(_), RawItem::Qualifier(_)) => Ordering::Greater,
+        }
+    }
+}
+
+impl<'a> Events<'a> {
+    fn new(s: &'a str) -> Self {
+        Events {
+            tokens: Tokens::new(s, |c| c.is_ascii_digit()),
+            current: None,
+            segment_end: false,
+        }
+    }
+
+    /// Ends the item being read, which is `0` if there isn't one.
+    fn take_item(&mut self, followed_by_digit: bool) -> RawItem<'a> {
+        match self.current.take() {
+            Some((s, kind)) => RawItem::new(s, kind, followed_by_digit),
+            None => RawItem::Number(""),
+        }
+    }
+}
+
+impl<'a> Iterator for Events<'a> {
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if mem::take(&mut self.segment_end) {
+            return Some(Event::SegmentEnd);
+        }
+
+        loop {
+            let token = match self.tokens.next() {
+                Some(token) => token,
+                None => {
+                    let item = self.current.take()?;
+                    return Some(Event::Item(RawItem::new(item.0, item.1, false)));
+                }
+            };
+
+            match token.kind() {
+                TokenKind::Dot | TokenKind::Dash => {
+                    self.segment_end = token.kind() == TokenKind::Dash;
+                    return Some(Event::Item(self.take_item(false)));
+                }
+                kind => {
+                    let previous = self.current.replace((token.as_str(), kind));
+
+                    // Boundary between digit and non-digit
+                    if let Some((s, previous_kind)) = previous {
+                        self.segment_end = true;
+                        let item = RawItem::new(s, previous_kind, kind == TokenKind::Number);
+                        return Some(Event::Item(item));
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a> StreamSegment<'a> {
+    fn items(&self) -> impl Iterator<Item = RawItem<'a>> {
+        self.events
+            .clone()
+            .map_while(|event| match event {
+                Event::Item(item) => Some(item),
+                Event::SegmentEnd => None,
+            })
+            .take(self.len)
+    }
+
+    fn padding(&self) -> Padding {
+        if self.last {
+            Padding::Null
+        } else {
+            Padding::NextSegment
+        }
+    }
+
+    /// Where this segment stands in comparison to no segment at all.
+    fn cmp_null(&self) -> Ordering {
+        first_difference(self.items().map(|item| item.cmp_padding(Padding::Null)))
+    }
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        let mut left = self.items();
+        let mut right = other.items();
+
+        first_difference(std::iter::from_fn(|| match (left.next(), right.next()) {
+            (Some(l), Some(r)) => Some(l.cmp(&r)),
+            (Some(l), None) => Some(l.cmp_padding(other.padding())),
+            (None, Some(r)) => Some(r.cmp_padding(self.padding()).reverse()),
+            (None, None) => None,
+        }))
+    }
+}
+
+impl<'a> Segments<'a> {
+    fn new(s: &'a str) -> Self {
+        let events = Events::new(s);
+        let last_item = events
+            .clone()
+            .filter_map(|event| match event {
+                Event::Item(item) => Some(item),
+                Event::SegmentEnd => None,
+            })
+            .enumerate()
+            .filter(|(_, item)| !item.is_null())
+            .last()
+            .map(|(i, _)| i);
+
+        Segments {
+            events,
+            index: 0,
+            last_item,
+        }
+    }
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = StreamSegment<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Trailing null segments are stripped
+        let last_item = self.last_item.filter(|i| *i >= self.index)?;
+        let start = self.events.clone();
+        let mut len = 0;
+        let mut items = 0;
+
+        while let Some(Event::Item(item)) = self.events.next() {
+            items += 1;
+            if !item.is_null() {
+                len = items;
+            }
+        }
+
+        self.index += items;
+        Some(StreamSegment {
+            events: start,
+            len,
+            last: last_item < self.index,
+        })
+    }
+}
+
+fn first_difference(mut orders: impl Iterator<Item = Ordering>) -> Ordering {
+    orders
+        .find(|order| *order != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Compares two version strings like [ComparableVersion](super::ComparableVersion) does, without
+/// parsing them into versions first. Nothing is allocated, which makes this much faster for
+/// sorting lots of version strings which are only compared once or twice each.
+///
+/// ```
+/// # use mvn_version::{cmp_str, ComparableVersion};
+/// # use std::cmp::Ordering;
+/// assert_eq!(cmp_str("1.0-RC1", "1.0-beta-2"), Ordering::Greater);
+/// assert_eq!(cmp_str("1.0.0.GA", "1"), Ordering::Equal);
+///
+/// let mut versions = vec!["1.10", "1.2-SNAPSHOT", "1.2", "1.0a1"];
+/// versions.sort_by(|a, b| cmp_str(a, b));
+/// assert_eq!(versions, ["1.0a1", "1.2-SNAPSHOT", "1.2", "1.10"]);
+/// ```
+pub fn cmp_str(a: &str, b: &str) -> Ordering {
+    let mut left = Segments::new(a);
+    let mut right = Segments::new(b);
+
+    first_difference(std::iter::from_fn(|| match (left.next(), right.next()) {
+        (Some(l), Some(r)) => Some(l.cmp(&r)),
+        (Some(l), None) => Some(l.cmp_null()),
+        (None, Some(r)) => Some(r.cmp_null().reverse()),
+        (None, None) => None,
+    }))
+}
+
+/// Whether two version strings are equal like [ComparableVersion](super::ComparableVersion)s
+/// parsed from them would be, without parsing them. See [cmp_str].
+///
+/// ```
+/// # use mvn_version::eq_str;
+/// assert!(eq_str("1.0.0-RC1", "1-cr-1"));
+/// assert!(!eq_str("1.0-RC1", "1.0-RC2"));
+/// ```
+pub fn eq_str(a: &str, b: &str) -> bool {
+    cmp_str(a, b) == Ordering::Equal
+}
+
+/// Hashes a version string such that strings which are [equal](eq_str) hash the same, without
+/// parsing it or allocating anything. Together with [eq_str], this lets borrowed version strings
+/// be used as keys of hash maps. The hash isn't the same as that of a
+/// [ComparableVersion](super::ComparableVersion).
+///
+/// ```
+/// # use mvn_version::{eq_str, hash_str};
+/// # use std::collections::HashSet;
+/// # use std::hash::{Hash, Hasher};
+/// struct Key<'a>(&'a str);
+///
+/// impl PartialEq for Key<'_> {
+///     fn eq(&self, other: &Self) -> bool {
+///         eq_str(self.0, other.0)
+///     }
+/// }
+///
+/// impl Eq for Key<'_> {}
+///
+/// impl Hash for Key<'_> {
+///     fn hash<H: Hasher>(&self, state: &mut H) {
+///         hash_str(self.0, state)
+///     }
+/// }
+///
+/// let index = "1.0\n1.0.0\n1-GA\n1.1-SNAPSHOT\n1.1-snapshot";
+/// let distinct = index.lines().map(Key).collect::<HashSet<_>>();
+/// assert_eq!(distinct.len(), 2);
+/// ```
+pub fn hash_str<H: Hasher>(s: &str, state: &mut H) {
+    let mut count = 0;
+
+    for segment in Segments::new(s) {
+        segment.items().for_each(|item| item.hash(state));
+        state.write_u8(b'-');
+        count += 1;
+    }
+
+    state.write_usize(count);
+}
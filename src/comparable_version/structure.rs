@@ -0,0 +1,583 @@
+// A read-only public view of the segments and items a ComparableVersion is parsed into, without
+// exposing the internal types themselves.
+
+use super::item::{Item, Segment};
+use super::ComparableVersion;
+use crate::BigUint;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// A segment of a parsed [ComparableVersion], such as `1.2.3` in `1.2.3-beta-1`. Trailing null
+/// items, i.e. zeroes and empty qualifiers, have already been stripped from it.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub struct VersionSegment<'a> {
+    segment: &'a Segment<'static>,
+}
+
+/// A single item of a [VersionSegment], i.e. a number or a qualifier.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum VersionItem<'a> {
+    /// A number which fits into 9 decimal digits.
+    Int(u64),
+    /// A larger number.
+    BigInt(&'a BigUint),
+    /// A qualifier, which is either one of the special qualifiers Maven knows or free text.
+    Qualifier(Qualifier<'a>),
+}
+
+/// What Maven compares an item with when the other segment has run out of items, as returned by
+/// [`VersionSegment::padding`]. This depends on whether the shorter segment is the last one of
+/// its version: `1.snapshot` against `1` compares `snapshot` with a release, but `1.1` against
+/// `1-1` compares the second `1` with the start of the next segment.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum Padding {
+    /// Padding in the last segment of a version, which is equal to both `0` and a release. Whole
+    /// segments missing from the end of a version are padded with this too.
+    Null,
+    /// Padding in a segment followed by others, which stands for the start of the next segment:
+    /// any number is greater than it, and any qualifier is less.
+    NextSegment,
+}
+
+/// An item of a [VersionSegment], or the padding after its last item, as returned by
+/// [`VersionSegment::padded_iter`].
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum PaddedItem<'a> {
+    /// An actual item of the segment.
+    Item(VersionItem<'a>),
+    /// Padding after the last item.
+    Padding(Padding),
+}
+
+/// A qualifier item of a [VersionSegment]. Qualifiers are case-insensitive, and aliases such as
+/// `cr` for `rc` or `a1` for `alpha-1` have already been expanded.
+///
+/// Qualifiers are ordered the same way as when comparing versions, i.e. the variants are in
+/// ascending order and other qualifiers are ordered lexically after all known ones.
+///
+/// ```
+/// # use mvn_version::Qualifier;
+/// assert!(Qualifier::Snapshot < Qualifier::Release);
+/// assert!(Qualifier::Release < Qualifier::Sp);
+/// assert!(Qualifier::Sp < Qualifier::Other("foo"));
+///
+/// let table = Qualifier::KNOWN.iter().map(|q| q.as_str()).collect::<Vec<_>>();
+/// assert_eq!(table, ["alpha", "beta", "milestone", "rc", "snapshot", "", "sp"]);
+/// ```
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy)]
+pub enum Qualifier<'a> {
+    /// `alpha`, or `a` when directly followed by a number.
+    Alpha,
+    /// `beta`, or `b` when directly followed by a number.
+    Beta,
+    /// `milestone`, or `m` when directly followed by a number.
+    Milestone,
+    /// `rc` or `cr`.
+    Rc,
+    /// `snapshot`.
+    Snapshot,
+    /// `ga`, `final` or `release`, which are all equivalent to no qualifier at all.
+    Release,
+    /// `sp`.
+    Sp,
+    /// Any other qualifier, lowercased.
+    Other(&'a str),
+}
+
+/// A summary of a parsed [ComparableVersion], as returned by [`ComparableVersion::decompose`].
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Decomposition<'a> {
+    /// The [numeric parts](ComparableVersion::numeric_parts) at the start of the version.
+    pub core: Vec<u64>,
+    /// The [qualifiers](ComparableVersion::qualifiers) along with the numbers following them.
+    pub qualifiers: Vec<(Qualifier<'a>, Option<u64>)>,
+    /// The number at the end of the version, if it's neither part of the core nor following a
+    /// qualifier, such as 4 in `1.2.3-4`.
+    pub build: Option<u64>,
+}
+
+impl ComparableVersion {
+    /// Returns the segments this version is parsed into, in order. Trailing null segments have
+    /// already been stripped, so `1.0-0` has a single segment.
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, Qualifier, VersionItem};
+    /// let version = ComparableVersion::new("1.2-beta-3");
+    /// let segments = version.segments().map(|s| s.items().collect()).collect::<Vec<Vec<_>>>();
+    /// assert_eq!(
+    ///     segments,
+    ///     [
+    ///         vec![VersionItem::Int(1), VersionItem::Int(2)],
+    ///         vec![VersionItem::Qualifier(Qualifier::Beta)],
+    ///         vec![VersionItem::Int(3)],
+    ///     ]
+    /// );
+    /// ```
+    pub fn segments(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = VersionSegment<'_>> + ExactSizeIterator {
+        self.segments
+            .iter()
+            .map(|segment| VersionSegment { segment })
+    }
+}
+
+impl ComparableVersion {
+    /// Returns the qualifiers of the version in order, each with the number directly following
+    /// it if there is one.
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, Qualifier};
+    /// let version = ComparableVersion::new("1.0-alpha-1-SNAPSHOT");
+    /// assert_eq!(
+    ///     version.qualifiers().collect::<Vec<_>>(),
+    ///     [(Qualifier::Alpha, Some(1)), (Qualifier::Snapshot, None)]
+    /// );
+    /// ```
+    pub fn qualifiers(&self) -> impl Iterator<Item = (Qualifier<'_>, Option<u64>)> {
+        let mut items = self.segments().flat_map(|s| s.items()).peekable();
+        let mut chain = Vec::new();
+
+        while let Some(item) = items.next() {
+            if let VersionItem::Qualifier(q) = item {
+                let number = match items.peek() {
+                    Some(VersionItem::Int(i)) => Some(*i),
+                    Some(VersionItem::BigInt(i)) => u64::try_from(*i).ok(),
+                    _ => None,
+                };
+                chain.push((q, number));
+            }
+        }
+
+        chain.into_iter()
+    }
+
+    /// Breaks the version down into its numeric core, its qualifiers and a build number.
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, Decomposition, Qualifier};
+    /// let version = ComparableVersion::new("2.1.0-beta-3-7");
+    /// let Decomposition { core, qualifiers, build } = version.decompose();
+    /// assert_eq!(core, [2, 1]);
+    /// assert_eq!(qualifiers, [(Qualifier::Beta, Some(3))]);
+    /// assert_eq!(build, Some(7));
+    /// ```
+    pub fn decompose(&self) -> Decomposition<'_> {
+        let core = self.numeric_parts().collect::<Vec<_>>();
+        let items = self.segments().flat_map(|s| s.items()).collect::<Vec<_>>();
+
+        let build = match items.as_slice() {
+            [.., before, last] if items.len() > core.len() => match (before, last) {
+                (VersionItem::Qualifier(_), _) => None,
+                (_, VersionItem::Int(i)) => Some(*i),
+                (_, VersionItem::BigInt(i)) => u64::try_from(*i).ok(),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        Decomposition {
+            core,
+            qualifiers: self.qualifiers().collect(),
+            build,
+        }
+    }
+
+    /// Renders the segments and items the version was parsed into, in the notation used in
+    /// [ComparableVersion]'s documentation. Qualifiers equivalent to a release are shown as `""`.
+    /// This is useful for understanding surprising comparison results.
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// assert_eq!(ComparableVersion::new("1.0A1").explain_parse(), "[[1], [alpha], [1]]");
+    /// assert_eq!(ComparableVersion::new("1.ga.2-foo").explain_parse(), "[[1, \"\", 2], [foo]]");
+    /// ```
+    pub fn explain_parse(&self) -> String {
+        let segments = self
+            .segments()
+            .map(|segment| {
+                let items = segment
+                    .items()
+                    .map(|item| match item {
+                        VersionItem::Qualifier(Qualifier::Release) => "\"\"".to_string(),
+                        item => item.to_string(),
+                    })
+                    .collect::<Vec<_>>();
+                format!("[{}]", items.join(", "))
+            })
+            .collect::<Vec<_>>();
+
+        format!("[{}]", segments.join(", "))
+    }
+
+    /// The number of [segments], after stripping trailing null ones.
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// let version = ComparableVersion::new("20240101.1-foo");
+    /// assert_eq!(version.segment_count(), 2);
+    /// assert_eq!(version.item_count(), 3);
+    /// ```
+    ///
+    /// [segments]: Self::segments
+    pub fn segment_count(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// The total number of items in all segments.
+    pub fn item_count(&self) -> usize {
+        self.segments.iter().map(|s| s.items().len()).sum()
+    }
+
+    /// Whether the version has no segments at all, i.e. it's equal to `0`.
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Returns the numbers at the start of the version, i.e. the numeric items of the first
+    /// segment up to the first qualifier. Trailing zeroes have been stripped, so `1.0` only has
+    /// the part 1, and parsing stops at a number too large for a [u64].
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// let version = ComparableVersion::new("1.2.3-beta-1");
+    /// assert_eq!(version.numeric_parts().collect::<Vec<_>>(), [1, 2, 3]);
+    /// assert_eq!(ComparableVersion::new("2.0.1.Final").numeric_parts().count(), 3);
+    /// ```
+    pub fn numeric_parts(&self) -> impl Iterator<Item = u64> + '_ {
+        self.segments()
+            .next()
+            .into_iter()
+            .flat_map(|s| s.items())
+            .map_while(|item| match item {
+                VersionItem::Int(i) => Some(i),
+                VersionItem::BigInt(i) => u64::try_from(i).ok(),
+                VersionItem::Qualifier(_) => None,
+            })
+    }
+
+    /// The major version, i.e. the first [numeric part]. See [`Self::patch`].
+    ///
+    /// [numeric part]: Self::numeric_parts
+    pub fn major(&self) -> Option<u64> {
+        self.numeric_part(0)
+    }
+
+    /// The minor version, i.e. the second [numeric part]. See [`Self::patch`].
+    ///
+    /// [numeric part]: Self::numeric_parts
+    pub fn minor(&self) -> Option<u64> {
+        self.numeric_part(1)
+    }
+
+    /// The patch version, i.e. the third [numeric part]. Since trailing zeroes are insignificant,
+    /// the parts are 0 if the first segment ends before them, but `None` if a qualifier comes
+    /// first or the number is too large for a [u64].
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// let version = ComparableVersion::new("1.2-beta-3");
+    /// assert_eq!(version.major(), Some(1));
+    /// assert_eq!(version.minor(), Some(2));
+    /// assert_eq!(version.patch(), Some(0));
+    ///
+    /// let version = ComparableVersion::new("1.x");
+    /// assert_eq!(version.major(), Some(1));
+    /// assert_eq!(version.minor(), None);
+    /// ```
+    ///
+    /// [numeric part]: Self::numeric_parts
+    pub fn patch(&self) -> Option<u64> {
+        self.numeric_part(2)
+    }
+
+    /// Whether both versions have the same numeric major version. Like with [`Self::major`],
+    /// trailing zeroes don't matter, but a qualifier in place of the major version never matches.
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// let version = ComparableVersion::new("1.2.3");
+    /// assert!(version.same_major(&ComparableVersion::new("1")));
+    /// assert!(version.same_major(&ComparableVersion::new("1.4-beta-1")));
+    /// assert!(!version.same_major(&ComparableVersion::new("2.0")));
+    /// ```
+    pub fn same_major(&self, other: &ComparableVersion) -> bool {
+        self.same_numeric_parts(other, 1)
+    }
+
+    /// Whether both versions have the same numeric major and minor versions. See
+    /// [`Self::same_major`].
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// let version = ComparableVersion::new("1.0.3");
+    /// assert!(version.same_minor(&ComparableVersion::new("1")));
+    /// assert!(!version.same_minor(&ComparableVersion::new("1.1")));
+    /// ```
+    pub fn same_minor(&self, other: &ComparableVersion) -> bool {
+        self.same_numeric_parts(other, 2)
+    }
+
+    /// Whether the first `n` items of the first segments, padded with zeroes, are equal numbers.
+    fn same_numeric_parts(&self, other: &ComparableVersion, n: usize) -> bool {
+        fn parts(version: &ComparableVersion, n: usize) -> impl Iterator<Item = VersionItem<'_>> {
+            version
+                .segments()
+                .next()
+                .into_iter()
+                .flat_map(|s| s.items())
+                .chain(std::iter::repeat(VersionItem::Int(0)))
+                .take(n)
+        }
+
+        parts(self, n)
+            .zip(parts(other, n))
+            .all(|(a, b)| a.is_numeric() && a == b)
+    }
+
+    fn numeric_part(&self, n: usize) -> Option<u64> {
+        let items = self.segments().next().map(|s| s.len()).unwrap_or(0);
+        let parts = self.numeric_parts().collect::<Vec<_>>();
+
+        match parts.get(n) {
+            Some(part) => Some(*part),
+            // The numeric parts stopped before the end of the segment, so something else came first
+            None if parts.len() < items => None,
+            None => Some(0),
+        }
+    }
+
+    /// Returns a version made up of only the first `n` numbers of this one, padded with zeroes if
+    /// there are fewer. These are the [numeric parts], except that numbers too large for a [u64]
+    /// are kept as well. Qualifiers are dropped.
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// let version = ComparableVersion::new("2.3.4-beta-1");
+    /// assert_eq!(version.truncate(2).as_str(), "2.3");
+    /// assert_eq!(version.truncate(5).as_str(), "2.3.4.0.0");
+    /// ```
+    ///
+    /// [numeric parts]: Self::numeric_parts
+    pub fn truncate(&self, n: usize) -> ComparableVersion {
+        let parts = self
+            .segments()
+            .next()
+            .into_iter()
+            .flat_map(|s| s.items())
+            .take_while(VersionItem::is_numeric)
+            .chain(std::iter::repeat(VersionItem::Int(0)))
+            .take(n)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>();
+
+        ComparableVersion::new_owned(parts.join("."))
+    }
+}
+
+impl<'a> VersionSegment<'a> {
+    /// Returns the items of this segment, in order.
+    pub fn items(&self) -> impl DoubleEndedIterator<Item = VersionItem<'a>> + ExactSizeIterator {
+        self.segment.items().iter().map(VersionItem::from_item)
+    }
+
+    /// The number of items in this segment.
+    pub fn len(&self) -> usize {
+        self.segment.items().len()
+    }
+
+    /// Whether this segment has no items, as in the middle segment of `1--2`.
+    pub fn is_empty(&self) -> bool {
+        self.segment.items().is_empty()
+    }
+
+    /// What this segment is padded with when compared with a longer segment.
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, Padding};
+    /// let version = ComparableVersion::new("1-1-snapshot");
+    /// let padding = version.segments().map(|s| s.padding()).collect::<Vec<_>>();
+    /// assert_eq!(padding, [Padding::NextSegment, Padding::NextSegment, Padding::Null]);
+    /// ```
+    pub fn padding(&self) -> Padding {
+        if self.segment.is_last_segment() {
+            Padding::Null
+        } else {
+            Padding::NextSegment
+        }
+    }
+
+    /// Returns the items of this segment followed by endless [padding], which is what Maven
+    /// compares segments item by item with. Comparing two segments takes as many items from each
+    /// as the longer one has; comparing an item with padding is done by
+    /// [`VersionItem::cmp_padding`].
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, PaddedItem, Padding, Qualifier, VersionItem};
+    /// # use std::cmp::Ordering;
+    /// // `1-1` is newer than `1-1-snapshot`, since the second segment of the latter is followed
+    /// // by another, and the padding standing for it loses against the 1
+    /// let version = ComparableVersion::new("1-1-snapshot");
+    /// let second = version.segments().nth(1).unwrap();
+    /// let items = second.padded_iter().take(2).collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     items,
+    ///     [
+    ///         PaddedItem::Item(VersionItem::Int(1)),
+    ///         PaddedItem::Padding(Padding::NextSegment),
+    ///     ]
+    /// );
+    /// assert_eq!(VersionItem::Int(1).cmp_padding(Padding::NextSegment), Ordering::Greater);
+    /// ```
+    ///
+    /// [padding]: Self::padding
+    pub fn padded_iter(&self) -> impl Iterator<Item = PaddedItem<'a>> {
+        self.items()
+            .map(PaddedItem::Item)
+            .chain(std::iter::repeat(PaddedItem::Padding(self.padding())))
+    }
+}
+
+impl<'a> VersionItem<'a> {
+    fn from_item(item: &'a Item) -> Self {
+        match item {
+            Item::Int(i) => VersionItem::Int(u64::from(*i)),
+            Item::BigInt(i) => VersionItem::BigInt(i),
+            Item::Known(q) => VersionItem::Qualifier(*q),
+            // Combinations only turn up when comparing under Maven 4 rules, never in a version
+            Item::String(s) | Item::Combination(s, _) => {
+                VersionItem::Qualifier(Qualifier::from_item(s))
+            }
+            // Only borrowed versions, which don't hand out their items, have these
+            Item::Unfolded(s) => VersionItem::Qualifier(Qualifier::Other(s)),
+        }
+    }
+
+    /// Whether this item is a number.
+    pub fn is_numeric(&self) -> bool {
+        !matches!(self, VersionItem::Qualifier(_))
+    }
+
+    /// Where this item stands in comparison to the given [Padding], with Maven's ordering of
+    /// qualifiers.
+    ///
+    /// ```
+    /// # use mvn_version::{Padding, Qualifier, VersionItem};
+    /// # use std::cmp::Ordering;
+    /// let snapshot = VersionItem::Qualifier(Qualifier::Snapshot);
+    /// assert_eq!(snapshot.cmp_padding(Padding::Null), Ordering::Less);
+    /// assert_eq!(VersionItem::Int(0).cmp_padding(Padding::Null), Ordering::Equal);
+    /// assert_eq!(VersionItem::Int(0).cmp_padding(Padding::NextSegment), Ordering::Greater);
+    /// ```
+    pub fn cmp_padding(&self, padding: Padding) -> Ordering {
+        match (self, padding) {
+            (VersionItem::Qualifier(_), Padding::NextSegment) => Ordering::Less,
+            (VersionItem::Qualifier(q), Padding::Null) => q.cmp(&Qualifier::Release),
+            (VersionItem::Int(0), Padding::Null) => Ordering::Equal,
+            (VersionItem::Int(_) | VersionItem::BigInt(_), _) => Ordering::Greater,
+        }
+    }
+}
+
+impl Qualifier<'static> {
+    /// All known qualifiers, in ascending order.
+    pub const KNOWN: [Qualifier<'static>; 7] = [
+        Qualifier::Alpha,
+        Qualifier::Beta,
+        Qualifier::Milestone,
+        Qualifier::Rc,
+        Qualifier::Snapshot,
+        Qualifier::Release,
+        Qualifier::Sp,
+    ];
+}
+
+impl<'a> Qualifier<'a> {
+    /// Classifies the string of a qualifier item, which has already been lowercased and had its
+    /// aliases expanded.
+    pub(super) fn from_item(s: &'a str) -> Self {
+        match s {
+            "alpha" => Qualifier::Alpha,
+            "beta" => Qualifier::Beta,
+            "milestone" => Qualifier::Milestone,
+            "rc" => Qualifier::Rc,
+            "snapshot" => Qualifier::Snapshot,
+            "" => Qualifier::Release,
+            "sp" => Qualifier::Sp,
+            _ => Qualifier::Other(s),
+        }
+    }
+
+    /// The position of the qualifier in the ordering of known qualifiers, from 0 for
+    /// [Qualifier::Alpha] to 6 for [Qualifier::Sp]. Other qualifiers all have rank 7.
+    pub fn rank(&self) -> u8 {
+        match self {
+            Qualifier::Alpha => 0,
+            Qualifier::Beta => 1,
+            Qualifier::Milestone => 2,
+            Qualifier::Rc => 3,
+            Qualifier::Snapshot => 4,
+            Qualifier::Release => 5,
+            Qualifier::Sp => 6,
+            Qualifier::Other(_) => 7,
+        }
+    }
+
+    /// Whether the qualifier sorts below a release.
+    pub fn is_prerelease(&self) -> bool {
+        *self < Qualifier::Release
+    }
+
+    /// The qualifier without the lifetime of the string it came from, or `None` if it's
+    /// [Qualifier::Other], which borrows it.
+    pub(super) fn known(&self) -> Option<Qualifier<'static>> {
+        Some(match *self {
+            Qualifier::Alpha => Qualifier::Alpha,
+            Qualifier::Beta => Qualifier::Beta,
+            Qualifier::Milestone => Qualifier::Milestone,
+            Qualifier::Rc => Qualifier::Rc,
+            Qualifier::Snapshot => Qualifier::Snapshot,
+            Qualifier::Release => Qualifier::Release,
+            Qualifier::Sp => Qualifier::Sp,
+            Qualifier::Other(_) => return None,
+        })
+    }
+
+    /// Returns the canonical form of the qualifier, which is empty for [Qualifier::Release].
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            Qualifier::Alpha => "alpha",
+            Qualifier::Beta => "beta",
+            Qualifier::Milestone => "milestone",
+            Qualifier::Rc => "rc",
+            Qualifier::Snapshot => "snapshot",
+            Qualifier::Release => "",
+            Qualifier::Sp => "sp",
+            Qualifier::Other(s) => s,
+        }
+    }
+}
+
+impl Display for VersionSegment<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.segment.fmt(f)
+    }
+}
+
+impl Display for VersionItem<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            VersionItem::Int(i) => i.fmt(f),
+            VersionItem::BigInt(i) => i.fmt(f),
+            VersionItem::Qualifier(q) => q.fmt(f),
+        }
+    }
+}
+
+impl Display for Qualifier<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
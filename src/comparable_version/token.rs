@@ -0,0 +1,171 @@
+// Lexing of version strings into tokens that keep track of where they came from, for tools which
+// need to point at parts of the original string.
+
+use super::{grammar, ComparableVersion};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::iter::FusedIterator;
+use std::ops::Range;
+use winnow::Parser;
+
+/// A token of a version string, along with its position in the string. Concatenating all tokens
+/// of a version yields the original string.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    start: usize,
+}
+
+/// The kind of a [Token].
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum TokenKind {
+    /// A run of ASCII digits.
+    Number,
+    /// A run of anything other than digits and separators.
+    Qualifier,
+    /// A `.`, which separates items within a segment.
+    Dot,
+    /// A `-`, which separates segments.
+    Dash,
+}
+
+/// What joins two adjacent items of a version string, as returned by
+/// [ComparableVersion::separators].
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum Separator {
+    /// A `.`, which separates items within a segment.
+    Dot,
+    /// A `-`, which separates segments.
+    Dash,
+    /// Nothing, where a number and a qualifier meet, as in `1.0RC1`. This separates segments just
+    /// like a dash.
+    Transition,
+}
+
+/// An iterator over the [Token]s of a version string, returned by [ComparableVersion::tokens].
+#[derive(Debug, Clone)]
+pub struct Tokens<'a> {
+    s: &'a str,
+    pos: usize,
+    digit: fn(char) -> bool,
+}
+
+impl ComparableVersion {
+    /// Returns the tokens of the original version string, with their byte spans. Unlike
+    /// [`Self::segments`], this is lossless: case, aliases and zeroes are left untouched, and
+    /// separators are included.
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, TokenKind};
+    /// let version = ComparableVersion::new("1.0RC1");
+    /// let tokens = version.tokens().map(|t| (t.kind(), t.as_str(), t.span())).collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     tokens,
+    ///     [
+    ///         (TokenKind::Number, "1", 0..1),
+    ///         (TokenKind::Dot, ".", 1..2),
+    ///         (TokenKind::Number, "0", 2..3),
+    ///         (TokenKind::Qualifier, "RC", 3..5),
+    ///         (TokenKind::Number, "1", 5..6),
+    ///     ]
+    /// );
+    /// ```
+    pub fn tokens(&self) -> Tokens<'_> {
+        Tokens::new(&self.orig, |c| c.is_ascii_digit())
+    }
+
+    /// Returns the separators of the original version string in order, including the implicit
+    /// ones between numbers and qualifiers, so that edited versions can be written in the same
+    /// style.
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, Separator};
+    /// let version = ComparableVersion::new("1.0RC1-SNAPSHOT");
+    /// assert_eq!(
+    ///     version.separators().collect::<Vec<_>>(),
+    ///     [Separator::Dot, Separator::Transition, Separator::Transition, Separator::Dash]
+    /// );
+    /// ```
+    pub fn separators(&self) -> impl Iterator<Item = Separator> + '_ {
+        let mut after_item = false;
+
+        self.tokens().filter_map(move |token| {
+            let separator = match token.kind() {
+                TokenKind::Dot => Some(Separator::Dot),
+                TokenKind::Dash => Some(Separator::Dash),
+                _ if after_item => Some(Separator::Transition),
+                _ => None,
+            };
+            after_item = !token.is_separator();
+            separator
+        })
+    }
+}
+
+impl<'a> Token<'a> {
+    /// What kind of token this is.
+    pub fn kind(&self) -> TokenKind {
+        self.kind
+    }
+
+    /// The text of the token, exactly as it appears in the version string.
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    /// The byte range of the token in the version string.
+    pub fn span(&self) -> Range<usize> {
+        self.start..self.start + self.text.len()
+    }
+
+    /// Whether this is a separator, i.e. a `.` or `-`.
+    pub fn is_separator(&self) -> bool {
+        matches!(self.kind, TokenKind::Dot | TokenKind::Dash)
+    }
+}
+
+impl Separator {
+    /// The separator as it's written, which is empty for a [`Separator::Transition`].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Separator::Dot => ".",
+            Separator::Dash => "-",
+            Separator::Transition => "",
+        }
+    }
+}
+
+impl Display for Separator {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> Tokens<'a> {
+    /// Lexes a version string, with the given characters counting as digits. The tokens come from
+    /// the grammar the parser is built on, so anything which needs to agree with it should lex
+    /// rather than scan the string itself.
+    pub(super) fn new(s: &'a str, digit: fn(char) -> bool) -> Self {
+        Tokens { s, pos: 0, digit }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut rest = &self.s[self.pos..];
+        let (kind, text) = grammar::token(self.digit).parse_next(&mut rest).ok()?;
+
+        let token = Token {
+            kind,
+            text,
+            start: self.pos,
+        };
+        self.pos += text.len();
+        Some(token)
+    }
+}
+
+impl FusedIterator for Tokens<'_> {}
@@ -0,0 +1,107 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// The error returned when Maven coordinates are malformed. It carries the kind of problem
+/// encountered, the field it concerns if any, and the byte offset into the coordinates at which
+/// it was detected.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct CoordinatesParseError {
+    kind: CoordinatesParseErrorKind,
+    field: Option<CoordinateField>,
+    offset: usize,
+}
+
+/// The specific reason Maven coordinates were rejected.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[non_exhaustive]
+pub enum CoordinatesParseErrorKind {
+    /// The coordinates ended before all required fields were given, e.g. `com.example:foo`.
+    MissingField,
+    /// The coordinates contained more fields than expected, e.g.
+    /// `com.example:foo:jar:tests:1.0:test:extra`.
+    TooManyFields,
+    /// A field was empty, e.g. `com.example::1.0`.
+    EmptyField,
+    /// A group or artifact ID contained a character Maven doesn't allow in them, e.g. the space
+    /// in `com.example:foo bar:1.0`.
+    InvalidCharacter(char),
+}
+
+/// A field of Maven coordinates.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum CoordinateField {
+    GroupId,
+    ArtifactId,
+    Packaging,
+    Classifier,
+    Version,
+    Scope,
+}
+
+impl CoordinatesParseError {
+    pub(crate) fn new(
+        kind: CoordinatesParseErrorKind,
+        field: Option<CoordinateField>,
+        offset: usize,
+    ) -> Self {
+        CoordinatesParseError {
+            kind,
+            field,
+            offset,
+        }
+    }
+
+    /// What went wrong.
+    pub fn kind(&self) -> CoordinatesParseErrorKind {
+        self.kind
+    }
+
+    /// The field which was malformed or missing, or `None` if the problem doesn't concern a
+    /// single field, e.g. because there were too many of them.
+    pub fn field(&self) -> Option<CoordinateField> {
+        self.field
+    }
+
+    /// The byte offset into the coordinates at which the problem was detected.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Display for CoordinatesParseErrorKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CoordinatesParseErrorKind::MissingField => f.write_str("missing field"),
+            CoordinatesParseErrorKind::TooManyFields => f.write_str("too many fields"),
+            CoordinatesParseErrorKind::EmptyField => f.write_str("empty field"),
+            CoordinatesParseErrorKind::InvalidCharacter(c) => {
+                write!(f, "invalid character {:?} in field", c)
+            }
+        }
+    }
+}
+
+impl Display for CoordinateField {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            CoordinateField::GroupId => "groupId",
+            CoordinateField::ArtifactId => "artifactId",
+            CoordinateField::Packaging => "packaging",
+            CoordinateField::Classifier => "classifier",
+            CoordinateField::Version => "version",
+            CoordinateField::Scope => "scope",
+        })
+    }
+}
+
+impl Display for CoordinatesParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.field {
+            Some(field) => write!(f, "{} `{}` at byte {}", self.kind, field, self.offset),
+            None => write!(f, "{} at byte {}", self.kind, self.offset),
+        }
+    }
+}
+
+impl Error for CoordinatesParseError {}
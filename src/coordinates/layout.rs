@@ -0,0 +1,112 @@
+// Paths of artifacts in a Maven repository with the default layout, i.e.
+// `groupId/as/dirs/artifactId/version/artifactId-version[-classifier].extension`.
+
+use super::MavenCoordinates;
+use crate::{SnapshotTimestamp, SnapshotVersion};
+
+impl MavenCoordinates {
+    /// The file extension of the artifact, which is derived from the packaging the same way
+    /// Maven's default artifact handlers do: the packagings Maven builds into a jar, such as
+    /// `maven-plugin` or `test-jar`, and no packaging at all are a `jar`, while any other
+    /// packaging, e.g. `pom`, `zip` or `aar`, is its own extension.
+    pub fn extension(&self) -> &str {
+        match self.packaging() {
+            None | Some("jar") | Some("maven-plugin") | Some("ejb") | Some("ejb-client")
+            | Some("test-jar") | Some("java-source") | Some("javadoc") | Some("bundle") => "jar",
+            Some(packaging) => packaging,
+        }
+    }
+
+    /// The classifier of the artifact file. This is the explicit classifier if there is one, and
+    /// otherwise the one implied by the packaging, e.g. `tests` for `test-jar` and `sources` for
+    /// `java-source`.
+    pub fn file_classifier(&self) -> Option<&str> {
+        self.classifier().or(match self.packaging() {
+            Some("test-jar") => Some("tests"),
+            Some("java-source") => Some("sources"),
+            Some("javadoc") => Some("javadoc"),
+            Some("ejb-client") => Some("client"),
+            _ => None,
+        })
+    }
+
+    /// The name of the artifact file, `artifactId-version[-classifier].extension`, e.g.
+    /// `foo-1.2.3-sources.jar`.
+    ///
+    /// ```
+    /// # use mvn_version::MavenCoordinates;
+    /// let coords = MavenCoordinates::new("com.example:foo:jar:sources:1.2.3").unwrap();
+    /// assert_eq!(coords.file_name(), "foo-1.2.3-sources.jar");
+    /// ```
+    pub fn file_name(&self) -> String {
+        self.file_name_with_version(self.version().as_str())
+    }
+
+    /// The name of a deployed SNAPSHOT artifact file, with the version replaced by the unique
+    /// version for the given timestamp and build number, e.g. `foo-1.0-20050205.060708-1.jar`.
+    /// If the version isn't a SNAPSHOT, this is the same as [`Self::file_name`].
+    ///
+    /// ```
+    /// # use mvn_version::{MavenCoordinates, SnapshotTimestamp};
+    /// let coords = MavenCoordinates::new("com.example:foo:1.0-SNAPSHOT").unwrap();
+    /// let timestamp = SnapshotTimestamp::parse("20050205.060708").unwrap();
+    /// assert_eq!(coords.snapshot_file_name(timestamp, 1), "foo-1.0-20050205.060708-1.jar");
+    /// ```
+    pub fn snapshot_file_name(&self, timestamp: SnapshotTimestamp, build_number: u32) -> String {
+        match SnapshotVersion::new(self.version().as_str()) {
+            Some(snapshot) => {
+                self.file_name_with_version(snapshot.to_unique(timestamp, build_number).as_str())
+            }
+            None => self.file_name(),
+        }
+    }
+
+    /// The path of the artifact relative to the root of a repository with the default layout,
+    /// e.g. `com/example/foo/1.2.3/foo-1.2.3.jar`. The directory is named after the
+    /// [base version], so a unique SNAPSHOT version is stored alongside the other builds of the
+    /// same SNAPSHOT.
+    ///
+    /// ```
+    /// # use mvn_version::MavenCoordinates;
+    /// let coords = MavenCoordinates::new("com.example:foo:jar:sources:1.2.3").unwrap();
+    /// assert_eq!(coords.repository_path(), "com/example/foo/1.2.3/foo-1.2.3-sources.jar");
+    /// ```
+    ///
+    /// [base version]: crate::ComparableVersion::base_version
+    pub fn repository_path(&self) -> String {
+        self.directory() + &self.file_name()
+    }
+
+    /// The path of a deployed SNAPSHOT artifact relative to the root of a repository with the
+    /// default layout, using [`Self::snapshot_file_name`] as the file name, e.g.
+    /// `com/example/foo/1.0-SNAPSHOT/foo-1.0-20050205.060708-1.jar`. If the version isn't a
+    /// SNAPSHOT, this is the same as [`Self::repository_path`].
+    pub fn snapshot_repository_path(
+        &self,
+        timestamp: SnapshotTimestamp,
+        build_number: u32,
+    ) -> String {
+        self.directory() + &self.snapshot_file_name(timestamp, build_number)
+    }
+
+    /// The directory containing the artifact, including a trailing slash.
+    fn directory(&self) -> String {
+        format!(
+            "{}/{}/{}/",
+            self.group_id().replace('.', "/"),
+            self.artifact_id(),
+            self.version().base_version()
+        )
+    }
+
+    fn file_name_with_version(&self, version: &str) -> String {
+        let mut name = format!("{}-{}", self.artifact_id(), version);
+        if let Some(classifier) = self.file_classifier() {
+            name.push('-');
+            name.push_str(classifier);
+        }
+        name.push('.');
+        name.push_str(self.extension());
+        name
+    }
+}
@@ -0,0 +1,377 @@
+// Maven coordinates identify an artifact by its group, artifact ID and version, optionally along
+// with its packaging, classifier and dependency scope. They're written in the notation used by
+// Maven's dependency plugin, `groupId:artifactId[:packaging[:classifier]]:version[:scope]`.
+
+mod error;
+mod layout;
+#[cfg(feature = "serde")]
+pub mod structured_coordinates;
+
+pub use error::{CoordinateField, CoordinatesParseError, CoordinatesParseErrorKind};
+
+use crate::{ComparableVersion, SnapshotVersion};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The dependency scopes Maven knows about, used to tell a trailing scope apart from a version.
+const SCOPES: [&str; 6] = ["compile", "provided", "runtime", "test", "system", "import"];
+
+/// Maven coordinates of the form `groupId:artifactId[:packaging[:classifier]]:version[:scope]`,
+/// e.g. `org.apache.maven:maven-core:3.9.6` or `com.example:foo:jar:tests:1.0:test`.
+///
+/// The version is stored as a [ComparableVersion], so coordinates compare equal whenever their
+/// other fields are identical and their versions are equal according to Maven, e.g.
+/// `com.example:foo:1.0` and `com.example:foo:1.0.0`. Coordinates are ordered by group ID, then
+/// artifact ID, then version, with the remaining fields only breaking ties.
+///
+/// ```
+/// # use mvn_version::MavenCoordinates;
+/// let coords: MavenCoordinates = "com.example:foo:1.0".parse().unwrap();
+/// assert_eq!(coords.group_id(), "com.example");
+/// assert_eq!(coords.artifact_id(), "foo");
+/// assert_eq!(coords.version().as_str(), "1.0");
+/// assert!(coords < "com.example:foo:1.0.1".parse().unwrap());
+/// ```
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Clone)]
+pub struct MavenCoordinates {
+    group_id: String,
+    artifact_id: String,
+    version: ComparableVersion,
+    packaging: Option<String>,
+    classifier: Option<String>,
+    scope: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for MavenCoordinates {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MavenCoordinates {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let spec = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::new(&spec).map_err(serde::de::Error::custom)
+    }
+}
+
+impl MavenCoordinates {
+    /// Constructs coordinates from their parts, without packaging, classifier or scope. The parts
+    /// aren't validated in any way.
+    pub fn from_parts(group_id: &str, artifact_id: &str, version: ComparableVersion) -> Self {
+        MavenCoordinates {
+            group_id: group_id.to_string(),
+            artifact_id: artifact_id.to_string(),
+            version,
+            packaging: None,
+            classifier: None,
+            scope: None,
+        }
+    }
+
+    /// Parses coordinates of the form `groupId:artifactId[:packaging[:classifier]]:version[:scope]`.
+    /// Every field given must be non-empty.
+    ///
+    /// Since the packaging, classifier and scope are all optional, the notation is ambiguous for
+    /// four and five fields. These are taken to end in a scope if the last field is one of
+    /// Maven's scopes, such as `compile` or `test`, and to contain a packaging or classifier
+    /// otherwise.
+    pub fn new(s: &str) -> Result<Self, CoordinatesParseError> {
+        let mut fields = Vec::with_capacity(6);
+        let mut start = 0;
+
+        for field in s.split(':') {
+            if fields.len() == 6 {
+                return Err(CoordinatesParseError::new(
+                    CoordinatesParseErrorKind::TooManyFields,
+                    None,
+                    start - 1,
+                ));
+            }
+
+            fields.push((field, start));
+            start += field.len() + 1;
+        }
+
+        let layout: &[CoordinateField] = match fields.len() {
+            1 | 2 => &[],
+            3 => &[CoordinateField::Version],
+            4 if SCOPES.contains(&fields[3].0) => {
+                &[CoordinateField::Version, CoordinateField::Scope]
+            }
+            4 => &[CoordinateField::Packaging, CoordinateField::Version],
+            5 if SCOPES.contains(&fields[4].0) => &[
+                CoordinateField::Packaging,
+                CoordinateField::Version,
+                CoordinateField::Scope,
+            ],
+            5 => &[
+                CoordinateField::Packaging,
+                CoordinateField::Classifier,
+                CoordinateField::Version,
+            ],
+            _ => &[
+                CoordinateField::Packaging,
+                CoordinateField::Classifier,
+                CoordinateField::Version,
+                CoordinateField::Scope,
+            ],
+        };
+
+        let mut coords = MavenCoordinates::from_parts("", "", ComparableVersion::new(""));
+        let names = [CoordinateField::GroupId, CoordinateField::ArtifactId];
+
+        for (&(field, start), &name) in fields.iter().zip(names.iter().chain(layout)) {
+            if field.is_empty() {
+                return Err(CoordinatesParseError::new(
+                    CoordinatesParseErrorKind::EmptyField,
+                    Some(name),
+                    start,
+                ));
+            }
+
+            match name {
+                CoordinateField::GroupId => coords.group_id = field.to_string(),
+                CoordinateField::ArtifactId => coords.artifact_id = field.to_string(),
+                CoordinateField::Packaging => coords.packaging = Some(field.to_string()),
+                CoordinateField::Classifier => coords.classifier = Some(field.to_string()),
+                CoordinateField::Version => coords.version = ComparableVersion::new(field),
+                CoordinateField::Scope => coords.scope = Some(field.to_string()),
+            }
+        }
+
+        match fields.len() {
+            1 => Err(CoordinatesParseError::new(
+                CoordinatesParseErrorKind::MissingField,
+                Some(CoordinateField::ArtifactId),
+                s.len(),
+            )),
+            2 => Err(CoordinatesParseError::new(
+                CoordinatesParseErrorKind::MissingField,
+                Some(CoordinateField::Version),
+                s.len(),
+            )),
+            _ => Ok(coords),
+        }
+    }
+
+    /// Parses coordinates like [`Self::new`], and additionally [validates] the group and
+    /// artifact IDs.
+    ///
+    /// [validates]: Self::validate
+    pub fn new_strict(s: &str) -> Result<Self, CoordinatesParseError> {
+        let coords = Self::new(s)?;
+        coords.validate()?;
+        Ok(coords)
+    }
+
+    /// Checks that the group and artifact IDs only consist of the characters Maven allows in
+    /// them, which are ASCII letters and digits, `-`, `_` and `.`. The error reports the first
+    /// offending character, with its offset into the [string form] of the coordinates.
+    ///
+    /// ```
+    /// # use mvn_version::{CoordinatesParseErrorKind, MavenCoordinates};
+    /// let coords = MavenCoordinates::new("com.example:foo bar:1.0").unwrap();
+    /// let err = coords.validate().unwrap_err();
+    /// assert_eq!(err.kind(), CoordinatesParseErrorKind::InvalidCharacter(' '));
+    /// assert_eq!(err.offset(), 15);
+    /// ```
+    ///
+    /// [string form]: Display
+    pub fn validate(&self) -> Result<(), CoordinatesParseError> {
+        let fields = [
+            (CoordinateField::GroupId, &self.group_id, 0),
+            (
+                CoordinateField::ArtifactId,
+                &self.artifact_id,
+                self.group_id.len() + 1,
+            ),
+        ];
+
+        for &(field, id, start) in &fields {
+            if id.is_empty() {
+                return Err(CoordinatesParseError::new(
+                    CoordinatesParseErrorKind::EmptyField,
+                    Some(field),
+                    start,
+                ));
+            }
+
+            if let Some((i, c)) = id
+                .char_indices()
+                .find(|&(_, c)| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+            {
+                return Err(CoordinatesParseError::new(
+                    CoordinatesParseErrorKind::InvalidCharacter(c),
+                    Some(field),
+                    start + i,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns these coordinates with the version replaced by the release it's a SNAPSHOT of,
+    /// e.g. `1.0` for both `1.0-SNAPSHOT` and `1.0-20050205.060708-1`. Coordinates whose version
+    /// isn't a SNAPSHOT are returned unchanged.
+    ///
+    /// ```
+    /// # use mvn_version::MavenCoordinates;
+    /// let coords = MavenCoordinates::new("com.example:foo:jar:1.0-SNAPSHOT:test").unwrap();
+    /// assert_eq!(coords.to_release().to_string(), "com.example:foo:jar:1.0:test");
+    /// ```
+    pub fn to_release(&self) -> Self {
+        match SnapshotVersion::new(self.version.as_str()) {
+            Some(snapshot) => self
+                .clone()
+                .with_version(ComparableVersion::new(snapshot.release_version())),
+            None => self.clone(),
+        }
+    }
+
+    /// Returns these coordinates with `-SNAPSHOT` appended to the version, e.g. `1.1-SNAPSHOT`
+    /// for `1.1`. A unique SNAPSHOT version is replaced by its [base version], and any other
+    /// SNAPSHOT version is left unchanged.
+    ///
+    /// [base version]: ComparableVersion::base_version
+    pub fn to_snapshot(&self) -> Self {
+        if self.version.is_snapshot() {
+            self.clone().with_version(self.version.base_version())
+        } else {
+            self.clone().with_version(ComparableVersion::new(&format!(
+                "{}-SNAPSHOT",
+                self.version
+            )))
+        }
+    }
+
+    /// Returns these coordinates with the given version.
+    pub fn with_version(mut self, version: ComparableVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Returns these coordinates with the given packaging.
+    pub fn with_packaging(mut self, packaging: &str) -> Self {
+        self.packaging = Some(packaging.to_string());
+        self
+    }
+
+    /// Returns these coordinates with the given classifier. A classifier can only be written after
+    /// a packaging, so if none was given, the packaging becomes Maven's default of `jar`.
+    pub fn with_classifier(mut self, classifier: &str) -> Self {
+        self.packaging.get_or_insert_with(|| "jar".to_string());
+        self.classifier = Some(classifier.to_string());
+        self
+    }
+
+    /// Returns these coordinates with the given dependency scope.
+    pub fn with_scope(mut self, scope: &str) -> Self {
+        self.scope = Some(scope.to_string());
+        self
+    }
+
+    /// The group ID, e.g. `org.apache.maven`.
+    pub fn group_id(&self) -> &str {
+        &self.group_id
+    }
+
+    /// The artifact ID, e.g. `maven-core`.
+    pub fn artifact_id(&self) -> &str {
+        &self.artifact_id
+    }
+
+    /// The version, e.g. `3.9.6`.
+    pub fn version(&self) -> &ComparableVersion {
+        &self.version
+    }
+
+    /// The packaging, e.g. `jar` or `pom`, if one was given.
+    pub fn packaging(&self) -> Option<&str> {
+        self.packaging.as_deref()
+    }
+
+    /// The classifier, e.g. `sources` or `tests`, if one was given.
+    pub fn classifier(&self) -> Option<&str> {
+        self.classifier.as_deref()
+    }
+
+    /// The dependency scope, e.g. `compile` or `test`, if one was given.
+    pub fn scope(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+}
+
+impl Display for MavenCoordinates {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.group_id, self.artifact_id)?;
+
+        match (&self.packaging, &self.classifier) {
+            (Some(packaging), Some(classifier)) => write!(f, ":{}:{}", packaging, classifier)?,
+            (Some(packaging), None) => write!(f, ":{}", packaging)?,
+            (None, Some(classifier)) => write!(f, ":jar:{}", classifier)?,
+            (None, None) => {}
+        }
+
+        write!(f, ":{}", self.version)?;
+
+        if let Some(scope) = &self.scope {
+            write!(f, ":{}", scope)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for MavenCoordinates {
+    type Err = CoordinatesParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+/// Buckets coordinates by their group and artifact IDs. The buckets are ordered by group ID, then
+/// artifact ID, and the coordinates within each bucket are sorted by version.
+///
+/// ```
+/// # use mvn_version::{group_by_ga, MavenCoordinates};
+/// let coords = ["com.example:foo:1.10", "com.example:bar:1.0", "com.example:foo:1.9"]
+///     .iter()
+///     .map(|c| MavenCoordinates::new(c).unwrap());
+/// let groups = group_by_ga(coords);
+///
+/// let foo = &groups[&("com.example".to_string(), "foo".to_string())];
+/// assert_eq!(foo[0].version().as_str(), "1.9");
+/// assert_eq!(foo[1].version().as_str(), "1.10");
+/// ```
+pub fn group_by_ga<I>(coords: I) -> BTreeMap<(String, String), Vec<MavenCoordinates>>
+where
+    I: IntoIterator<Item = MavenCoordinates>,
+{
+    let mut groups: BTreeMap<(String, String), Vec<MavenCoordinates>> = BTreeMap::new();
+
+    for c in coords {
+        groups
+            .entry((c.group_id.clone(), c.artifact_id.clone()))
+            .or_default()
+            .push(c);
+    }
+
+    for group in groups.values_mut() {
+        group.sort();
+    }
+
+    groups
+}
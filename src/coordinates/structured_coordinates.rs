@@ -0,0 +1,116 @@
+//! An alternative structured serialization of [MavenCoordinates], for use with
+//! `#[serde(with = "mvn_version::structured_coordinates")]`.
+//!
+//! By default, coordinates serialize as a string, e.g. `"com.example:foo:jar:sources:1.0"`. The
+//! structured form instead spells out every field, leaving out the optional ones which are absent:
+//!
+//! ```json
+//! { "group_id": "com.example", "artifact_id": "foo", "version": "1.0", "packaging": "jar", "classifier": "sources" }
+//! ```
+//!
+//! Deserialization with this module accepts both forms. All fields given in the structured form
+//! must be non-empty, just like in the string form. The default impls only accept strings, so that
+//! they also work with formats which aren't self-describing, such as bincode.
+
+use super::{CoordinateField, CoordinatesParseErrorKind, MavenCoordinates};
+use crate::ComparableVersion;
+use serde::de::{Error, MapAccess, Visitor};
+use serde::ser::SerializeStruct;
+use serde::{Deserializer, Serializer};
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Serializes MavenCoordinates in the structured form.
+pub fn serialize<S>(coords: &MavenCoordinates, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let optional = [coords.packaging(), coords.classifier(), coords.scope()];
+    let len = 3 + optional.iter().filter(|f| f.is_some()).count();
+
+    let mut s = serializer.serialize_struct("MavenCoordinates", len)?;
+    s.serialize_field("group_id", coords.group_id())?;
+    s.serialize_field("artifact_id", coords.artifact_id())?;
+    s.serialize_field("version", coords.version().as_str())?;
+    for (&name, field) in FIELDS[3..].iter().zip(optional.iter()) {
+        match field {
+            Some(field) => s.serialize_field(name, field)?,
+            None => s.skip_field(name)?,
+        }
+    }
+    s.end()
+}
+
+/// Deserializes MavenCoordinates from either the structured form or a string.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<MavenCoordinates, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(CoordinatesVisitor)
+}
+
+struct CoordinatesVisitor;
+
+impl<'de> Visitor<'de> for CoordinatesVisitor {
+    type Value = MavenCoordinates;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("Maven coordinates or a map of their fields")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        MavenCoordinates::new(v).map_err(E::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut group_id: Option<String> = None;
+        let mut artifact_id: Option<String> = None;
+        let mut version: Option<String> = None;
+        let mut packaging: Option<String> = None;
+        let mut classifier: Option<String> = None;
+        let mut scope: Option<String> = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            let (field, value) = match key.as_str() {
+                "group_id" => (CoordinateField::GroupId, &mut group_id),
+                "artifact_id" => (CoordinateField::ArtifactId, &mut artifact_id),
+                "version" => (CoordinateField::Version, &mut version),
+                "packaging" => (CoordinateField::Packaging, &mut packaging),
+                "classifier" => (CoordinateField::Classifier, &mut classifier),
+                "scope" => (CoordinateField::Scope, &mut scope),
+                _ => return Err(A::Error::unknown_field(&key, FIELDS)),
+            };
+
+            *value = map.next_value()?;
+            if value.as_deref() == Some("") {
+                return Err(A::Error::custom(format_args!(
+                    "{} `{}`",
+                    CoordinatesParseErrorKind::EmptyField,
+                    field
+                )));
+            }
+        }
+
+        let group_id = group_id.ok_or_else(|| A::Error::missing_field("group_id"))?;
+        let artifact_id = artifact_id.ok_or_else(|| A::Error::missing_field("artifact_id"))?;
+        let version = version.ok_or_else(|| A::Error::missing_field("version"))?;
+
+        let mut coords =
+            MavenCoordinates::from_parts(&group_id, &artifact_id, ComparableVersion::new(&version));
+        // A classifier needs a packaging, just like with `with_classifier`
+        coords.packaging = packaging.or_else(|| classifier.as_ref().map(|_| "jar".to_string()));
+        coords.classifier = classifier;
+        coords.scope = scope;
+
+        Ok(coords)
+    }
+}
+
+const FIELDS: &[&str] = &[
+    "group_id",
+    "artifact_id",
+    "version",
+    "packaging",
+    "classifier",
+    "scope",
+];
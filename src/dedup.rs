@@ -0,0 +1,38 @@
+use crate::ComparableVersion;
+use std::collections::HashSet;
+
+/// Which of several equal versions [dedup_versions] keeps.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum Representative {
+    /// The first of the equal versions, as written.
+    First,
+    /// The [canonical form] of the versions.
+    ///
+    /// [canonical form]: ComparableVersion::canonical
+    Canonical,
+}
+
+/// Removes versions which are equal according to Maven, such as `1.0`, `1.0.0` and `1-0`, keeping
+/// a single representative of each in the position the first of them appeared at.
+///
+/// ```
+/// # use mvn_version::{dedup_versions, ComparableVersion, Representative};
+/// let versions = ["1.0", "2.0", "1.0.0", "1-0"].iter().map(|v| ComparableVersion::new(v));
+/// let deduped = dedup_versions(versions, Representative::First);
+/// assert_eq!(deduped.iter().map(|v| v.as_str()).collect::<Vec<_>>(), ["1.0", "2.0"]);
+/// ```
+pub fn dedup_versions<I>(versions: I, representative: Representative) -> Vec<ComparableVersion>
+where
+    I: IntoIterator<Item = ComparableVersion>,
+{
+    let mut seen = HashSet::new();
+
+    versions
+        .into_iter()
+        .filter(|v| seen.insert(v.clone()))
+        .map(|v| match representative {
+            Representative::First => v,
+            Representative::Canonical => ComparableVersion::new(v.canonical()),
+        })
+        .collect()
+}
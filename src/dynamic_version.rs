@@ -0,0 +1,106 @@
+use crate::{ComparableVersion, RangeParseError, VersionRange};
+use std::borrow::Borrow;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A Gradle dynamic version selector, which picks a version out of a list of available ones.
+///
+/// The following selectors are supported:
+///
+/// - `1.+` or `2.3.+` select the highest version starting with `1.` or `2.3.` respectively. Like
+///   in Gradle, this is a plain string prefix match, so `1+` also matches `10.0`. A lone `+`
+///   matches everything.
+/// - `latest.release` selects the highest version which isn't a SNAPSHOT.
+/// - `latest.integration` selects the highest version of all.
+/// - `[1.0,2.0)` and any other bracketed Maven range select the highest version in the range.
+/// - Anything else selects exactly that version.
+///
+/// Versions are ordered according to [ComparableVersion], which is not always identical to
+/// Gradle's own ordering.
+///
+/// ```
+/// # use mvn_version::{ComparableVersion, DynamicVersion};
+/// let available = ["1.9", "1.10", "2.0-SNAPSHOT"].iter().map(|v| ComparableVersion::new(v));
+/// let selector = DynamicVersion::new("1.+").unwrap();
+/// assert_eq!(selector.select(available).unwrap().as_str(), "1.10");
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub enum DynamicVersion {
+    /// A prefix matcher such as `1.+`, holding the prefix without the trailing `+`.
+    Prefix(String),
+    /// `latest.release`
+    LatestRelease,
+    /// `latest.integration`
+    LatestIntegration,
+    /// A bracketed Maven version range.
+    Range(VersionRange),
+    /// A plain version, which only matches itself.
+    Exact(ComparableVersion),
+}
+
+impl DynamicVersion {
+    /// Parses a dynamic version selector. This can only fail if the selector looks like a range
+    /// but isn't a valid one.
+    pub fn new(s: &str) -> Result<Self, RangeParseError> {
+        if let Some(prefix) = s.strip_suffix('+') {
+            return Ok(DynamicVersion::Prefix(prefix.to_string()));
+        }
+
+        match s {
+            "latest.release" => Ok(DynamicVersion::LatestRelease),
+            "latest.integration" => Ok(DynamicVersion::LatestIntegration),
+            _ if s.starts_with(['[', '(']) => Ok(DynamicVersion::Range(VersionRange::new(s)?)),
+            _ => Ok(DynamicVersion::Exact(ComparableVersion::new(s))),
+        }
+    }
+
+    /// Returns whether this selector can match more than one version.
+    pub fn is_dynamic(&self) -> bool {
+        !matches!(self, DynamicVersion::Exact(_))
+    }
+
+    /// Returns whether the given version is acceptable to this selector.
+    pub fn accepts(&self, version: &ComparableVersion) -> bool {
+        match self {
+            DynamicVersion::Prefix(prefix) => version.as_str().starts_with(prefix.as_str()),
+            DynamicVersion::LatestRelease => !version.is_snapshot(),
+            DynamicVersion::LatestIntegration => true,
+            DynamicVersion::Range(range) => range.contains(version),
+            DynamicVersion::Exact(exact) => exact == version,
+        }
+    }
+
+    /// Selects the highest acceptable version out of the available ones, or `None` if there is
+    /// none.
+    pub fn select<I>(&self, available: I) -> Option<I::Item>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<ComparableVersion>,
+    {
+        available
+            .into_iter()
+            .filter(|v| self.accepts(v.borrow()))
+            .max_by(|a, b| a.borrow().cmp(b.borrow()))
+    }
+}
+
+impl Display for DynamicVersion {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            DynamicVersion::Prefix(prefix) => write!(f, "{}+", prefix),
+            DynamicVersion::LatestRelease => f.write_str("latest.release"),
+            DynamicVersion::LatestIntegration => f.write_str("latest.integration"),
+            DynamicVersion::Range(range) => range.fmt(f),
+            DynamicVersion::Exact(version) => version.fmt(f),
+        }
+    }
+}
+
+impl FromStr for DynamicVersion {
+    type Err = RangeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
@@ -0,0 +1,199 @@
+// Maven Resolver's (formerly Eclipse Aether) GenericVersion, which orders versions during dependency
+// resolution, as opposed to the ComparableVersion Maven uses everywhere else.
+
+use crate::BigUint;
+use std::cmp::Ordering;
+
+/// A single token of a version. Kinds of tokens are ordered the same way as the variants.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd)]
+enum Item {
+    /// `min` as the last token, which is lower than anything.
+    Min,
+    /// A known qualifier, by its rank relative to a release.
+    Qualifier(i8),
+    /// Any other text, lowercased.
+    String(String),
+    Int(u32),
+    BigInt(BigUint),
+    /// `max` as the last token, which is higher than anything.
+    Max,
+}
+
+#[derive(Eq, PartialEq, Clone, Copy)]
+enum State {
+    Start,
+    Letters,
+    LeadingZeroes,
+    Digits,
+}
+
+const ALPHA: i8 = -5;
+const BETA: i8 = -4;
+const MILESTONE: i8 = -3;
+const RC: i8 = -2;
+const SNAPSHOT: i8 = -1;
+const RELEASE: i8 = 0;
+const SP: i8 = 1;
+
+impl Item {
+    fn is_number(&self) -> bool {
+        !matches!(self, Item::Qualifier(_) | Item::String(_))
+    }
+
+    /// Where this item stands in comparison to padding, i.e. `0` or `ga`.
+    fn cmp_padding(&self) -> Ordering {
+        match self {
+            Item::Min => Ordering::Less,
+            Item::Qualifier(q) => q.cmp(&RELEASE),
+            Item::Int(i) => i.cmp(&0),
+            Item::String(_) | Item::BigInt(_) | Item::Max => Ordering::Greater,
+        }
+    }
+}
+
+/// Splits a version into items. Tokens are separated by `.`, `-` and `_`, as well as by
+/// transitions between letters and digits. Unlike ComparableVersion, it doesn't matter which.
+fn parse(version: &str) -> Vec<Item> {
+    let chars = version.chars().collect::<Vec<char>>();
+    let mut items = Vec::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        let mut state = State::Start;
+        let mut start = index;
+        let mut end = chars.len();
+        let mut terminated_by_number = false;
+
+        while index < chars.len() {
+            let c = chars[index];
+
+            if c == '.' || c == '-' || c == '_' {
+                end = index;
+                index += 1;
+                break;
+            } else if let Some(digit) = c.to_digit(10) {
+                if state == State::Letters {
+                    end = index;
+                    terminated_by_number = true;
+                    break;
+                } else if state == State::LeadingZeroes {
+                    start += 1;
+                }
+
+                state = if state == State::Digits || digit > 0 {
+                    State::Digits
+                } else {
+                    State::LeadingZeroes
+                };
+            } else {
+                if state == State::LeadingZeroes || state == State::Digits {
+                    end = index;
+                    break;
+                }
+
+                state = State::Letters;
+            }
+
+            index += 1;
+        }
+
+        let token = chars[start..end].iter().collect::<String>();
+        let item = if token.is_empty() {
+            Item::Int(0)
+        } else if state != State::Letters {
+            if token.len() < 10 {
+                Item::Int(token.parse().unwrap())
+            } else {
+                Item::BigInt(token.parse().unwrap())
+            }
+        } else {
+            let token = token.to_lowercase();
+            match (index >= chars.len(), terminated_by_number, token.as_str()) {
+                (true, _, "min") => Item::Min,
+                (true, _, "max") => Item::Max,
+                (_, true, "a") | (_, _, "alpha") => Item::Qualifier(ALPHA),
+                (_, true, "b") | (_, _, "beta") => Item::Qualifier(BETA),
+                (_, true, "m") | (_, _, "milestone") => Item::Qualifier(MILESTONE),
+                (_, _, "rc") | (_, _, "cr") => Item::Qualifier(RC),
+                (_, _, "snapshot") => Item::Qualifier(SNAPSHOT),
+                (_, _, "ga") | (_, _, "final") | (_, _, "release") => Item::Qualifier(RELEASE),
+                (_, _, "sp") => Item::Qualifier(SP),
+                _ => Item::String(token),
+            }
+        };
+
+        items.push(item);
+    }
+
+    trim_padding(&mut items);
+    items
+}
+
+/// Removes padding from the end of each run of numbers or qualifiers, so `1.0.0-ga` becomes `1`,
+/// unless it's all that separates two runs of the other kind, like the `ga` in `1-ga-1`.
+fn trim_padding(items: &mut Vec<Item>) {
+    let mut number = None;
+    let mut end = items.len().saturating_sub(1);
+
+    for i in (1..items.len()).rev() {
+        let is_number = items[i].is_number();
+        if number != Some(is_number) {
+            end = i;
+            number = Some(is_number);
+        }
+
+        if end == i
+            && (i == items.len() - 1 || items[i - 1].is_number() == is_number)
+            && items[i].cmp_padding() == Ordering::Equal
+        {
+            items.remove(i);
+            end -= 1;
+        }
+    }
+}
+
+/// Compares the remaining items against padding, up to the end of the current run of numbers or
+/// qualifiers if `number` is given.
+fn cmp_padding(items: &[Item], number: Option<bool>) -> Ordering {
+    items
+        .iter()
+        .take_while(|item| number.is_none_or(|number| number == item.is_number()))
+        .map(Item::cmp_padding)
+        .find(|order| *order != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Compares two version strings the way Maven Resolver's GenericVersionScheme does. See
+/// [`crate::VersionScheme::Generic`].
+pub(crate) fn cmp_generic(a: &str, b: &str) -> Ordering {
+    let these = parse(a);
+    let those = parse(b);
+    let mut number = true;
+
+    for index in 0.. {
+        match (these.get(index), those.get(index)) {
+            (None, None) => break,
+            (Some(_), None) => return cmp_padding(&these[index..], None),
+            (None, Some(_)) => return cmp_padding(&those[index..], None).reverse(),
+            (Some(this), Some(that)) if this.is_number() != that.is_number() => {
+                // A number against a qualifier: whichever continues the current run is compared
+                // against padding instead
+                return if this.is_number() == number {
+                    cmp_padding(&these[index..], Some(number))
+                } else {
+                    cmp_padding(&those[index..], Some(number)).reverse()
+                };
+            }
+            (Some(this), Some(that)) => {
+                let order = this.cmp(that);
+                if order != Ordering::Equal {
+                    return order;
+                }
+
+                number = this.is_number();
+            }
+        }
+    }
+
+    Ordering::Equal
+}
@@ -0,0 +1,200 @@
+use crate::{ComparableVersion, RangeParseError, RangeParseErrorKind, Restriction};
+use std::borrow::Borrow;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// An Apache Ivy revision matcher, which picks a version out of a list of available ones.
+///
+/// The following matchers are supported:
+///
+/// - `1.0.+` selects the highest revision starting with `1.0.`. Like in Ivy, this is a plain
+///   string prefix match.
+/// - `latest.integration`, `latest.milestone` and `latest.release` select the highest revision
+///   whose [IvyStatus] is at least the given one.
+/// - `[1.0,2.0[` and the other Ivy range forms select the highest revision in the range. An
+///   outward-facing bracket (`]1.0` or `2.0[`) excludes the bound, and an unbounded side is
+///   written with a parenthesis, e.g. `[1.0,)` or `(,2.0]`.
+/// - Anything else selects exactly that revision.
+///
+/// Revisions are ordered according to [ComparableVersion].
+///
+/// ```
+/// # use mvn_version::{ComparableVersion, IvyMatcher};
+/// let available = ["1.0", "1.5", "2.0"].iter().map(|v| ComparableVersion::new(v));
+/// let matcher = IvyMatcher::new("[1.0,2.0[").unwrap();
+/// assert_eq!(matcher.select(available).unwrap().as_str(), "1.5");
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub enum IvyMatcher {
+    /// A sub-revision matcher such as `1.0.+`, holding the prefix without the trailing `+`.
+    SubRevision(String),
+    /// `latest.<status>`
+    Latest(IvyStatus),
+    /// A bracketed range.
+    Range(Restriction),
+    /// A plain revision, which only matches itself.
+    Exact(ComparableVersion),
+}
+
+/// The status of an Ivy module, in ascending order of maturity.
+///
+/// Since the status is not part of the version, it's inferred from the qualifiers instead:
+/// SNAPSHOTs are integration builds, other prereleases such as `1.0-beta-1` or `1.0-rc1` are
+/// milestones, and everything else is a release.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy)]
+pub enum IvyStatus {
+    Integration,
+    Milestone,
+    Release,
+}
+
+impl IvyStatus {
+    /// Infers the status of the given version from its qualifiers.
+    pub fn of(version: &ComparableVersion) -> Self {
+        if version.is_snapshot() {
+            IvyStatus::Integration
+        } else if version.is_prerelease() {
+            IvyStatus::Milestone
+        } else {
+            IvyStatus::Release
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            IvyStatus::Integration => "integration",
+            IvyStatus::Milestone => "milestone",
+            IvyStatus::Release => "release",
+        }
+    }
+}
+
+impl IvyMatcher {
+    /// Parses an Ivy revision matcher. This can only fail if the matcher looks like a range but
+    /// isn't a valid one.
+    pub fn new(s: &str) -> Result<Self, RangeParseError> {
+        if let Some(prefix) = s.strip_suffix('+') {
+            return Ok(IvyMatcher::SubRevision(prefix.to_string()));
+        }
+
+        match s {
+            "latest.integration" => Ok(IvyMatcher::Latest(IvyStatus::Integration)),
+            "latest.milestone" => Ok(IvyMatcher::Latest(IvyStatus::Milestone)),
+            "latest.release" => Ok(IvyMatcher::Latest(IvyStatus::Release)),
+            _ if s.starts_with(['[', ']', '(']) => parse_range(s).map(IvyMatcher::Range),
+            _ => Ok(IvyMatcher::Exact(ComparableVersion::new(s))),
+        }
+    }
+
+    /// Returns whether the given version is acceptable to this matcher.
+    pub fn accepts(&self, version: &ComparableVersion) -> bool {
+        match self {
+            IvyMatcher::SubRevision(prefix) => version.as_str().starts_with(prefix.as_str()),
+            IvyMatcher::Latest(status) => IvyStatus::of(version) >= *status,
+            IvyMatcher::Range(restriction) => restriction.contains(version),
+            IvyMatcher::Exact(exact) => exact == version,
+        }
+    }
+
+    /// Selects the highest acceptable version out of the available ones, or `None` if there is
+    /// none.
+    pub fn select<I>(&self, available: I) -> Option<I::Item>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<ComparableVersion>,
+    {
+        available
+            .into_iter()
+            .filter(|v| self.accepts(v.borrow()))
+            .max_by(|a, b| a.borrow().cmp(b.borrow()))
+    }
+}
+
+/// Parses an Ivy range such as "[1.0,2.0[" into a restriction.
+fn parse_range(s: &str) -> Result<Restriction, RangeParseError> {
+    let end = s.len() - 1;
+
+    if end == 0 || !s.ends_with([']', '[', ')']) {
+        return Err(RangeParseError::new(
+            RangeParseErrorKind::UnbalancedBracket,
+            end,
+        ));
+    }
+
+    let inner = &s[1..end];
+    let comma = match inner.find(',') {
+        Some(i) => i,
+        None => {
+            return Err(RangeParseError::new(
+                RangeParseErrorKind::ExpectedSingleRestriction,
+                0,
+            ))
+        }
+    };
+
+    if let Some(i) = inner[comma + 1..].find(',') {
+        return Err(RangeParseError::new(
+            RangeParseErrorKind::TooManyBounds,
+            comma + 2 + i,
+        ));
+    }
+
+    let lower = Some(inner[..comma].trim())
+        .filter(|s| !s.is_empty())
+        .map(ComparableVersion::new);
+    let upper = Some(inner[comma + 1..].trim())
+        .filter(|s| !s.is_empty())
+        .map(ComparableVersion::new);
+
+    let lower_inclusive = lower.is_some() && s.starts_with('[');
+    let upper_inclusive = upper.is_some() && s.ends_with(']');
+
+    let restriction = Restriction::new(lower, lower_inclusive, upper, upper_inclusive);
+
+    if restriction.is_empty() {
+        return Err(RangeParseError::new(
+            RangeParseErrorKind::BoundsOutOfOrder,
+            0,
+        ));
+    }
+
+    Ok(restriction)
+}
+
+impl Display for IvyStatus {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Display for IvyMatcher {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            IvyMatcher::SubRevision(prefix) => write!(f, "{}+", prefix),
+            IvyMatcher::Latest(status) => write!(f, "latest.{}", status),
+            IvyMatcher::Range(r) => {
+                match (r.lower_bound(), r.is_lower_bound_inclusive()) {
+                    (Some(lower), true) => write!(f, "[{}", lower)?,
+                    (Some(lower), false) => write!(f, "]{}", lower)?,
+                    (None, _) => f.write_str("(")?,
+                }
+                f.write_str(",")?;
+                match (r.upper_bound(), r.is_upper_bound_inclusive()) {
+                    (Some(upper), true) => write!(f, "{}]", upper),
+                    (Some(upper), false) => write!(f, "{}[", upper),
+                    (None, _) => f.write_str(")"),
+                }
+            }
+            IvyMatcher::Exact(version) => version.fmt(f),
+        }
+    }
+}
+
+impl FromStr for IvyMatcher {
+    type Err = RangeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
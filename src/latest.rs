@@ -0,0 +1,46 @@
+use crate::ComparableVersion;
+use std::borrow::Borrow;
+
+/// Determines the `latest` and `release` versions out of a list of versions, the way Maven does
+/// when merging repository metadata: `latest` is the highest version of all, and `release` is the
+/// highest version which isn't a SNAPSHOT. Either is `None` if there's no such version.
+///
+/// ```
+/// # use mvn_version::{latest_and_release, ComparableVersion};
+/// let versions = ["1.0", "1.1", "1.2-SNAPSHOT"].iter().map(|v| ComparableVersion::new(v));
+/// let (latest, release) = latest_and_release(versions);
+/// assert_eq!(latest.unwrap().as_str(), "1.2-SNAPSHOT");
+/// assert_eq!(release.unwrap().as_str(), "1.1");
+/// ```
+pub fn latest_and_release<I>(versions: I) -> (Option<I::Item>, Option<I::Item>)
+where
+    I: IntoIterator,
+    I::Item: Borrow<ComparableVersion> + Clone,
+{
+    let mut latest: Option<I::Item> = None;
+    let mut release: Option<I::Item> = None;
+
+    for version in versions {
+        let v = version.borrow();
+
+        if !v.is_snapshot() && is_higher(v, &release) {
+            release = Some(version.clone());
+        }
+        if is_higher(v, &latest) {
+            latest = Some(version);
+        }
+    }
+
+    (latest, release)
+}
+
+/// Whether the version is higher than the current candidate, or there's no candidate yet.
+fn is_higher<T: Borrow<ComparableVersion>>(
+    version: &ComparableVersion,
+    current: &Option<T>,
+) -> bool {
+    match current {
+        Some(current) => version > current.borrow(),
+        None => true,
+    }
+}
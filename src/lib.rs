@@ -1,5 +1,57 @@
 mod artifact_version;
+mod big_uint;
+mod catalog;
 mod comparable_version;
+mod coordinates;
+mod dedup;
+mod dynamic_version;
+mod generic_version;
+mod ivy_matcher;
+mod latest;
+mod meta_version;
+#[cfg(feature = "metadata")]
+pub mod metadata;
+mod newest;
+mod placeholder;
+mod scheme;
+mod snapshot;
+mod version_or_unknown;
+mod version_range;
 
-pub use artifact_version::ArtifactVersion;
-pub use comparable_version::ComparableVersion;
+pub use artifact_version::{
+    ArtifactVersion, ArtifactVersionBuilder, ArtifactVersionParseError,
+    ArtifactVersionParseErrorKind, LeadingZeroPolicy, QualifierKind, VersionComponent,
+};
+/// Numbers too long for a [u32], as found in [VersionItem::BigInt]. This is `num_bigint::BigUint`
+/// with the `bigint` feature, and a minimal stand-in without it. Only these are guaranteed under
+/// both: [FromStr](std::str::FromStr), whose error type isn't named, [Display](std::fmt::Display),
+/// [Debug], [Clone], [Eq], [Ord], [Hash], `From<u64>`, `From<u128>` and `u64: TryFrom<&BigUint>`.
+pub use big_uint::BigUint;
+pub use catalog::{CatalogEntry, VersionCatalog};
+pub use comparable_version::{
+    cmp_str, eq_str, hash_str, ComparableVersion, ComparableVersionBuilder, ComparableVersionRef,
+    Decomposition, PaddedItem, Padding, ParseOptions, Qualifier, QualifierRanking, QualifierTable,
+    Separator, Token, TokenKind, Tokens, VersionItem, VersionParseError, VersionParseErrorKind,
+    VersionPattern, VersionPatternError, VersionSegment,
+};
+#[cfg(feature = "serde")]
+pub use coordinates::structured_coordinates;
+pub use coordinates::{
+    group_by_ga, CoordinateField, CoordinatesParseError, CoordinatesParseErrorKind,
+    MavenCoordinates,
+};
+pub use dedup::{dedup_versions, Representative};
+pub use dynamic_version::DynamicVersion;
+pub use ivy_matcher::{IvyMatcher, IvyStatus};
+pub use latest::latest_and_release;
+pub use meta_version::MetaVersion;
+pub use newest::Newest;
+pub use placeholder::{has_placeholders, interpolate, PlaceholderError, PlaceholderErrorKind};
+pub use scheme::VersionScheme;
+pub use snapshot::{SnapshotTimestamp, SnapshotVersion};
+pub use version_or_unknown::{UnknownOrder, VersionOrUnknown};
+#[cfg(feature = "serde")]
+pub use version_range::structured_range;
+pub use version_range::{
+    RangeParseError, RangeParseErrorKind, RejectList, Restriction, VersionRange,
+};
@@ -0,0 +1,82 @@
+use crate::{latest_and_release, ComparableVersion};
+use std::borrow::Borrow;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A version which may also be one of the meta-versions `LATEST` and `RELEASE`, which Maven
+/// resolves against the versions available in a repository rather than comparing as versions.
+///
+/// [ComparableVersion] itself treats `LATEST` and `RELEASE` as ordinary qualifiers, so this type
+/// is only needed where meta-versions should be recognized, e.g. in plugin versions.
+///
+/// ```
+/// # use mvn_version::{ComparableVersion, MetaVersion};
+/// let available = ["1.0", "1.1", "1.2-SNAPSHOT"].iter().map(|v| ComparableVersion::new(v));
+/// let release = MetaVersion::new("RELEASE");
+/// assert_eq!(release.resolve(available).unwrap().as_str(), "1.1");
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub enum MetaVersion {
+    /// `LATEST`, which resolves to the highest version, including SNAPSHOTs.
+    Latest,
+    /// `RELEASE`, which resolves to the highest version which isn't a SNAPSHOT.
+    Release,
+    /// Any other version, which resolves to itself.
+    Version(ComparableVersion),
+}
+
+impl MetaVersion {
+    /// Parses a version, recognizing `LATEST` and `RELEASE`. Like in Maven, these are
+    /// case-sensitive.
+    pub fn new(s: &str) -> Self {
+        match s {
+            "LATEST" => MetaVersion::Latest,
+            "RELEASE" => MetaVersion::Release,
+            _ => MetaVersion::Version(ComparableVersion::new(s)),
+        }
+    }
+
+    /// Whether this is `LATEST` or `RELEASE`.
+    pub fn is_meta(&self) -> bool {
+        !matches!(self, MetaVersion::Version(_))
+    }
+
+    /// Resolves this version against the available ones, returning `None` if there's no suitable
+    /// version. A plain version resolves to the first available version equal to it.
+    pub fn resolve<I>(&self, available: I) -> Option<I::Item>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<ComparableVersion> + Clone,
+    {
+        match self {
+            MetaVersion::Latest => latest_and_release(available).0,
+            MetaVersion::Release => latest_and_release(available).1,
+            MetaVersion::Version(version) => available.into_iter().find(|v| v.borrow() == version),
+        }
+    }
+}
+
+impl Display for MetaVersion {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            MetaVersion::Latest => f.write_str("LATEST"),
+            MetaVersion::Release => f.write_str("RELEASE"),
+            MetaVersion::Version(version) => version.fmt(f),
+        }
+    }
+}
+
+impl From<&str> for MetaVersion {
+    fn from(s: &str) -> Self {
+        Self::new(s)
+    }
+}
+
+impl FromStr for MetaVersion {
+    type Err = core::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
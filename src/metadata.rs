@@ -0,0 +1,346 @@
+//! Reading of the `maven-metadata.xml` files Maven repositories keep next to artifacts.
+//!
+//! An artifact's metadata lists its available versions, along with the `latest` and `release`
+//! versions, while a SNAPSHOT version's metadata records the timestamp and build number of its
+//! latest deployment and the unique versions of each of its files.
+//!
+//! ```
+//! # use mvn_version::metadata::Metadata;
+//! let xml = r#"<metadata>
+//!   <groupId>com.example</groupId>
+//!   <artifactId>foo</artifactId>
+//!   <versioning>
+//!     <latest>1.1-SNAPSHOT</latest>
+//!     <release>1.0</release>
+//!     <versions>
+//!       <version>1.0</version>
+//!       <version>1.1-SNAPSHOT</version>
+//!     </versions>
+//!   </versioning>
+//! </metadata>"#;
+//!
+//! let metadata = Metadata::from_xml(xml).unwrap();
+//! assert_eq!(metadata.release().unwrap().as_str(), "1.0");
+//! assert_eq!(metadata.versions().len(), 2);
+//! ```
+
+use crate::ComparableVersion;
+use roxmltree::{Document, Node};
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The contents of a `maven-metadata.xml` file. Elements which are absent from the file are
+/// `None` or empty.
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Metadata {
+    group_id: Option<String>,
+    artifact_id: Option<String>,
+    version: Option<ComparableVersion>,
+    latest: Option<ComparableVersion>,
+    release: Option<ComparableVersion>,
+    versions: Vec<ComparableVersion>,
+    last_updated: Option<String>,
+    snapshot: Option<Snapshot>,
+    snapshot_artifacts: Vec<SnapshotArtifact>,
+}
+
+/// The `<snapshot>` element of a SNAPSHOT version's metadata, describing its latest deployment.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Default)]
+pub struct Snapshot {
+    timestamp: Option<String>,
+    build_number: u32,
+    local_copy: bool,
+}
+
+/// A `<snapshotVersion>` element of a SNAPSHOT version's metadata, giving the unique version of
+/// one of its files.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct SnapshotArtifact {
+    classifier: Option<String>,
+    extension: String,
+    version: ComparableVersion,
+    updated: Option<String>,
+}
+
+impl Metadata {
+    /// Parses the contents of a `maven-metadata.xml` file. Unknown elements are ignored, as are
+    /// the `<plugins>` listed in group metadata.
+    pub fn from_xml(xml: &str) -> Result<Self, MetadataParseError> {
+        let doc = Document::parse(xml).map_err(|e| {
+            let pos = e.pos();
+            MetadataParseError::new(MetadataParseErrorKind::MalformedXml, pos.row, pos.col)
+        })?;
+
+        let root = doc.root_element();
+        if root.tag_name().name() != "metadata" {
+            return Err(MetadataParseError::at(
+                MetadataParseErrorKind::UnexpectedRootElement,
+                &doc,
+                root,
+            ));
+        }
+
+        let mut metadata = Metadata::default();
+
+        for node in root.children().filter(Node::is_element) {
+            match node.tag_name().name() {
+                "groupId" => metadata.group_id = Some(text(node).to_string()),
+                "artifactId" => metadata.artifact_id = Some(text(node).to_string()),
+                "version" => metadata.version = Some(ComparableVersion::new(text(node))),
+                "versioning" => metadata.read_versioning(&doc, node)?,
+                _ => {}
+            }
+        }
+
+        Ok(metadata)
+    }
+
+    fn read_versioning(
+        &mut self,
+        doc: &Document,
+        versioning: Node,
+    ) -> Result<(), MetadataParseError> {
+        for node in versioning.children().filter(Node::is_element) {
+            match node.tag_name().name() {
+                "latest" => self.latest = Some(ComparableVersion::new(text(node))),
+                "release" => self.release = Some(ComparableVersion::new(text(node))),
+                "versions" => {
+                    self.versions = children(node, "version")
+                        .map(|v| ComparableVersion::new(text(v)))
+                        .collect()
+                }
+                "lastUpdated" => self.last_updated = Some(text(node).to_string()),
+                "snapshot" => self.snapshot = Some(Snapshot::read(doc, node)?),
+                "snapshotVersions" => {
+                    self.snapshot_artifacts = children(node, "snapshotVersion")
+                        .map(SnapshotArtifact::read)
+                        .collect()
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The group ID the metadata belongs to.
+    pub fn group_id(&self) -> Option<&str> {
+        self.group_id.as_deref()
+    }
+
+    /// The artifact ID the metadata belongs to, which is absent from group metadata.
+    pub fn artifact_id(&self) -> Option<&str> {
+        self.artifact_id.as_deref()
+    }
+
+    /// The version the metadata belongs to, which is only present in SNAPSHOT version metadata.
+    pub fn version(&self) -> Option<&ComparableVersion> {
+        self.version.as_ref()
+    }
+
+    /// The most recently deployed version, including SNAPSHOTs.
+    pub fn latest(&self) -> Option<&ComparableVersion> {
+        self.latest.as_ref()
+    }
+
+    /// The most recently deployed release version.
+    pub fn release(&self) -> Option<&ComparableVersion> {
+        self.release.as_ref()
+    }
+
+    /// All available versions, in the order they're listed in. This is usually, but not
+    /// necessarily, ascending.
+    pub fn versions(&self) -> &[ComparableVersion] {
+        &self.versions
+    }
+
+    /// When the metadata was last updated, as a UTC timestamp of the form `yyyyMMddHHmmss`.
+    pub fn last_updated(&self) -> Option<&str> {
+        self.last_updated.as_deref()
+    }
+
+    /// The latest deployment of a SNAPSHOT version.
+    pub fn snapshot(&self) -> Option<&Snapshot> {
+        self.snapshot.as_ref()
+    }
+
+    /// The unique versions of each file of a SNAPSHOT version.
+    pub fn snapshot_artifacts(&self) -> &[SnapshotArtifact] {
+        &self.snapshot_artifacts
+    }
+}
+
+impl FromStr for Metadata {
+    type Err = MetadataParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_xml(s)
+    }
+}
+
+impl Snapshot {
+    fn read(doc: &Document, snapshot: Node) -> Result<Self, MetadataParseError> {
+        let mut result = Snapshot::default();
+
+        for node in snapshot.children().filter(Node::is_element) {
+            match node.tag_name().name() {
+                "timestamp" => result.timestamp = Some(text(node).to_string()),
+                "buildNumber" => {
+                    result.build_number = text(node).parse().map_err(|_| {
+                        MetadataParseError::at(
+                            MetadataParseErrorKind::InvalidBuildNumber,
+                            doc,
+                            node,
+                        )
+                    })?
+                }
+                "localCopy" => result.local_copy = text(node) == "true",
+                _ => {}
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// The UTC timestamp of the deployment, of the form `yyyyMMdd.HHmmss`. This is absent if the
+    /// SNAPSHOT was only installed locally.
+    pub fn timestamp(&self) -> Option<&str> {
+        self.timestamp.as_deref()
+    }
+
+    /// The build number of the deployment, counting up from 1. This is 0 if it's absent.
+    pub fn build_number(&self) -> u32 {
+        self.build_number
+    }
+
+    /// Whether the SNAPSHOT was installed locally rather than deployed.
+    pub fn is_local_copy(&self) -> bool {
+        self.local_copy
+    }
+}
+
+impl SnapshotArtifact {
+    fn read(snapshot_version: Node) -> Self {
+        let mut result = SnapshotArtifact {
+            classifier: None,
+            extension: String::new(),
+            version: ComparableVersion::new(""),
+            updated: None,
+        };
+
+        for node in snapshot_version.children().filter(Node::is_element) {
+            match node.tag_name().name() {
+                "classifier" => {
+                    result.classifier = Some(text(node).to_string()).filter(|c| !c.is_empty())
+                }
+                "extension" => result.extension = text(node).to_string(),
+                "value" => result.version = ComparableVersion::new(text(node)),
+                "updated" => result.updated = Some(text(node).to_string()),
+                _ => {}
+            }
+        }
+
+        result
+    }
+
+    /// The classifier of the file, e.g. `sources`, or `None` for the main artifact.
+    pub fn classifier(&self) -> Option<&str> {
+        self.classifier.as_deref()
+    }
+
+    /// The extension of the file, e.g. `jar` or `pom`.
+    pub fn extension(&self) -> &str {
+        &self.extension
+    }
+
+    /// The unique version of the file, e.g. `1.0-20050205.060708-1`.
+    pub fn version(&self) -> &ComparableVersion {
+        &self.version
+    }
+
+    /// When the file was deployed, as a UTC timestamp of the form `yyyyMMddHHmmss`.
+    pub fn updated(&self) -> Option<&str> {
+        self.updated.as_deref()
+    }
+}
+
+/// Returns the trimmed text content of an element, or an empty string if it has none.
+fn text<'a>(node: Node<'a, '_>) -> &'a str {
+    node.text().map(str::trim).unwrap_or("")
+}
+
+/// Returns the child elements of a node with the given name.
+fn children<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    name: &'a str,
+) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    node.children()
+        .filter(move |n| n.is_element() && n.tag_name().name() == name)
+}
+
+/// The error returned when a `maven-metadata.xml` file can't be read. It carries the kind of
+/// problem encountered and the position in the file at which it was detected.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct MetadataParseError {
+    kind: MetadataParseErrorKind,
+    line: u32,
+    column: u32,
+}
+
+/// The specific reason a `maven-metadata.xml` file was rejected.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[non_exhaustive]
+pub enum MetadataParseErrorKind {
+    /// The file isn't well-formed XML.
+    MalformedXml,
+    /// The root element of the file isn't `<metadata>`.
+    UnexpectedRootElement,
+    /// The `<buildNumber>` of a snapshot isn't a number.
+    InvalidBuildNumber,
+}
+
+impl MetadataParseError {
+    fn new(kind: MetadataParseErrorKind, line: u32, column: u32) -> Self {
+        MetadataParseError { kind, line, column }
+    }
+
+    fn at(kind: MetadataParseErrorKind, doc: &Document, node: Node) -> Self {
+        let pos = doc.text_pos_at(node.range().start);
+        MetadataParseError::new(kind, pos.row, pos.col)
+    }
+
+    /// What went wrong.
+    pub fn kind(&self) -> MetadataParseErrorKind {
+        self.kind
+    }
+
+    /// The line at which the problem was detected, starting at 1.
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// The column at which the problem was detected, starting at 1.
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+}
+
+impl Display for MetadataParseErrorKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            MetadataParseErrorKind::MalformedXml => "malformed XML",
+            MetadataParseErrorKind::UnexpectedRootElement => "root element is not <metadata>",
+            MetadataParseErrorKind::InvalidBuildNumber => "invalid build number",
+        })
+    }
+}
+
+impl Display for MetadataParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} at {}:{}", self.kind, self.line, self.column)
+    }
+}
+
+impl Error for MetadataParseError {}
@@ -0,0 +1,61 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+
+/// A wrapper which orders versions newest first, i.e. reverses their ordering. This turns a
+/// `BinaryHeap` into a min-heap of versions, and makes sorted collections such as `BTreeSet`
+/// iterate from the newest version down.
+///
+/// ```
+/// # use mvn_version::{ComparableVersion, Newest};
+/// # use std::collections::BTreeSet;
+/// let versions = ["1.0", "2.0-SNAPSHOT", "2.0", "1.5"]
+///     .iter()
+///     .map(|v| Newest(ComparableVersion::new(v)))
+///     .collect::<BTreeSet<_>>();
+///
+/// let order = versions.iter().map(|v| v.as_str()).collect::<Vec<_>>();
+/// assert_eq!(order, ["2.0", "2.0-SNAPSHOT", "1.5", "1.0"]);
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy, Default)]
+pub struct Newest<T>(pub T);
+
+impl<T> Newest<T> {
+    /// Unwraps the version.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Ord> PartialOrd for Newest<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for Newest<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl<T> Deref for Newest<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> From<T> for Newest<T> {
+    fn from(version: T) -> Self {
+        Newest(version)
+    }
+}
+
+impl<T: Display> Display for Newest<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,198 @@
+use crate::ComparableVersion;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// Returns whether the string contains a property placeholder such as `${project.version}`, which
+/// has to be [interpolated] before it's a meaningful version.
+///
+/// ```
+/// # use mvn_version::has_placeholders;
+/// assert!(has_placeholders("${spring.version}"));
+/// assert!(has_placeholders("1.0-${revision}"));
+/// assert!(!has_placeholders("1.0"));
+/// ```
+///
+/// [interpolated]: interpolate
+pub fn has_placeholders(s: &str) -> bool {
+    match s.find("${") {
+        Some(start) => s[start + 2..].contains('}'),
+        None => false,
+    }
+}
+
+/// Replaces every property placeholder such as `${spring.version}` in the string with the value
+/// `lookup` returns for the property name. Values may contain placeholders themselves, which are
+/// replaced in turn.
+///
+/// ```
+/// # use mvn_version::interpolate;
+/// # use std::collections::HashMap;
+/// let mut properties = HashMap::new();
+/// properties.insert("revision", "1.2");
+/// properties.insert("changelist", "-SNAPSHOT");
+///
+/// let version = interpolate("${revision}${changelist}", |p| properties.get(p)).unwrap();
+/// assert_eq!(version, "1.2-SNAPSHOT");
+/// ```
+pub fn interpolate<F, V>(s: &str, mut lookup: F) -> Result<String, PlaceholderError>
+where
+    F: FnMut(&str) -> Option<V>,
+    V: AsRef<str>,
+{
+    let mut result = String::with_capacity(s.len());
+    expand(s, &mut lookup, &mut Vec::new(), &mut result)?;
+    Ok(result)
+}
+
+impl ComparableVersion {
+    /// [Interpolates] the property placeholders in the string, and parses the result as a
+    /// version.
+    ///
+    /// ```
+    /// # use mvn_version::ComparableVersion;
+    /// let version = ComparableVersion::interpolated("${project.version}", |p| match p {
+    ///     "project.version" => Some("1.0.0"),
+    ///     _ => None,
+    /// });
+    /// assert_eq!(version.unwrap(), ComparableVersion::new("1"));
+    /// ```
+    ///
+    /// [Interpolates]: crate::interpolate
+    pub fn interpolated<F, V>(s: &str, lookup: F) -> Result<Self, PlaceholderError>
+    where
+        F: FnMut(&str) -> Option<V>,
+        V: AsRef<str>,
+    {
+        interpolate(s, lookup).map(|v| ComparableVersion::new(&v))
+    }
+}
+
+fn expand<F, V>(
+    s: &str,
+    lookup: &mut F,
+    stack: &mut Vec<String>,
+    out: &mut String,
+) -> Result<(), PlaceholderError>
+where
+    F: FnMut(&str) -> Option<V>,
+    V: AsRef<str>,
+{
+    let mut pos = 0;
+
+    while let Some(i) = s[pos..].find("${") {
+        let start = pos + i;
+        out.push_str(&s[pos..start]);
+
+        let end = match s[start + 2..].find('}') {
+            Some(j) => start + 2 + j,
+            None => {
+                return Err(PlaceholderError::new(
+                    PlaceholderErrorKind::UnterminatedPlaceholder,
+                    None,
+                    start,
+                ))
+            }
+        };
+
+        let name = &s[start + 2..end];
+        if stack.iter().any(|p| p == name) {
+            return Err(PlaceholderError::new(
+                PlaceholderErrorKind::RecursiveProperty,
+                Some(name),
+                start,
+            ));
+        }
+
+        let value = match lookup(name) {
+            Some(value) => value,
+            None => {
+                return Err(PlaceholderError::new(
+                    PlaceholderErrorKind::UndefinedProperty,
+                    Some(name),
+                    start,
+                ))
+            }
+        };
+
+        // Errors inside the value are reported at the placeholder which led to it
+        stack.push(name.to_string());
+        expand(value.as_ref(), lookup, stack, out)
+            .map_err(|e| PlaceholderError { offset: start, ..e })?;
+        stack.pop();
+
+        pos = end + 1;
+    }
+
+    out.push_str(&s[pos..]);
+    Ok(())
+}
+
+/// The error returned when property placeholders can't be interpolated. It carries the kind of
+/// problem encountered, the property concerned if any, and the byte offset into the string at
+/// which it was detected.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct PlaceholderError {
+    kind: PlaceholderErrorKind,
+    property: Option<String>,
+    offset: usize,
+}
+
+/// The specific reason property placeholders couldn't be interpolated.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[non_exhaustive]
+pub enum PlaceholderErrorKind {
+    /// A placeholder referred to a property without a value, e.g. `${undefined}`.
+    UndefinedProperty,
+    /// The value of a property referred back to the property itself, possibly through other
+    /// properties.
+    RecursiveProperty,
+    /// A placeholder was opened but never closed, e.g. `${revision`.
+    UnterminatedPlaceholder,
+}
+
+impl PlaceholderError {
+    fn new(kind: PlaceholderErrorKind, property: Option<&str>, offset: usize) -> Self {
+        PlaceholderError {
+            kind,
+            property: property.map(str::to_string),
+            offset,
+        }
+    }
+
+    /// What went wrong.
+    pub fn kind(&self) -> PlaceholderErrorKind {
+        self.kind
+    }
+
+    /// The name of the property which was undefined or recursive.
+    pub fn property(&self) -> Option<&str> {
+        self.property.as_deref()
+    }
+
+    /// The byte offset into the string of the placeholder at which the problem was detected.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Display for PlaceholderErrorKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            PlaceholderErrorKind::UndefinedProperty => "undefined property",
+            PlaceholderErrorKind::RecursiveProperty => "recursive property",
+            PlaceholderErrorKind::UnterminatedPlaceholder => "unterminated placeholder",
+        })
+    }
+}
+
+impl Display for PlaceholderError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.property {
+            Some(property) => write!(f, "{} `{}` at byte {}", self.kind, property, self.offset),
+            None => write!(f, "{} at byte {}", self.kind, self.offset),
+        }
+    }
+}
+
+impl Error for PlaceholderError {}
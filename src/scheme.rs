@@ -0,0 +1,165 @@
+use crate::comparable_version::{cmp_maven4, cmp_str};
+use crate::generic_version::cmp_generic;
+use crate::{ArtifactVersion, ComparableVersion};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+/// The rules by which a particular Maven version orders versions. The usual comparison operators
+/// always use [`VersionScheme::Maven3`]; the other schemes are opt-in, per comparison, for tools
+/// which need to reproduce how other Maven versions resolved dependencies.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum VersionScheme {
+    /// Maven 2's `DefaultArtifactVersion` ordering. Versions are compared by their major, minor
+    /// and incremental versions, and then:
+    ///
+    /// - A version with a qualifier is older than one without.
+    /// - If one qualifier starts with the other, the longer one is older, so `alpha-1-SNAPSHOT`
+    ///   comes before `alpha-1`.
+    /// - Other qualifiers are compared as case-sensitive strings, so `beta` comes before `rc` but
+    ///   `RC` comes before `beta`.
+    /// - Without qualifiers, the build numbers decide.
+    ///
+    /// Versions which don't fit the `major.minor.incremental-qualifier` format are all qualifier,
+    /// and so are versions with a component larger than [i32::MAX]. A build number larger than
+    /// that is a qualifier instead.
+    Maven2,
+    /// The [ComparableVersion] ordering used since Maven 3.
+    #[default]
+    Maven3,
+    /// Maven 4's ordering, which is Maven 3's except that a qualifier directly followed by a
+    /// number is kept together with it, rather than split into two segments. So `1.0.RC1` comes
+    /// before `1.0-RC2` and `2.0.RC1` equals `2.RC1`, which isn't the case under Maven 3. Such a
+    /// qualifier comes after the same qualifier without a number, whatever follows that.
+    Maven4,
+    /// The GenericVersionScheme of Maven Resolver, formerly Eclipse Aether, which Maven uses to
+    /// resolve version ranges and conflicts between dependencies. It's close to Maven 3's ordering,
+    /// but:
+    ///
+    /// - `_` separates tokens too, and it doesn't matter whether tokens are separated by `.`, `-`
+    ///   or a transition between letters and digits, so `1-1` equals `1.1` and `1rc1` equals
+    ///   `1.rc.1`.
+    /// - `min` and `max` as the last token are lower and higher than anything else, so
+    ///   `[1.0,1.max)` matches every `1.x` version.
+    Generic,
+}
+
+impl VersionScheme {
+    /// Compares two version strings under this scheme.
+    ///
+    /// ```
+    /// # use mvn_version::VersionScheme;
+    /// # use std::cmp::Ordering;
+    /// assert_eq!(VersionScheme::Maven3.compare("1.0-RC1", "1.0-beta"), Ordering::Greater);
+    /// assert_eq!(VersionScheme::Maven2.compare("1.0-RC1", "1.0-beta"), Ordering::Less);
+    ///
+    /// assert_eq!(VersionScheme::Maven3.compare("1.0.RC1", "1.0-RC2"), Ordering::Greater);
+    /// assert_eq!(VersionScheme::Maven4.compare("1.0.RC1", "1.0-RC2"), Ordering::Less);
+    ///
+    /// assert_eq!(VersionScheme::Maven3.compare("1.max", "1.5"), Ordering::Less);
+    /// assert_eq!(VersionScheme::Generic.compare("1.max", "1.5"), Ordering::Greater);
+    /// ```
+    pub fn compare(self, a: &str, b: &str) -> Ordering {
+        match self {
+            VersionScheme::Maven2 => maven2_cmp(&ArtifactVersion::new(a), &ArtifactVersion::new(b)),
+            VersionScheme::Maven3 => cmp_str(a, b),
+            VersionScheme::Maven4 => cmp_maven4(a, b),
+            VersionScheme::Generic => cmp_generic(a, b),
+        }
+    }
+}
+
+impl ComparableVersion {
+    /// Compares with another version under the given scheme, rather than the usual
+    /// [`VersionScheme::Maven3`] one.
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, VersionScheme};
+    /// # use std::cmp::Ordering;
+    /// let a = ComparableVersion::new("1.0-alpha-10");
+    /// let b = ComparableVersion::new("1.0-alpha-9");
+    /// assert_eq!(a.cmp_with_scheme(&b, VersionScheme::Maven3), Ordering::Greater);
+    /// assert_eq!(a.cmp_with_scheme(&b, VersionScheme::Maven2), Ordering::Less);
+    /// ```
+    pub fn cmp_with_scheme(&self, other: &ComparableVersion, scheme: VersionScheme) -> Ordering {
+        match scheme {
+            VersionScheme::Maven3 => self.cmp(other),
+            _ => scheme.compare(self.as_str(), other.as_str()),
+        }
+    }
+}
+
+impl ArtifactVersion {
+    /// Compares with another version under the given scheme, rather than the usual
+    /// [`VersionScheme::Maven3`] one.
+    pub fn cmp_with_scheme(&self, other: &ArtifactVersion, scheme: VersionScheme) -> Ordering {
+        match scheme {
+            VersionScheme::Maven2 => maven2_cmp(self, other),
+            _ => self
+                .comparable()
+                .cmp_with_scheme(other.comparable(), scheme),
+        }
+    }
+}
+
+fn maven2_cmp(a: &ArtifactVersion, b: &ArtifactVersion) -> Ordering {
+    let (a, b) = (Maven2Version::new(a), Maven2Version::new(b));
+
+    a.major
+        .cmp(&b.major)
+        .then(a.minor.cmp(&b.minor))
+        .then(a.incremental.cmp(&b.incremental))
+        .then_with(|| match (&a.qualifier, &b.qualifier) {
+            (Some(q), Some(r)) if q.len() > r.len() && q.starts_with(r.as_ref()) => Ordering::Less,
+            (Some(q), Some(r)) if q.len() < r.len() && r.starts_with(q.as_ref()) => {
+                Ordering::Greater
+            }
+            (Some(q), Some(r)) => q.cmp(r),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => a.build.cmp(&b.build),
+        })
+}
+
+/// The components of a version as Maven 2 reads them. It parses numbers with `Integer.valueOf`,
+/// so a version or build number larger than [i32::MAX] is a qualifier instead: the whole version
+/// for a version number, and just the build number for a build number.
+struct Maven2Version<'a> {
+    major: u64,
+    minor: u64,
+    incremental: u64,
+    build: u64,
+    qualifier: Option<Cow<'a, str>>,
+}
+
+impl<'a> Maven2Version<'a> {
+    fn new(version: &'a ArtifactVersion) -> Self {
+        let fits = |n: u64| n <= i32::MAX as u64;
+
+        if !fits(version.major()) || !fits(version.minor()) || !fits(version.incremental()) {
+            Maven2Version {
+                major: 0,
+                minor: 0,
+                incremental: 0,
+                build: 0,
+                qualifier: Some(Cow::Borrowed(version.as_str())),
+            }
+        } else if !fits(version.build()) {
+            Maven2Version {
+                major: version.major(),
+                minor: version.minor(),
+                incremental: version.incremental(),
+                build: 0,
+                qualifier: Some(Cow::Owned(version.build().to_string())),
+            }
+        } else {
+            Maven2Version {
+                major: version.major(),
+                minor: version.minor(),
+                incremental: version.incremental(),
+                build: version.build(),
+                qualifier: version.qualifier().as_deref().map(Cow::Borrowed),
+            }
+        }
+    }
+}
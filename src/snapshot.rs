@@ -0,0 +1,252 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// A SNAPSHOT version, which is either non-unique like `1.0-SNAPSHOT` or unique (timestamped) like
+/// `1.0-20050205.060708-1`. Unique versions are what non-unique ones are replaced with when they're
+/// deployed to a remote repository, and consist of the version without `SNAPSHOT`, the UTC
+/// timestamp of the deployment and a build number.
+///
+/// Like in Maven, any version ending in `SNAPSHOT` ignoring case is a non-unique SNAPSHOT.
+///
+/// ```
+/// # use mvn_version::SnapshotVersion;
+/// let snapshot = SnapshotVersion::new("1.0-20050205.060708-1").unwrap();
+/// assert!(snapshot.is_unique());
+/// assert_eq!(snapshot.base_version(), "1.0-SNAPSHOT");
+/// assert_eq!(snapshot.timestamp().unwrap().to_string(), "20050205.060708");
+/// assert_eq!(snapshot.build_number(), Some(1));
+///
+/// assert!(!SnapshotVersion::new("1.0-SNAPSHOT").unwrap().is_unique());
+/// assert!(SnapshotVersion::new("1.0").is_none());
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct SnapshotVersion {
+    orig: String,
+    release: String,
+    unique: Option<(SnapshotTimestamp, u32)>,
+}
+
+/// The UTC timestamp of a SNAPSHOT deployment, written as `yyyyMMdd.HHmmss`.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy)]
+pub struct SnapshotTimestamp {
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl SnapshotVersion {
+    /// Parses a SNAPSHOT version, returning `None` if the version isn't one.
+    pub fn new(s: &str) -> Option<Self> {
+        if let Some((release, timestamp, build_number)) = split_unique(s) {
+            return Some(SnapshotVersion {
+                orig: s.to_string(),
+                release: release.to_string(),
+                unique: Some((timestamp, build_number)),
+            });
+        }
+
+        let split = s.len().checked_sub("SNAPSHOT".len())?;
+        if !s.is_char_boundary(split) || !s[split..].eq_ignore_ascii_case("SNAPSHOT") {
+            return None;
+        }
+
+        let release = &s[..split];
+        Some(SnapshotVersion {
+            orig: s.to_string(),
+            release: release.strip_suffix('-').unwrap_or(release).to_string(),
+            unique: None,
+        })
+    }
+
+    /// Whether this is a unique (timestamped) SNAPSHOT version.
+    pub fn is_unique(&self) -> bool {
+        self.unique.is_some()
+    }
+
+    /// The non-unique version this SNAPSHOT version belongs to, e.g. `1.0-SNAPSHOT` for both
+    /// `1.0-SNAPSHOT` and `1.0-20050205.060708-1`. Non-unique versions are returned as is.
+    pub fn base_version(&self) -> String {
+        match self.unique {
+            Some(_) => format!("{}-SNAPSHOT", self.release),
+            None => self.orig.clone(),
+        }
+    }
+
+    /// The version without `SNAPSHOT` or the timestamp and build number, e.g. `1.0`.
+    pub fn release_version(&self) -> &str {
+        &self.release
+    }
+
+    /// The timestamp of a unique SNAPSHOT version.
+    pub fn timestamp(&self) -> Option<&SnapshotTimestamp> {
+        self.unique.as_ref().map(|(timestamp, _)| timestamp)
+    }
+
+    /// The build number of a unique SNAPSHOT version.
+    pub fn build_number(&self) -> Option<u32> {
+        self.unique.map(|(_, build_number)| build_number)
+    }
+
+    /// Returns the original string representation of the version.
+    pub fn as_str(&self) -> &str {
+        &self.orig
+    }
+
+    /// Returns the unique SNAPSHOT version with the given timestamp and build number, the way
+    /// Maven generates one when deploying this SNAPSHOT. A unique version gets its timestamp and
+    /// build number replaced.
+    ///
+    /// ```
+    /// # use mvn_version::{SnapshotTimestamp, SnapshotVersion};
+    /// let snapshot = SnapshotVersion::new("1.0-SNAPSHOT").unwrap();
+    /// let timestamp = SnapshotTimestamp::new(2005, 2, 5, 6, 7, 8).unwrap();
+    /// assert_eq!(snapshot.to_unique(timestamp, 1).as_str(), "1.0-20050205.060708-1");
+    /// ```
+    pub fn to_unique(&self, timestamp: SnapshotTimestamp, build_number: u32) -> Self {
+        SnapshotVersion {
+            orig: format!("{}-{}-{}", self.release, timestamp, build_number),
+            release: self.release.clone(),
+            unique: Some((timestamp, build_number)),
+        }
+    }
+}
+
+impl Display for SnapshotVersion {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.orig)
+    }
+}
+
+/// Splits a unique SNAPSHOT version such as `1.0-20050205.060708-1` into its parts.
+fn split_unique(s: &str) -> Option<(&str, SnapshotTimestamp, u32)> {
+    let (rest, build_number) = s.rsplit_once('-')?;
+    let (release, timestamp) = rest.rsplit_once('-')?;
+
+    if build_number.is_empty() || !build_number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    Some((
+        release,
+        SnapshotTimestamp::parse(timestamp)?,
+        build_number.parse().ok()?,
+    ))
+}
+
+impl SnapshotTimestamp {
+    /// Parses a timestamp of the form `yyyyMMdd.HHmmss`, returning `None` if it's malformed or
+    /// not a valid date and time.
+    pub fn parse(s: &str) -> Option<Self> {
+        let b = s.as_bytes();
+        if b.len() != 15 || b[8] != b'.' {
+            return None;
+        }
+
+        let number = |range: std::ops::Range<usize>| {
+            b[range].iter().try_fold(0u16, |n, &d| match d {
+                b'0'..=b'9' => Some(n * 10 + u16::from(d - b'0')),
+                _ => None,
+            })
+        };
+
+        SnapshotTimestamp::new(
+            number(0..4)?,
+            number(4..6)? as u8,
+            number(6..8)? as u8,
+            number(9..11)? as u8,
+            number(11..13)? as u8,
+            number(13..15)? as u8,
+        )
+    }
+
+    /// Constructs a timestamp from its parts, returning `None` if they're out of range. The day
+    /// is only checked against 31, regardless of the month.
+    pub fn new(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Option<Self> {
+        if year > 9999
+            || !(1..=12).contains(&month)
+            || !(1..=31).contains(&day)
+            || hour > 23
+            || minute > 59
+            || second > 59
+        {
+            return None;
+        }
+
+        Some(SnapshotTimestamp {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    /// The current time. This is only available with the `chrono` feature.
+    #[cfg(feature = "chrono")]
+    pub fn now() -> Self {
+        chrono::Utc::now().into()
+    }
+
+    /// The year, e.g. 2005.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// The month, from 1 to 12.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// The day of the month, from 1 to 31.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// The hour, from 0 to 23.
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// The minute, from 0 to 59.
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// The second, from 0 to 59.
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+}
+
+impl Display for SnapshotTimestamp {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:04}{:02}{:02}.{:02}{:02}{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for SnapshotTimestamp {
+    /// Converts the time to UTC and truncates it to whole seconds.
+    fn from(time: chrono::DateTime<Tz>) -> Self {
+        use chrono::{Datelike, Timelike};
+
+        let utc = time.naive_utc();
+        SnapshotTimestamp {
+            year: utc.year().clamp(0, 9999) as u16,
+            month: utc.month() as u8,
+            day: utc.day() as u8,
+            hour: utc.hour() as u8,
+            minute: utc.minute() as u8,
+            // Leap seconds are represented as the 59th second with extra nanoseconds
+            second: utc.second() as u8,
+        }
+    }
+}
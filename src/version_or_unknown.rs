@@ -0,0 +1,97 @@
+use crate::ComparableVersion;
+use std::cmp::Ordering;
+
+/// A version which may not be known, such as that of a dependency whose version couldn't be
+/// determined. Unlike with [Option], the ordering is spelled out: unknown versions sort before all
+/// known ones, and [`VersionOrUnknown::cmp_with`] can sort them last instead.
+///
+/// ```
+/// # use mvn_version::{UnknownOrder, VersionOrUnknown};
+/// let mut versions = vec![
+///     VersionOrUnknown::from("2.0"),
+///     VersionOrUnknown::Unknown,
+///     VersionOrUnknown::from("1.0"),
+/// ];
+///
+/// versions.sort();
+/// assert_eq!(versions[0], VersionOrUnknown::Unknown);
+///
+/// versions.sort_by(|a, b| a.cmp_with(b, UnknownOrder::Last));
+/// assert_eq!(versions[0], VersionOrUnknown::from("1.0"));
+/// assert_eq!(versions[2], VersionOrUnknown::Unknown);
+/// ```
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Default)]
+pub enum VersionOrUnknown {
+    /// A version which couldn't be determined.
+    #[default]
+    Unknown,
+    /// A known version.
+    Known(ComparableVersion),
+}
+
+/// Where [VersionOrUnknown::Unknown] sorts relative to known versions.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum UnknownOrder {
+    /// Unknown versions sort before all known ones. This is the default ordering.
+    First,
+    /// Unknown versions sort after all known ones.
+    Last,
+}
+
+impl VersionOrUnknown {
+    /// Returns the version if it's known.
+    pub fn known(&self) -> Option<&ComparableVersion> {
+        match self {
+            VersionOrUnknown::Known(version) => Some(version),
+            VersionOrUnknown::Unknown => None,
+        }
+    }
+
+    /// Whether the version is known.
+    pub fn is_known(&self) -> bool {
+        self.known().is_some()
+    }
+
+    /// Compares with another version, sorting unknown versions as given. Known versions are
+    /// compared as usual, and unknown versions are equal to each other.
+    pub fn cmp_with(&self, other: &Self, unknown: UnknownOrder) -> Ordering {
+        match (self, other, unknown) {
+            (VersionOrUnknown::Known(a), VersionOrUnknown::Known(b), _) => a.cmp(b),
+            (VersionOrUnknown::Unknown, VersionOrUnknown::Unknown, _) => Ordering::Equal,
+            (VersionOrUnknown::Unknown, _, UnknownOrder::First)
+            | (_, VersionOrUnknown::Unknown, UnknownOrder::Last) => Ordering::Less,
+            (VersionOrUnknown::Unknown, _, UnknownOrder::Last)
+            | (_, VersionOrUnknown::Unknown, UnknownOrder::First) => Ordering::Greater,
+        }
+    }
+}
+
+impl From<ComparableVersion> for VersionOrUnknown {
+    fn from(version: ComparableVersion) -> Self {
+        VersionOrUnknown::Known(version)
+    }
+}
+
+impl From<Option<ComparableVersion>> for VersionOrUnknown {
+    fn from(version: Option<ComparableVersion>) -> Self {
+        match version {
+            Some(version) => VersionOrUnknown::Known(version),
+            None => VersionOrUnknown::Unknown,
+        }
+    }
+}
+
+impl From<&str> for VersionOrUnknown {
+    fn from(s: &str) -> Self {
+        VersionOrUnknown::Known(ComparableVersion::new(s))
+    }
+}
+
+impl From<VersionOrUnknown> for Option<ComparableVersion> {
+    fn from(version: VersionOrUnknown) -> Self {
+        match version {
+            VersionOrUnknown::Known(version) => Some(version),
+            VersionOrUnknown::Unknown => None,
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// The error returned when a version range specification is malformed. It carries the kind of
+/// problem encountered and the byte offset into the specification at which it was detected.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct RangeParseError {
+    kind: RangeParseErrorKind,
+    offset: usize,
+}
+
+/// The specific reason a version range specification was rejected.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[non_exhaustive]
+pub enum RangeParseErrorKind {
+    /// A restriction was opened but never closed, e.g. `[1.0`, or, when parsing strictly, a
+    /// bracket appeared where none was expected, e.g. `1.0]`.
+    UnbalancedBracket,
+    /// The upper bound of a restriction is lower than its lower bound, e.g. `[2.0,1.0]`, or the
+    /// bounds are equal but not both inclusive, e.g. `[1.0,1.0)`.
+    BoundsOutOfOrder,
+    /// A restriction starts before the previous one ends, e.g. `[1.0,2.0],[1.5,3.0]`, or, when
+    /// parsing strictly, follows one which never ends, e.g. `[1.0,),[2.0,3.0]`.
+    RangesOverlap,
+    /// A restriction on a single version used parentheses, e.g. `(1.0)`.
+    SingleVersionNotInclusive,
+    /// A restriction on a single version contained no version at all, e.g. `[]`. Only reported
+    /// when parsing strictly.
+    EmptyVersion,
+    /// A restriction contained more than two bounds, e.g. `[1.0,2.0,3.0]`. Only reported when
+    /// parsing strictly.
+    TooManyBounds,
+    /// A bare version followed restrictions, e.g. `[1.0,2.0),1.5`, or, when parsing strictly,
+    /// was followed by them, e.g. `1.5,[1.0,2.0)`.
+    RecommendedVersionWithRanges,
+    /// More than one bare version was given, e.g. `1.0,1.5`. Only reported when parsing
+    /// strictly.
+    DuplicateRecommendedVersion,
+    /// Exactly one bracketed restriction was expected, e.g. when parsing a [Restriction] from
+    /// `1.0` or `[1.0],[2.0]`.
+    ///
+    /// [Restriction]: crate::Restriction
+    ExpectedSingleRestriction,
+    /// A caret or tilde requirement didn't start with an operator followed by a dot-separated
+    /// numeric version, e.g. `^a.b` or `~1..2`, or the component it would bump is already the
+    /// largest possible one, e.g. `^18446744073709551615`.
+    InvalidRequirement,
+}
+
+impl RangeParseError {
+    pub(crate) fn new(kind: RangeParseErrorKind, offset: usize) -> Self {
+        RangeParseError { kind, offset }
+    }
+
+    /// What went wrong.
+    pub fn kind(&self) -> RangeParseErrorKind {
+        self.kind
+    }
+
+    /// The byte offset into the specification at which the problem was detected.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Display for RangeParseErrorKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            RangeParseErrorKind::UnbalancedBracket => "unbalanced bracket",
+            RangeParseErrorKind::BoundsOutOfOrder => "range defies version ordering",
+            RangeParseErrorKind::RangesOverlap => "ranges overlap",
+            RangeParseErrorKind::SingleVersionNotInclusive => {
+                "single version must be surrounded by []"
+            }
+            RangeParseErrorKind::EmptyVersion => "empty version in range",
+            RangeParseErrorKind::TooManyBounds => "too many bounds in range",
+            RangeParseErrorKind::RecommendedVersionWithRanges => {
+                "only fully-qualified sets allowed in multiple set scenario"
+            }
+            RangeParseErrorKind::DuplicateRecommendedVersion => "more than one recommended version",
+            RangeParseErrorKind::ExpectedSingleRestriction => "expected a single restriction",
+            RangeParseErrorKind::InvalidRequirement => "invalid caret or tilde requirement",
+        })
+    }
+}
+
+impl Display for RangeParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.offset)
+    }
+}
+
+impl Error for RangeParseError {}
@@ -0,0 +1,718 @@
+// Maven version ranges are a comma-separated list of restrictions, each of which is an interval
+// written using mathematical notation, e.g. "[1.0,2.0)". Multiple restrictions form a union, which
+// is how specs like "[1.0,1.5),(1.5,2.0]" exclude a single version. A spec without any brackets is
+// a "soft" requirement on a single version: it matches everything, but the version is kept around
+// as a recommendation so that dependency mediation can prefer it.
+
+mod error;
+mod reject_list;
+mod restriction;
+mod semver;
+#[cfg(feature = "serde")]
+pub mod structured_range;
+
+pub use error::{RangeParseError, RangeParseErrorKind};
+pub use reject_list::RejectList;
+pub use restriction::Restriction;
+
+use crate::ComparableVersion;
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// This is an implementation of Maven's VersionRange.
+///
+/// A VersionRange is made up of any number of [Restriction]s, and contains a version if any of
+/// its restrictions do. The following are all valid range specifications:
+///
+/// - `1.0` is a soft requirement on 1.0, which matches every version
+/// - `[1.0]` matches exactly 1.0
+/// - `[1.0,2.0)` matches everything from 1.0 (inclusive) up to 2.0 (exclusive)
+/// - `(,1.0]` matches everything up to and including 1.0
+/// - `[1.0,1.5),(1.5,2.0]` matches everything from 1.0 to 2.0 except 1.5
+///
+/// Restrictions must be listed in ascending order and may not overlap, though like Maven, nothing
+/// is checked after a restriction that is unbounded from above.
+///
+/// The [Display] implementation produces the canonical form of the specification, with all
+/// whitespace removed, restrictions on a single version written as `[1.0]`, and soft requirements
+/// written as the bare version. Parsing the canonical form again yields an equal range, unless the
+/// range came from [`Self::from_version`] on a version which itself looks like a range, such as
+/// `[1.0]`: that's written as is and reads back as the hard requirement. With the `serde` feature,
+/// ranges are (de)serialized in this form, so the same goes for them.
+///
+/// Parsing follows Maven's `VersionRange.createFromVersionSpec` exactly, except that whitespace
+/// around the whole specification is ignored. [`Self::new_strict`] additionally rejects a few
+/// specifications Maven accepts but which are almost certainly mistakes.
+///
+/// ```
+/// # use mvn_version::{ComparableVersion, VersionRange};
+/// let range = VersionRange::new("[1.0,1.5),(1.5,2.0]").unwrap();
+/// assert!(range.contains(&ComparableVersion::new("1.2")));
+/// assert!(!range.contains(&ComparableVersion::new("1.5")));
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct VersionRange {
+    recommended_version: Option<ComparableVersion>,
+    restrictions: Vec<Restriction>,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for VersionRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for VersionRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let spec = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::new(&spec).map_err(serde::de::Error::custom)
+    }
+}
+
+impl VersionRange {
+    /// Parses a VersionRange from the given specification. Whitespace between restrictions and
+    /// around bounds is ignored. If the specification is malformed, the returned error describes
+    /// what was wrong and where.
+    ///
+    /// This accepts everything Maven does, including specifications which are probably mistakes,
+    /// such as `1.0,1.5`, which is a soft requirement on a version containing a comma.
+    pub fn new(spec: &str) -> Result<Self, RangeParseError> {
+        Self::parse(spec, false)
+    }
+
+    /// Parses a VersionRange like [`Self::new`], but also rejects specifications which Maven
+    /// accepts but which are almost certainly mistakes:
+    ///
+    /// - A restriction following one that is unbounded from above, e.g. `[1.0,),[2.0,3.0]`.
+    ///   Maven only checks for overlaps against bounded restrictions.
+    /// - A bare version containing a comma or bracket, e.g. `1.0,1.5` or `1.0]`, which Maven
+    ///   takes as a single odd-looking version.
+    /// - A bracket inside a restriction, e.g. `[1.0,[2.0]`, or a bound containing a comma, e.g.
+    ///   `[1.0,2.0,3.0]`, which Maven also takes as part of a version.
+    /// - An empty single-version restriction `[]`.
+    ///
+    /// ```
+    /// # use mvn_version::{RangeParseErrorKind, VersionRange};
+    /// let range = VersionRange::new("1.0,1.5").unwrap();
+    /// assert_eq!(range.recommended_version().unwrap().as_str(), "1.0,1.5");
+    ///
+    /// let err = VersionRange::new_strict("1.0,1.5").unwrap_err();
+    /// assert_eq!(err.kind(), RangeParseErrorKind::DuplicateRecommendedVersion);
+    /// ```
+    pub fn new_strict(spec: &str) -> Result<Self, RangeParseError> {
+        Self::parse(spec, true)
+    }
+
+    fn parse(spec: &str, strict: bool) -> Result<Self, RangeParseError> {
+        let mut restrictions: Vec<Restriction> = Vec::new();
+        let mut pos = skip_whitespace(spec, 0);
+
+        while spec[pos..].starts_with(['[', '(']) {
+            let end = match spec[pos..].find([']', ')']) {
+                Some(i) => pos + i,
+                None => {
+                    return Err(RangeParseError::new(
+                        RangeParseErrorKind::UnbalancedBracket,
+                        pos,
+                    ))
+                }
+            };
+
+            if let Some(i) = spec[pos + 1..end].find(['[', '(']).filter(|_| strict) {
+                return Err(RangeParseError::new(
+                    RangeParseErrorKind::UnbalancedBracket,
+                    pos + 1 + i,
+                ));
+            }
+
+            let restriction = parse_restriction(spec, pos, end, strict)?;
+
+            if let Some(previous) = restrictions.last() {
+                if overlaps(previous, &restriction, strict) {
+                    return Err(RangeParseError::new(
+                        RangeParseErrorKind::RangesOverlap,
+                        pos,
+                    ));
+                }
+            }
+
+            restrictions.push(restriction);
+
+            pos = skip_whitespace(spec, end + 1);
+            if spec[pos..].starts_with(',') {
+                pos = skip_whitespace(spec, pos + 1);
+            }
+        }
+
+        // A bare version is trimmed just like the bounds of a restriction
+        let rest = spec[pos..].trim_end();
+        let mut recommended_version = None;
+
+        if strict && rest.starts_with([']', ')']) {
+            return Err(RangeParseError::new(
+                RangeParseErrorKind::UnbalancedBracket,
+                pos,
+            ));
+        }
+
+        if !rest.is_empty() {
+            if !restrictions.is_empty() {
+                return Err(RangeParseError::new(
+                    RangeParseErrorKind::RecommendedVersionWithRanges,
+                    pos,
+                ));
+            }
+
+            // Maven takes all of the rest as the version, whatever it contains
+            if strict {
+                check_bare_version(spec, pos)?;
+            }
+
+            recommended_version = Some(ComparableVersion::new(rest));
+            restrictions.push(Restriction::EVERYTHING);
+        }
+
+        Ok(VersionRange {
+            recommended_version,
+            restrictions,
+        })
+    }
+
+    /// Constructs a soft requirement on the given version, like Maven's
+    /// `VersionRange.createFromVersion`. Unlike [`Self::new`], the version is never interpreted as
+    /// a range, even if it contains brackets. Such a version is still displayed as is though, so it
+    /// doesn't survive a round trip through its string form:
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, VersionRange};
+    /// let range = VersionRange::from_version(ComparableVersion::new("[1.0]"));
+    /// assert!(range.is_soft());
+    /// assert!(!VersionRange::new(&range.to_string()).unwrap().is_soft());
+    /// ```
+    pub fn from_version(version: ComparableVersion) -> Self {
+        VersionRange {
+            recommended_version: Some(version),
+            restrictions: vec![Restriction::EVERYTHING],
+        }
+    }
+
+    /// Returns whether this range is a soft requirement, i.e. it was specified as a bare version
+    /// such as `1.0` rather than with brackets. Soft requirements match every version, but
+    /// [`Self::recommended_version`] should be preferred if nothing else constrains the choice.
+    pub fn is_soft(&self) -> bool {
+        self.recommended_version.is_some()
+    }
+
+    /// The version recommended by a soft requirement, or `None` if this range is made up of hard
+    /// restrictions. For example, this is `Some` for `1.0` but `None` for `[1.0]`.
+    pub fn recommended_version(&self) -> Option<&ComparableVersion> {
+        self.recommended_version.as_ref()
+    }
+
+    /// Returns whether this range has restrictions that must be matched against the available
+    /// versions, i.e. it's not a soft requirement and contains at least one restriction. This is
+    /// the same as Maven's `VersionRange.hasRestrictions`.
+    pub fn has_restrictions(&self) -> bool {
+        !self.restrictions.is_empty() && self.recommended_version.is_none()
+    }
+
+    /// The restrictions making up this range, in ascending order.
+    pub fn restrictions(&self) -> &[Restriction] {
+        &self.restrictions
+    }
+
+    /// Returns whether the given version falls within any of the restrictions of this range.
+    pub fn contains(&self, version: &ComparableVersion) -> bool {
+        self.restrictions.iter().any(|r| r.contains(version))
+    }
+
+    /// Returns the highest of the given versions contained in this range, or `None` if none of
+    /// them are, like Maven's `VersionRange.matchVersion`. Note that this ignores the recommended
+    /// version of a soft requirement, since it contains every version.
+    pub fn match_version<I>(&self, versions: I) -> Option<I::Item>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<ComparableVersion>,
+    {
+        versions
+            .into_iter()
+            .filter(|v| self.contains(v.borrow()))
+            .max_by(|a, b| a.borrow().cmp(b.borrow()))
+    }
+
+    /// Computes the tightest inclusive range containing all of the given versions, i.e.
+    /// `[lowest,highest]`. Returns `None` if there are no versions.
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, VersionRange};
+    /// let versions = ["1.2", "1.0-beta-1", "1.10"].iter().map(|v| ComparableVersion::new(v));
+    /// let range = VersionRange::covering(versions).unwrap();
+    /// assert_eq!(range.to_string(), "[1.0-beta-1,1.10]");
+    /// ```
+    pub fn covering<I>(versions: I) -> Option<Self>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<ComparableVersion>,
+    {
+        let mut versions = versions.into_iter();
+        let first = versions.next()?;
+        let (mut lowest, mut highest) = (first.borrow().clone(), first.borrow().clone());
+
+        for version in versions {
+            let version = version.borrow();
+            if version < &lowest {
+                lowest = version.clone();
+            } else if version > &highest {
+                highest = version.clone();
+            }
+        }
+
+        Some(Self::from_restrictions(vec![Restriction::new(
+            Some(lowest),
+            true,
+            Some(highest),
+            true,
+        )]))
+    }
+
+    /// Returns the range containing exactly the versions this range doesn't. Since a soft
+    /// requirement contains every version, its complement is a range with no restrictions, which
+    /// contains nothing.
+    ///
+    /// ```
+    /// # use mvn_version::VersionRange;
+    /// let range = VersionRange::new("[1.0,1.5),(1.5,2.0]").unwrap();
+    /// assert_eq!(range.complement().to_string(), "(,1.0),[1.5],(2.0,)");
+    /// ```
+    pub fn complement(&self) -> Self {
+        let mut restrictions = Vec::new();
+        // The start of the gap after the previous restriction, which is unbounded at first
+        let mut gap_start: Option<(ComparableVersion, bool)> = None;
+
+        for restriction in &self.restrictions {
+            if let Some(lower) = restriction.lower_bound() {
+                let (gap_lower, gap_lower_inclusive) = match gap_start.take() {
+                    Some((version, inclusive)) => (Some(version), inclusive),
+                    None => (None, false),
+                };
+
+                let gap = Restriction::new(
+                    gap_lower,
+                    gap_lower_inclusive,
+                    Some(lower.clone()),
+                    !restriction.is_lower_bound_inclusive(),
+                );
+
+                if !gap.is_empty() {
+                    restrictions.push(gap);
+                }
+            }
+
+            match restriction.upper_bound() {
+                Some(upper) => {
+                    gap_start = Some((upper.clone(), !restriction.is_upper_bound_inclusive()))
+                }
+                // Nothing left uncovered after a restriction that is unbounded from above
+                None => return Self::from_restrictions(restrictions),
+            }
+        }
+
+        let (gap_lower, gap_lower_inclusive) = match gap_start {
+            Some((version, inclusive)) => (Some(version), inclusive),
+            None => (None, false),
+        };
+        restrictions.push(Restriction::new(
+            gap_lower,
+            gap_lower_inclusive,
+            None,
+            false,
+        ));
+
+        Self::from_restrictions(restrictions)
+    }
+
+    /// Returns the given version if it's in this range, or otherwise the closest bound of the
+    /// range to it. When the version falls into a gap between two restrictions, the bound above
+    /// it is preferred, since suggesting an upgrade is usually the safer fix. If the closest bound
+    /// is exclusive, the returned version itself lies just outside the range. Returns `None` only
+    /// for a range without any restrictions.
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, VersionRange};
+    /// let range = VersionRange::new("[1.0,2.0],[3.0,4.0]").unwrap();
+    /// let clamp = |v| range.clamp(&ComparableVersion::new(v)).unwrap().to_string();
+    /// assert_eq!(clamp("0.5"), "1.0");
+    /// assert_eq!(clamp("1.5"), "1.5");
+    /// assert_eq!(clamp("2.5"), "3.0");
+    /// assert_eq!(clamp("5.0"), "4.0");
+    /// ```
+    pub fn clamp<'a>(&'a self, version: &'a ComparableVersion) -> Option<&'a ComparableVersion> {
+        if self.contains(version) {
+            return Some(version);
+        }
+
+        // Restrictions are in ascending order, so the first one with a lower bound above the
+        // version is the closest one above it
+        for restriction in &self.restrictions {
+            if let Some(lower) = restriction.lower_bound() {
+                if lower >= version {
+                    return Some(lower);
+                }
+            }
+        }
+
+        self.restrictions.last().and_then(|r| r.upper_bound())
+    }
+
+    /// Extends this range minimally so that it contains the given version, leaving every other
+    /// restriction untouched. A version below or above all restrictions moves the outermost bound
+    /// on that side, and a version falling into a gap between two restrictions raises the upper
+    /// bound of the one below it. A range without any restrictions becomes `[version]`.
+    ///
+    /// ```
+    /// # use mvn_version::{ComparableVersion, VersionRange};
+    /// let mut range = VersionRange::new("[1.0,2.0),[3.0,4.0]").unwrap();
+    /// range.widen(&ComparableVersion::new("2.5"));
+    /// range.widen(&ComparableVersion::new("0.9"));
+    /// assert_eq!(range.to_string(), "[0.9,2.5],[3.0,4.0]");
+    /// ```
+    pub fn widen(&mut self, version: &ComparableVersion) {
+        if self.contains(version) {
+            return;
+        }
+
+        // The last restriction lying below the version, if any
+        let below = self
+            .restrictions
+            .iter()
+            .rposition(|r| match r.upper_bound() {
+                Some(upper) => upper <= version,
+                None => false,
+            });
+
+        let (index, restriction) = match below {
+            Some(i) => {
+                let r = &self.restrictions[i];
+                let widened = Restriction::new(
+                    r.lower_bound().cloned(),
+                    r.is_lower_bound_inclusive(),
+                    Some(version.clone()),
+                    true,
+                );
+                (i, widened)
+            }
+            None => match self.restrictions.first() {
+                Some(r) => {
+                    let widened = Restriction::new(
+                        Some(version.clone()),
+                        true,
+                        r.upper_bound().cloned(),
+                        r.is_upper_bound_inclusive(),
+                    );
+                    (0, widened)
+                }
+                None => {
+                    let exact =
+                        Restriction::new(Some(version.clone()), true, Some(version.clone()), true);
+                    self.restrictions.push(exact);
+                    return;
+                }
+            },
+        };
+
+        self.restrictions[index] = restriction;
+    }
+
+    /// Constructs a range from the given restrictions. Unlike [`Self::new`] and
+    /// [`Self::try_from_restrictions`], this performs no validation, so the restrictions may be
+    /// unordered, overlapping or empty, in which case the [Display] output of the range won't
+    /// parse again. Use [`Self::normalize`] to bring them into canonical form.
+    pub fn from_restrictions(restrictions: Vec<Restriction>) -> Self {
+        VersionRange {
+            recommended_version: None,
+            restrictions,
+        }
+    }
+
+    /// Constructs a range from the given restrictions, checking them like [`Self::new_strict`]
+    /// checks a specification: they must be in ascending order, and none may be empty or overlap
+    /// the one before it. The offset of an error is where the offending restriction starts in the
+    /// [Display] output the range would have had.
+    ///
+    /// ```
+    /// # use mvn_version::{RangeParseErrorKind, Restriction, VersionRange};
+    /// let restrictions = |specs: &[&str]| -> Vec<Restriction> {
+    ///     specs.iter().map(|s| s.parse().unwrap()).collect()
+    /// };
+    ///
+    /// let range = VersionRange::try_from_restrictions(restrictions(&["[1.0,2.0)", "[3.0,)"]));
+    /// assert_eq!(range.unwrap().to_string(), "[1.0,2.0),[3.0,)");
+    ///
+    /// let err = VersionRange::try_from_restrictions(restrictions(&["[3.0,)", "[1.0,2.0)"]));
+    /// assert_eq!(err.unwrap_err().kind(), RangeParseErrorKind::RangesOverlap);
+    /// ```
+    pub fn try_from_restrictions(restrictions: Vec<Restriction>) -> Result<Self, RangeParseError> {
+        let mut offset = 0;
+
+        for (i, restriction) in restrictions.iter().enumerate() {
+            if restriction.is_empty() {
+                return Err(RangeParseError::new(
+                    RangeParseErrorKind::BoundsOutOfOrder,
+                    offset,
+                ));
+            }
+
+            if i > 0 && overlaps(&restrictions[i - 1], restriction, true) {
+                return Err(RangeParseError::new(
+                    RangeParseErrorKind::RangesOverlap,
+                    offset,
+                ));
+            }
+
+            // Restrictions are separated by a comma
+            offset += restriction.to_string().len() + 1;
+        }
+
+        Ok(Self::from_restrictions(restrictions))
+    }
+
+    /// Brings the restrictions of this range into canonical form: empty restrictions are dropped,
+    /// the rest are sorted, and overlapping or adjacent restrictions are merged. Two ranges
+    /// containing the same versions compare equal after normalization, unless exactly one of them
+    /// is a soft requirement.
+    ///
+    /// ```
+    /// # use mvn_version::VersionRange;
+    /// let mut range = VersionRange::new("[1.0,1.5),[1.5,2.0]").unwrap();
+    /// range.normalize();
+    /// assert_eq!(range.to_string(), "[1.0,2.0]");
+    /// ```
+    pub fn normalize(&mut self) {
+        let mut restrictions = std::mem::take(&mut self.restrictions);
+        restrictions.retain(|r| !r.is_empty());
+        restrictions.sort_by(cmp_lower_bounds);
+
+        for restriction in restrictions {
+            let previous = match self.restrictions.last_mut() {
+                Some(previous) => previous,
+                None => {
+                    self.restrictions.push(restriction);
+                    continue;
+                }
+            };
+
+            let touches = match (previous.upper_bound(), restriction.lower_bound()) {
+                (Some(upper), Some(lower)) => {
+                    lower < upper
+                        || (lower == upper
+                            && (previous.is_upper_bound_inclusive()
+                                || restriction.is_lower_bound_inclusive()))
+                }
+                _ => true,
+            };
+
+            if !touches {
+                self.restrictions.push(restriction);
+                continue;
+            }
+
+            let (upper, upper_inclusive) =
+                if cmp_upper_bounds(previous, &restriction) == Ordering::Less {
+                    (
+                        restriction.upper_bound(),
+                        restriction.is_upper_bound_inclusive(),
+                    )
+                } else {
+                    (previous.upper_bound(), previous.is_upper_bound_inclusive())
+                };
+
+            *previous = Restriction::new(
+                previous.lower_bound().cloned(),
+                previous.is_lower_bound_inclusive(),
+                upper.cloned(),
+                upper_inclusive,
+            );
+        }
+    }
+}
+
+/// Whether a restriction starts before the one before it in a range ends.
+fn overlaps(previous: &Restriction, next: &Restriction, strict: bool) -> bool {
+    match (previous.upper_bound(), next.lower_bound()) {
+        (Some(upper), Some(lower)) => lower < upper,
+        (Some(_), None) => true,
+        // Maven doesn't check what follows a restriction which is unbounded from above, even
+        // though it can never be disjoint from it
+        (None, _) => strict,
+    }
+}
+
+/// Orders restrictions by where they start, with an unbounded start coming first.
+fn cmp_lower_bounds(a: &Restriction, b: &Restriction) -> Ordering {
+    match (a.lower_bound(), b.lower_bound()) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(x), Some(y)) => x.cmp(y).then_with(|| {
+            // An inclusive bound starts earlier than an exclusive one
+            b.is_lower_bound_inclusive()
+                .cmp(&a.is_lower_bound_inclusive())
+        }),
+    }
+}
+
+/// Orders restrictions by where they end, with an unbounded end coming last.
+fn cmp_upper_bounds(a: &Restriction, b: &Restriction) -> Ordering {
+    match (a.upper_bound(), b.upper_bound()) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(x), Some(y)) => x.cmp(y).then_with(|| {
+            // An inclusive bound ends later than an exclusive one
+            a.is_upper_bound_inclusive()
+                .cmp(&b.is_upper_bound_inclusive())
+        }),
+    }
+}
+
+/// Returns the index of the first non-whitespace character in `s` at or after `from`.
+fn skip_whitespace(s: &str, from: usize) -> usize {
+    s.len() - s[from..].trim_start().len()
+}
+
+/// Checks that the bare version `spec[pos..]` is a single version without any brackets.
+fn check_bare_version(spec: &str, pos: usize) -> Result<(), RangeParseError> {
+    let rest = &spec[pos..];
+    let comma = rest.find(',').unwrap_or(rest.len());
+
+    if let Some(i) = rest[..comma].find(['[', ']', '(', ')']) {
+        return Err(RangeParseError::new(
+            RangeParseErrorKind::UnbalancedBracket,
+            pos + i,
+        ));
+    }
+
+    if comma == rest.len() {
+        return Ok(());
+    }
+
+    let next = skip_whitespace(spec, pos + comma + 1);
+    let kind = if spec[next..].starts_with(['[', '(']) {
+        RangeParseErrorKind::RecommendedVersionWithRanges
+    } else {
+        RangeParseErrorKind::DuplicateRecommendedVersion
+    };
+
+    Err(RangeParseError::new(kind, next))
+}
+
+/// Parses the single bracketed restriction `spec[start..=end]`, e.g. "[1.0,2.0)" or "[1.0]".
+fn parse_restriction(
+    spec: &str,
+    start: usize,
+    end: usize,
+    strict: bool,
+) -> Result<Restriction, RangeParseError> {
+    let lower_bound_inclusive = spec[start..].starts_with('[');
+    let upper_bound_inclusive = spec[end..].starts_with(']');
+
+    let process = &spec[start + 1..end];
+
+    match process.find(',') {
+        None => {
+            if !lower_bound_inclusive || !upper_bound_inclusive {
+                return Err(RangeParseError::new(
+                    RangeParseErrorKind::SingleVersionNotInclusive,
+                    start,
+                ));
+            }
+
+            let version = process.trim();
+
+            if strict && version.is_empty() {
+                return Err(RangeParseError::new(
+                    RangeParseErrorKind::EmptyVersion,
+                    start,
+                ));
+            }
+
+            let version = ComparableVersion::new(version);
+            Ok(Restriction::new(
+                Some(version.clone()),
+                true,
+                Some(version),
+                true,
+            ))
+        }
+        Some(index) => {
+            let lower = process[..index].trim();
+            let upper = &process[index + 1..];
+
+            if let Some(i) = upper.find(',').filter(|_| strict) {
+                return Err(RangeParseError::new(
+                    RangeParseErrorKind::TooManyBounds,
+                    start + index + 2 + i,
+                ));
+            }
+
+            let lower = Some(lower)
+                .filter(|s| !s.is_empty())
+                .map(ComparableVersion::new);
+            let upper = Some(upper.trim())
+                .filter(|s| !s.is_empty())
+                .map(ComparableVersion::new);
+
+            if let (Some(l), Some(u)) = (&lower, &upper) {
+                if u < l || (u == l && (!lower_bound_inclusive || !upper_bound_inclusive)) {
+                    return Err(RangeParseError::new(
+                        RangeParseErrorKind::BoundsOutOfOrder,
+                        start,
+                    ));
+                }
+            }
+
+            Ok(Restriction::new(
+                lower,
+                lower_bound_inclusive,
+                upper,
+                upper_bound_inclusive,
+            ))
+        }
+    }
+}
+
+impl Display for VersionRange {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if let Some(recommended) = &self.recommended_version {
+            return f.write_str(recommended.as_str());
+        }
+
+        for (i, restriction) in self.restrictions.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            restriction.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for VersionRange {
+    type Err = RangeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
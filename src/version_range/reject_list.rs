@@ -0,0 +1,72 @@
+use crate::{ComparableVersion, VersionRange};
+use std::borrow::Borrow;
+use std::iter::FromIterator;
+
+/// A deny-list of version ranges. Candidate versions are checked against each rule in order, and
+/// are rejected by the first one containing them.
+///
+/// Soft requirements behave differently here than they do in a [VersionRange]: since a soft
+/// requirement contains every version, a rule such as `2.14.1` instead rejects exactly its
+/// recommended version.
+///
+/// ```
+/// # use mvn_version::{ComparableVersion, RejectList, VersionRange};
+/// let rules = RejectList::new(vec![
+///     VersionRange::new("[2.0,2.17.0)").unwrap(),
+///     VersionRange::new("2.17.2").unwrap(),
+/// ]);
+///
+/// let (index, _) = rules.matching_rule(&ComparableVersion::new("2.14.1")).unwrap();
+/// assert_eq!(index, 0);
+/// assert!(!rules.is_rejected(&ComparableVersion::new("2.17.1")));
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Default)]
+pub struct RejectList {
+    rules: Vec<VersionRange>,
+}
+
+impl RejectList {
+    /// Constructs a RejectList from the given rules.
+    pub fn new(rules: Vec<VersionRange>) -> Self {
+        RejectList { rules }
+    }
+
+    /// The rules in this list, in the order they are checked.
+    pub fn rules(&self) -> &[VersionRange] {
+        &self.rules
+    }
+
+    /// Returns the first rule rejecting the given version along with its index, or `None` if the
+    /// version is acceptable.
+    pub fn matching_rule(&self, version: &ComparableVersion) -> Option<(usize, &VersionRange)> {
+        self.rules
+            .iter()
+            .enumerate()
+            .find(|(_, rule)| match rule.recommended_version() {
+                Some(recommended) => recommended == version,
+                None => rule.contains(version),
+            })
+    }
+
+    /// Returns whether any rule rejects the given version.
+    pub fn is_rejected(&self, version: &ComparableVersion) -> bool {
+        self.matching_rule(version).is_some()
+    }
+
+    /// Returns an iterator over the given versions which no rule rejects.
+    pub fn filter<'a, I>(&'a self, versions: I) -> impl Iterator<Item = I::Item> + 'a
+    where
+        I: IntoIterator + 'a,
+        I::Item: Borrow<ComparableVersion>,
+    {
+        versions
+            .into_iter()
+            .filter(move |v| !self.is_rejected(v.borrow()))
+    }
+}
+
+impl FromIterator<VersionRange> for RejectList {
+    fn from_iter<T: IntoIterator<Item = VersionRange>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
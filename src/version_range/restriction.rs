@@ -0,0 +1,153 @@
+use crate::{ComparableVersion, RangeParseError, RangeParseErrorKind, VersionRange};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A single interval of versions, such as `[1.0,2.0)`. Either bound may be missing, in which case
+/// the interval is unbounded on that side.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Restriction {
+    lower_bound: Option<ComparableVersion>,
+    lower_bound_inclusive: bool,
+    upper_bound: Option<ComparableVersion>,
+    upper_bound_inclusive: bool,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Restriction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Restriction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let spec = <String as serde::Deserialize>::deserialize(deserializer)?;
+        spec.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl Restriction {
+    /// The restriction which contains every version, `(,)`.
+    pub const EVERYTHING: Restriction = Restriction {
+        lower_bound: None,
+        lower_bound_inclusive: false,
+        upper_bound: None,
+        upper_bound_inclusive: false,
+    };
+
+    /// Constructs a restriction from its bounds. No checks are performed on the ordering of the
+    /// bounds, so it's possible to construct a restriction that contains nothing.
+    pub fn new(
+        lower_bound: Option<ComparableVersion>,
+        lower_bound_inclusive: bool,
+        upper_bound: Option<ComparableVersion>,
+        upper_bound_inclusive: bool,
+    ) -> Self {
+        Restriction {
+            lower_bound,
+            lower_bound_inclusive,
+            upper_bound,
+            upper_bound_inclusive,
+        }
+    }
+
+    /// The lower bound, or `None` if the restriction is unbounded from below.
+    pub fn lower_bound(&self) -> Option<&ComparableVersion> {
+        self.lower_bound.as_ref()
+    }
+    /// Whether the lower bound itself is part of the restriction.
+    pub fn is_lower_bound_inclusive(&self) -> bool {
+        self.lower_bound_inclusive
+    }
+    /// The upper bound, or `None` if the restriction is unbounded from above.
+    pub fn upper_bound(&self) -> Option<&ComparableVersion> {
+        self.upper_bound.as_ref()
+    }
+    /// Whether the upper bound itself is part of the restriction.
+    pub fn is_upper_bound_inclusive(&self) -> bool {
+        self.upper_bound_inclusive
+    }
+
+    /// Returns whether this restriction contains no versions at all, e.g. `[2.0,1.0]` or
+    /// `(1.0,1.0]`.
+    pub fn is_empty(&self) -> bool {
+        match (&self.lower_bound, &self.upper_bound) {
+            (Some(lower), Some(upper)) => {
+                lower > upper
+                    || (lower == upper
+                        && !(self.lower_bound_inclusive && self.upper_bound_inclusive))
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns whether the given version falls within this restriction.
+    pub fn contains(&self, version: &ComparableVersion) -> bool {
+        if let Some(lower) = &self.lower_bound {
+            if version < lower || (version == lower && !self.lower_bound_inclusive) {
+                return false;
+            }
+        }
+
+        if let Some(upper) = &self.upper_bound {
+            if version > upper || (version == upper && !self.upper_bound_inclusive) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl Display for Restriction {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(if self.lower_bound_inclusive { "[" } else { "(" })?;
+
+        match (&self.lower_bound, &self.upper_bound) {
+            // A restriction on exactly one version is written the short way, e.g. "[1.0]"
+            (Some(lower), Some(upper))
+                if self.lower_bound_inclusive
+                    && self.upper_bound_inclusive
+                    && lower.as_str() == upper.as_str() =>
+            {
+                f.write_str(lower.as_str())?;
+            }
+            (lower, upper) => {
+                if let Some(lower) = lower {
+                    f.write_str(lower.as_str())?;
+                }
+                f.write_str(",")?;
+                if let Some(upper) = upper {
+                    f.write_str(upper.as_str())?;
+                }
+            }
+        }
+
+        f.write_str(if self.upper_bound_inclusive { "]" } else { ")" })
+    }
+}
+
+impl FromStr for Restriction {
+    type Err = RangeParseError;
+
+    /// Parses a single bracketed restriction, such as `[1.0,2.0)`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let range = VersionRange::new(s)?;
+
+        match range.restrictions() {
+            [restriction] if !range.is_soft() => Ok(restriction.clone()),
+            _ => Err(RangeParseError::new(
+                RangeParseErrorKind::ExpectedSingleRestriction,
+                0,
+            )),
+        }
+    }
+}
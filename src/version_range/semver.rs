@@ -0,0 +1,99 @@
+use super::{RangeParseError, RangeParseErrorKind, Restriction, VersionRange};
+use crate::ComparableVersion;
+use std::cmp::Ordering;
+
+/// Which component of the version a requirement allows to change.
+#[derive(Clone, Copy)]
+enum Operator {
+    Caret,
+    Tilde,
+}
+
+impl VersionRange {
+    /// Translates a Cargo/npm-style caret or tilde requirement into the equivalent range.
+    ///
+    /// - `^1.2.3` allows changes that don't modify the leftmost non-zero component, yielding
+    ///   `[1.2.3,2.0.0)`. Likewise, `^0.2.3` yields `[0.2.3,0.3.0)` and `^0.0.3` yields
+    ///   `[0.0.3,0.0.4)`.
+    /// - `~1.2.3` allows patch-level changes, yielding `[1.2.3,1.3.0)`. `~1` yields `[1,2)`.
+    ///
+    /// The upper bound has as many components as the requirement itself. The requirement may end
+    /// in a qualifier such as `^1.2.3-beta-1` or `^1.2.3.RELEASE`, which only affects the lower
+    /// bound.
+    ///
+    /// Note that the resulting range is evaluated under Maven ordering, so unlike in semver,
+    /// `[1.2.3,2.0.0)` contains prereleases of the upper bound such as `2.0.0-rc1`.
+    ///
+    /// ```
+    /// # use mvn_version::VersionRange;
+    /// assert_eq!(VersionRange::from_semver("^1.2.3").unwrap().to_string(), "[1.2.3,2.0.0)");
+    /// assert_eq!(VersionRange::from_semver("~1.2.3").unwrap().to_string(), "[1.2.3,1.3.0)");
+    /// ```
+    pub fn from_semver(requirement: &str) -> Result<Self, RangeParseError> {
+        let (operator, version) = if let Some(version) = requirement.strip_prefix('^') {
+            (Operator::Caret, version)
+        } else if let Some(version) = requirement.strip_prefix('~') {
+            (Operator::Tilde, version)
+        } else {
+            return Err(RangeParseError::new(
+                RangeParseErrorKind::InvalidRequirement,
+                0,
+            ));
+        };
+
+        // The numeric core ends at the qualifier, if there is one, leaving out the dot before a
+        // dotted qualifier as in `1.2.3.RELEASE`
+        let core_len = match version.find(|c: char| c != '.' && !c.is_ascii_digit()) {
+            Some(i) if version[..i].ends_with('.') => i - 1,
+            Some(i) => i,
+            None => version.len(),
+        };
+
+        // Each component along with its offset in the requirement
+        let mut components = Vec::new();
+        let mut start = 1;
+        for number in version[..core_len].split('.') {
+            match number.parse::<u64>() {
+                Ok(n) if !number.is_empty() => components.push((n, start)),
+                _ => {
+                    return Err(RangeParseError::new(
+                        RangeParseErrorKind::InvalidRequirement,
+                        start,
+                    ))
+                }
+            }
+            start += number.len() + 1;
+        }
+
+        let bump = match operator {
+            Operator::Caret => components
+                .iter()
+                .position(|&(n, _)| n != 0)
+                .unwrap_or(components.len() - 1),
+            Operator::Tilde => components.len().min(2) - 1,
+        };
+
+        let (n, offset) = components[bump];
+        let bumped = n
+            .checked_add(1)
+            .ok_or_else(|| RangeParseError::new(RangeParseErrorKind::InvalidRequirement, offset))?;
+
+        let upper = components
+            .iter()
+            .enumerate()
+            .map(|(i, &(n, _))| match i.cmp(&bump) {
+                Ordering::Less => n.to_string(),
+                Ordering::Equal => bumped.to_string(),
+                Ordering::Greater => "0".to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(".");
+
+        Ok(Self::from_restrictions(vec![Restriction::new(
+            Some(ComparableVersion::new(version)),
+            true,
+            Some(ComparableVersion::new(&upper)),
+            false,
+        )]))
+    }
+}
@@ -0,0 +1,197 @@
+//! An alternative structured serialization of [VersionRange], for use with
+//! `#[serde(with = "mvn_version::structured_range")]`.
+//!
+//! By default, ranges and restrictions serialize as their specification string, e.g.
+//! `"[1.0,2.0),[3.0,)"`. The structured form instead spells out every bound:
+//!
+//! ```json
+//! {
+//!   "recommended_version": null,
+//!   "restrictions": [
+//!     { "lower_bound": "1.0", "lower_bound_inclusive": true, "upper_bound": "2.0", "upper_bound_inclusive": false },
+//!     { "lower_bound": "3.0", "lower_bound_inclusive": true, "upper_bound": null, "upper_bound_inclusive": false }
+//!   ]
+//! }
+//! ```
+//!
+//! Deserialization with this module accepts both forms, and restrictions inside the structured
+//! form may themselves be given either as strings or as maps. Structured input is validated by the
+//! same rules as specification strings, and a recommended version may only come with the single
+//! unbounded restriction of a soft requirement. The default impls only accept strings, so that
+//! they also work with formats which aren't self-describing, such as bincode.
+
+use crate::{ComparableVersion, Restriction, VersionRange};
+use serde::de::{Error, MapAccess, Visitor};
+use serde::ser::{SerializeSeq, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Serializes a VersionRange in the structured form.
+pub fn serialize<S>(range: &VersionRange, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut s = serializer.serialize_struct("VersionRange", 2)?;
+    s.serialize_field(
+        "recommended_version",
+        &range.recommended_version().map(|v| v.as_str()),
+    )?;
+    s.serialize_field(
+        "restrictions",
+        &StructuredRestrictions(range.restrictions()),
+    )?;
+    s.end()
+}
+
+/// Deserializes a VersionRange from either the structured form or a specification string.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<VersionRange, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(VersionRangeVisitor)
+}
+
+/// A restriction given either as a string or as a map.
+struct AnyRestriction(Restriction);
+
+impl<'de> Deserialize<'de> for AnyRestriction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_any(RestrictionVisitor)
+            .map(AnyRestriction)
+    }
+}
+
+struct StructuredRestrictions<'a>(&'a [Restriction]);
+
+impl Serialize for StructuredRestrictions<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for restriction in self.0 {
+            seq.serialize_element(&StructuredRestriction(restriction))?;
+        }
+        seq.end()
+    }
+}
+
+struct StructuredRestriction<'a>(&'a Restriction);
+
+impl Serialize for StructuredRestriction<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let r = self.0;
+        let mut s = serializer.serialize_struct("Restriction", 4)?;
+        s.serialize_field("lower_bound", &r.lower_bound().map(|v| v.as_str()))?;
+        s.serialize_field("lower_bound_inclusive", &r.is_lower_bound_inclusive())?;
+        s.serialize_field("upper_bound", &r.upper_bound().map(|v| v.as_str()))?;
+        s.serialize_field("upper_bound_inclusive", &r.is_upper_bound_inclusive())?;
+        s.end()
+    }
+}
+
+struct VersionRangeVisitor;
+
+impl<'de> Visitor<'de> for VersionRangeVisitor {
+    type Value = VersionRange;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("a version range specification or map")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        VersionRange::new(v).map_err(E::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut recommended_version: Option<String> = None;
+        let mut restrictions: Option<Vec<AnyRestriction>> = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "recommended_version" => recommended_version = map.next_value()?,
+                "restrictions" => restrictions = Some(map.next_value()?),
+                _ => return Err(A::Error::unknown_field(&key, RANGE_FIELDS)),
+            }
+        }
+
+        let restrictions = restrictions.unwrap_or_default();
+
+        // Going through the canonical form subjects the structured input to the same validation
+        // as a specification string
+        let spec = match recommended_version {
+            Some(recommended) => match restrictions.as_slice() {
+                [] => recommended,
+                [AnyRestriction(r)] if *r == Restriction::EVERYTHING => recommended,
+                _ => {
+                    return Err(A::Error::custom(
+                        "a recommended version can't be combined with restrictions",
+                    ))
+                }
+            },
+            None => restrictions
+                .iter()
+                .map(|r| r.0.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        };
+
+        VersionRange::new(&spec).map_err(A::Error::custom)
+    }
+}
+
+struct RestrictionVisitor;
+
+impl<'de> Visitor<'de> for RestrictionVisitor {
+    type Value = Restriction;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("a version restriction or map")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut lower_bound: Option<String> = None;
+        let mut lower_bound_inclusive = false;
+        let mut upper_bound: Option<String> = None;
+        let mut upper_bound_inclusive = false;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "lower_bound" => lower_bound = map.next_value()?,
+                "lower_bound_inclusive" => lower_bound_inclusive = map.next_value()?,
+                "upper_bound" => upper_bound = map.next_value()?,
+                "upper_bound_inclusive" => upper_bound_inclusive = map.next_value()?,
+                _ => return Err(A::Error::unknown_field(&key, RESTRICTION_FIELDS)),
+            }
+        }
+
+        let restriction = Restriction::new(
+            lower_bound.as_deref().map(ComparableVersion::new),
+            lower_bound_inclusive,
+            upper_bound.as_deref().map(ComparableVersion::new),
+            upper_bound_inclusive,
+        );
+
+        restriction.to_string().parse().map_err(A::Error::custom)
+    }
+}
+
+const RANGE_FIELDS: &[&str] = &["recommended_version", "restrictions"];
+const RESTRICTION_FIELDS: &[&str] = &[
+    "lower_bound",
+    "lower_bound_inclusive",
+    "upper_bound",
+    "upper_bound_inclusive",
+];
@@ -0,0 +1,166 @@
+//! Counts heap allocations made while parsing and comparing. Allocations are counted per thread,
+//! so tests running at the same time don't see each other's.
+
+use mvn_version::{cmp_str, eq_str, hash_str, ComparableVersion, ComparableVersionRef};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn test_qualifier_allocations() {
+    // Upper case qualifiers don't need a lowercase copy, whether Maven knows them or not
+    let parse = |s| {
+        std::hint::black_box(ComparableVersionRef::new(s));
+    };
+
+    for (upper, lower) in [
+        ("1.0-SNAPSHOT", "1.0-snapshot"),
+        ("1.0-RC1", "1.0-rc1"),
+        ("1.0-CR1", "1.0-cr1"),
+        ("1.0.Final", "1.0.final"),
+        ("2.0-M3", "2.0-m3"),
+        ("1-SP-2", "1-sp-2"),
+        ("1.0-FOO", "1.0-foo"),
+        ("1.0-GAMMA", "1.0-gamma"),
+        ("Hoxton.SR3", "hoxton.sr3"),
+    ] {
+        assert_eq!(
+            allocations(|| parse(upper)),
+            allocations(|| parse(lower)),
+            "{}",
+            upper
+        );
+    }
+}
+
+#[test]
+fn test_owned_known_qualifiers_allocations() {
+    // Owned versions keep the qualifiers Maven knows without copying them, unlike other ones
+    let parse = |s| {
+        std::hint::black_box(ComparableVersion::new(s));
+    };
+
+    for (known, other) in [
+        ("1.0-SNAPSHOT", "1.0-foo"),
+        ("1.0-rc1", "1.0-foo1"),
+        ("2.0.0-BETA-1", "2.0.0-foo-1"),
+        ("1-sp", "1-foo"),
+    ] {
+        assert!(
+            allocations(|| parse(known)) < allocations(|| parse(other)),
+            "{}",
+            known
+        );
+    }
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn test_typical_versions_are_allocation_free() {
+    for s in [
+        "1",
+        "1.2.3",
+        "1.2.3-SNAPSHOT",
+        "2.0.0-beta-1",
+        "1.0-RC1",
+        "5.3.31.RELEASE",
+        "1.0-alpha-1-SNAPSHOT",
+        "Hoxton.SR3",
+    ] {
+        let allocations = allocations(|| {
+            std::hint::black_box(ComparableVersionRef::new(s));
+        });
+        assert_eq!(allocations, 0, "{}", s);
+    }
+}
+
+#[test]
+fn test_canonical_is_lazy() {
+    // The canonical form is only built once it's asked for
+    let parse = || ComparableVersion::new("1.0-alpha-1");
+
+    let parsed = allocations(|| {
+        std::hint::black_box(parse());
+    });
+    let canonical = allocations(|| {
+        std::hint::black_box(parse().canonical().len());
+    });
+    assert!(parsed < canonical);
+}
+
+/// Typical versions along with the awkward ones from the fuzz tests: long runs of digits, many
+/// segments and qualifiers which aren't ASCII.
+fn streamed_versions() -> Vec<String> {
+    let mut versions = [
+        "",
+        "1",
+        "1.2.3",
+        "1.2.3-SNAPSHOT",
+        "1.0-RC1",
+        "5.3.31.RELEASE",
+        "Hoxton.SR3",
+        "1.0-alpha-1-SNAPSHOT",
+        "1.0-İẞΣς\u{307}",
+        "1-\u{212a}-🦀",
+        "२.१-é",
+        "1.0.0.0.0-0.0",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect::<Vec<_>>();
+
+    versions.push("9".repeat(200));
+    versions.push(format!("1.{}-rc", "0".repeat(200)));
+    versions.push("1.".repeat(500) + "-a");
+    versions.push("a-1-".repeat(500));
+    versions
+}
+
+#[test]
+fn test_streaming_is_allocation_free() {
+    let versions = streamed_versions();
+
+    for a in &versions {
+        for b in &versions {
+            let allocations = allocations(|| {
+                std::hint::black_box(cmp_str(a, b));
+                std::hint::black_box(eq_str(a, b));
+            });
+            assert_eq!(allocations, 0, "{:?} {:?}", a, b);
+        }
+
+        let allocations = allocations(|| {
+            let mut hasher = DefaultHasher::new();
+            hash_str(a, &mut hasher);
+            std::hint::black_box(hasher.finish());
+        });
+        assert_eq!(allocations, 0, "{:?}", a);
+    }
+}
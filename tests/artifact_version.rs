@@ -1,6 +1,11 @@
-//! Directly lifted from Maven's test cases.
+//! Directly lifted from Maven's test cases. Numeric components are read as a u64 rather than
+//! Maven's int, so the cases which overflow an int deliberately diverge from Maven's test; its
+//! expectations are kept next to them.
 
-use mvn_version::ArtifactVersion;
+use mvn_version::{
+    ArtifactVersion, ArtifactVersionBuilder, ArtifactVersionParseErrorKind, ComparableVersion,
+    LeadingZeroPolicy, QualifierKind, VersionComponent,
+};
 use std::cmp::Ordering;
 
 fn assert_versions_equal(s: &str, t: &str) {
@@ -23,10 +28,10 @@ fn assert_versions_ordered(s: &str, t: &str) {
 
 fn check_parsing(
     s: &str,
-    major: u32,
-    minor: u32,
-    incremental: u32,
-    build: u32,
+    major: u64,
+    minor: u64,
+    incremental: u64,
+    build: u64,
     qualifier: Option<&str>,
 ) {
     let version = ArtifactVersion::new(s);
@@ -88,7 +93,24 @@ fn test_parsing() {
     check_parsing(".1.0-SNAPSHOT", 0, 0, 0, 0, Some(".1.0-SNAPSHOT"));
 
     check_parsing("1.2.3.200705301630", 0, 0, 0, 0, Some("1.2.3.200705301630"));
-    check_parsing("1.2.3-200705301630", 1, 2, 3, 0, Some("200705301630"));
+    // Maven expects the build number to overflow into the qualifier, see test_parsing_u64:
+    // check_parsing("1.2.3-200705301630", 1, 2, 3, 0, Some("200705301630"));
+}
+
+#[test]
+fn test_parsing_u64() {
+    check_parsing("1.2.3-200705301630", 1, 2, 3, 200705301630, None);
+    check_parsing("3000000000.0.0", 3000000000, 0, 0, 0, None);
+    check_parsing("20240101.1", 20240101, 1, 0, 0, None);
+    check_parsing("1.2.3-4294967296", 1, 2, 3, 4294967296, None);
+    check_parsing(
+        "1.2.30000000000000000000",
+        0,
+        0,
+        0,
+        0,
+        Some("1.2.30000000000000000000"),
+    );
 }
 
 #[test]
@@ -176,3 +198,389 @@ fn test_snapshot_release() {
     assert_versions_ordered("1.0-rc1", "1.0-SNAPSHOT");
     assert_versions_ordered("1.0-rc-1", "1.0-SNAPSHOT");
 }
+
+#[test]
+fn test_non_digit_components() {
+    // Rust's integer parsing accepts a sign, but Maven only takes digits as a number
+    check_parsing("1.+2.3", 0, 0, 0, 0, Some("1.+2.3"));
+    check_parsing("+1", 0, 0, 0, 0, Some("+1"));
+}
+
+#[test]
+fn test_new_owned() {
+    for s in &["1.2.3-4", "1.2-beta-1", "1.0.0.0", "foo", ""] {
+        let borrowed = ArtifactVersion::new(s);
+        let owned = ArtifactVersion::new_owned(s.to_string());
+        assert_eq!(owned, borrowed);
+        assert_eq!(owned.as_str(), *s);
+        assert_eq!(owned.qualifier(), borrowed.qualifier());
+        assert_eq!(owned.build(), borrowed.build());
+        assert_eq!(ArtifactVersion::from(s.to_string()).as_str(), *s);
+    }
+}
+
+#[test]
+fn test_compare_with_str() {
+    let version = ArtifactVersion::new("1.2.3-4");
+
+    assert!(version == "1.2.3-4");
+    assert!(version == *"1.2.3.0-4");
+    assert!(version < "1.2.3-5");
+    assert!(version > "1.2.3");
+    assert!(version >= *"1.2.3-4");
+}
+
+#[test]
+fn test_comparable() {
+    let version = ArtifactVersion::new("1.0.0-RC1");
+
+    assert_eq!(version.comparable().as_str(), "1.0.0-RC1");
+    assert_eq!(version.comparable().canonical(), "1-rc-1");
+    assert_eq!(version.comparable(), &ComparableVersion::new("1.0.0-RC1"));
+
+    let comparable = version.clone().into_comparable();
+    assert_eq!(&comparable, version.comparable());
+    assert_eq!(ComparableVersion::from(version), comparable);
+}
+
+fn check_error(
+    s: &str,
+    kind: ArtifactVersionParseErrorKind,
+    component: VersionComponent,
+    offset: usize,
+) {
+    let error = ArtifactVersion::try_new(s).unwrap_err();
+    assert_eq!(error.kind(), kind, "{:?}", s);
+    assert_eq!(error.component(), component, "{:?}", s);
+    assert_eq!(error.offset(), offset, "{:?}", s);
+
+    // Whatever try_new rejects, new falls back on
+    let version = ArtifactVersion::new(s);
+    assert_eq!(version.qualifier().as_deref(), Some(s));
+    assert!(!version.is_structured());
+    assert_eq!(version.fallback_reason(), Some(&error));
+}
+
+#[test]
+fn test_try_new() {
+    use ArtifactVersionParseErrorKind::*;
+    use VersionComponent::*;
+
+    for s in &[
+        "1",
+        "1.2.3-1",
+        "1.2-alpha-1",
+        "1.1.2.beta1",
+        "2.0-01",
+        "1.2.3-200705301630",
+        "1.0--SNAPSHOT",
+        "1.0---1",
+    ] {
+        let strict = ArtifactVersion::try_new(s).unwrap();
+        let lenient = ArtifactVersion::new(s);
+        assert_eq!(strict, lenient);
+        assert!(strict.is_structured());
+        assert!(lenient.is_structured());
+        assert_eq!(strict.major(), lenient.major());
+        assert_eq!(strict.incremental(), lenient.incremental());
+        assert_eq!(strict.build(), lenient.build());
+        assert_eq!(strict.qualifier(), lenient.qualifier());
+        assert_eq!(lenient.fallback_reason(), None);
+    }
+
+    check_error("", EmptyComponent, Major, 0);
+    check_error(".1.0-SNAPSHOT", EmptyComponent, Major, 0);
+    check_error("1..0-SNAPSHOT", EmptyComponent, Minor, 2);
+    check_error("1.0.-SNAPSHOT", EmptyComponent, Incremental, 4);
+    check_error("02", LeadingZero, Major, 0);
+    check_error("0.2.09", LeadingZero, Major, 0);
+    check_error("1.30000000000000000000", NumberTooLarge, Minor, 2);
+    check_error("RELEASE", InvalidCharacter('R'), Major, 0);
+    check_error("1.0M2", InvalidCharacter('M'), Minor, 3);
+    check_error("1.0.1b", InvalidCharacter('b'), Incremental, 5);
+    check_error("1.ü", InvalidCharacter('ü'), Minor, 2);
+    check_error("1.0.0.-SNAPSHOT", InvalidCharacter('-'), Qualifier, 6);
+    check_error("1.7.3.0-1", InvalidCharacter('-'), Qualifier, 7);
+    check_error("1.7.3.0", TooManyComponents, Qualifier, 6);
+    check_error("1.0--1", SignedNumber, Qualifier, 4);
+    check_error("1-+2", SignedNumber, Qualifier, 2);
+    check_error("1.-2", EmptyComponent, Minor, 2);
+
+    assert_eq!(
+        ArtifactVersion::try_new("1.0M2").unwrap_err().to_string(),
+        "invalid character 'M' in minor version at byte 3"
+    );
+}
+
+#[test]
+fn test_leading_zero_policy() {
+    let lenient = |s| ArtifactVersion::try_new_with_policy(s, LeadingZeroPolicy::Lenient).unwrap();
+    let components = |v: &ArtifactVersion| {
+        let qualifier = v.qualifier().clone();
+        (v.major(), v.minor(), v.incremental(), v.build(), qualifier)
+    };
+
+    assert_eq!(components(&lenient("02")), (2, 0, 0, 0, None));
+    assert_eq!(components(&lenient("0.2.09")), (0, 2, 9, 0, None));
+    assert_eq!(
+        components(&lenient("0.9.0-SNAPSHOT")),
+        (0, 9, 0, 0, Some("SNAPSHOT".to_string()))
+    );
+    assert_eq!(components(&lenient("2.0-01")), (2, 0, 0, 1, None));
+    assert_eq!(components(&lenient("2.0-0")), (2, 0, 0, 0, None));
+    assert_eq!(
+        lenient("0.2.09").span(VersionComponent::Incremental),
+        Some(4..6)
+    );
+
+    // Other problems are still problems
+    let error = ArtifactVersion::try_new_with_policy("00.1RC", LeadingZeroPolicy::Lenient);
+    assert_eq!(
+        error.unwrap_err().kind(),
+        ArtifactVersionParseErrorKind::InvalidCharacter('R')
+    );
+    let version = ArtifactVersion::new_with_policy("00.1RC", LeadingZeroPolicy::Lenient);
+    assert_eq!(version.qualifier().as_deref(), Some("00.1RC"));
+
+    // Maven's behaviour is the default, and the policy doesn't affect comparison
+    for s in ["02", "0.2.09", "2.0-01", "1.2.3"] {
+        let strict = ArtifactVersion::new_with_policy(s, LeadingZeroPolicy::MavenStrict);
+        let default = ArtifactVersion::new(s);
+        assert_eq!(components(&strict), components(&default));
+        assert_eq!(strict.is_structured(), default.is_structured());
+        assert_eq!(lenient(s), default);
+    }
+    assert_eq!(LeadingZeroPolicy::default(), LeadingZeroPolicy::MavenStrict);
+}
+
+#[test]
+fn test_builder() {
+    fn check(builder: ArtifactVersionBuilder, expected: &str) {
+        let built = builder.finish();
+        let parsed = ArtifactVersion::new(expected);
+
+        assert_eq!(built.as_str(), expected);
+        assert_eq!(built, parsed);
+        assert_eq!(built.major(), parsed.major(), "{:?}", expected);
+        assert_eq!(built.minor(), parsed.minor(), "{:?}", expected);
+        assert_eq!(built.incremental(), parsed.incremental(), "{:?}", expected);
+        assert_eq!(built.build(), parsed.build(), "{:?}", expected);
+        assert_eq!(built.qualifier(), parsed.qualifier(), "{:?}", expected);
+        assert_eq!(built.is_structured(), parsed.is_structured());
+    }
+
+    let b = ArtifactVersionBuilder::new;
+
+    check(b().major(1), "1");
+    check(b().major(1).minor(0), "1.0");
+    check(b().major(1).minor(2).incremental(3), "1.2.3");
+    check(b().major(1).incremental(3), "1.0.3");
+    check(b().major(1).minor(2).incremental(3).build(4), "1.2.3-4");
+    check(b().major(1).build(0), "1");
+    check(b().major(1).minor(2).qualifier("alpha-1"), "1.2-alpha-1");
+    check(b().major(2).qualifier("01"), "2-01");
+    check(b().major(1).build(4).qualifier("SNAPSHOT"), "1-SNAPSHOT");
+    check(b().major(1).qualifier("SNAPSHOT").build(4), "1-4");
+    check(b().major(1).qualifier("+5"), "1-+5");
+    check(b().major(1).qualifier("-5"), "1--5");
+    check(
+        b().major(1).qualifier("123456789012345678901"),
+        "1-123456789012345678901",
+    );
+
+    // Maven reads a major version of 0 as a qualifier, and so does the builder
+    check(b(), "0");
+    check(b().major(0).minor(1), "0.1");
+    check(b().major(0).minor(1).build(2), "0.1-2");
+    let version = b().major(0).minor(1).finish();
+    assert_eq!(version.minor(), 0);
+    assert_eq!(version.qualifier().as_deref(), Some("0.1"));
+    assert!(!version.is_structured());
+}
+
+#[test]
+#[should_panic]
+fn test_builder_rejects_numeric_qualifier() {
+    ArtifactVersionBuilder::new().qualifier("12");
+}
+
+#[test]
+fn test_qualifier_kind() {
+    fn kind(s: &str) -> Option<QualifierKind> {
+        ArtifactVersion::new(s).qualifier_kind()
+    }
+
+    assert_eq!(kind("1.0"), None);
+    assert_eq!(kind("1.0-3"), None);
+    assert_eq!(kind("1.0-alpha"), Some(QualifierKind::Alpha(0)));
+    assert_eq!(kind("1.0-alpha-1"), Some(QualifierKind::Alpha(1)));
+    assert_eq!(kind("1.0-a2"), Some(QualifierKind::Alpha(2)));
+    assert_eq!(kind("1.1.2.beta1"), Some(QualifierKind::Beta(1)));
+    assert_eq!(kind("1.0-M3"), Some(QualifierKind::Milestone(3)));
+    assert_eq!(kind("1.0-milestone.4"), Some(QualifierKind::Milestone(4)));
+    assert_eq!(kind("1.0-RC-5"), Some(QualifierKind::Rc(5)));
+    assert_eq!(kind("1.0-cr6"), Some(QualifierKind::Rc(6)));
+    assert_eq!(kind("1.0-SNAPSHOT"), Some(QualifierKind::Snapshot));
+    assert_eq!(kind("1.0-sp"), Some(QualifierKind::Sp(0)));
+    assert_eq!(kind("1.0-SP2"), Some(QualifierKind::Sp(2)));
+
+    let other = |s: &str| Some(QualifierKind::Other(s.to_string()));
+    assert_eq!(kind("1.0-alpha-1-SNAPSHOT"), other("alpha-1-SNAPSHOT"));
+    assert_eq!(kind("1.0-SNAPSHOT-1"), other("SNAPSHOT-1"));
+    assert_eq!(kind("1.0-rc-1-2"), other("rc-1-2"));
+    assert_eq!(kind("1.0-a"), other("a"));
+    assert_eq!(kind("1.0-Final"), other("Final"));
+    assert_eq!(kind("2.0-01"), other("01"));
+    assert_eq!(kind("1.0RC2"), other("1.0RC2"));
+    assert_eq!(kind("1.0-beta-12345678901"), other("beta-12345678901"));
+}
+
+#[test]
+fn test_default() {
+    #[derive(Default)]
+    struct Dependency {
+        version: ArtifactVersion,
+    }
+
+    let version = Dependency::default().version;
+    assert_eq!(version.as_str(), "0");
+    assert_eq!(version.major(), 0);
+    assert_eq!(version.qualifier().as_deref(), Some("0"));
+    assert!(!version.is_structured());
+    assert_eq!(version, ArtifactVersion::new("0"));
+    assert_eq!(version, ArtifactVersion::new("0.0.0"));
+}
+
+#[test]
+fn test_into_string() {
+    let original = String::from("1.2.3-beta-1");
+    let ptr = original.as_ptr();
+
+    let s = String::from(ArtifactVersion::new_owned(original));
+    assert_eq!(s, "1.2.3-beta-1");
+    assert_eq!(s.as_ptr(), ptr);
+}
+
+#[test]
+fn test_prerelease() {
+    assert!(ArtifactVersion::new("1.2.3-beta-1").is_prerelease());
+    assert!(ArtifactVersion::new("1.0RC2").is_prerelease());
+    assert!(ArtifactVersion::new("1.2.3-4").is_release());
+}
+
+#[test]
+fn test_from_comparable() {
+    for s in &["1.2.3-beta-1", "1.2.3-4", "1.0RC2", ""] {
+        let comparable = ComparableVersion::new(s);
+        let artifact = ArtifactVersion::from(comparable.clone());
+
+        assert_eq!(artifact, ArtifactVersion::new(s));
+        assert_eq!(artifact.as_str(), *s);
+        assert_eq!(artifact.qualifier(), ArtifactVersion::new(s).qualifier());
+        assert_eq!(artifact.build(), ArtifactVersion::new(s).build());
+        assert_eq!(artifact.into_comparable(), comparable);
+    }
+}
+
+#[test]
+fn test_spans() {
+    fn sections(s: &str) -> Vec<(VersionComponent, &str)> {
+        ArtifactVersion::new(s)
+            .sections()
+            .map(|(component, span)| (component, &s[span]))
+            .collect()
+    }
+
+    use VersionComponent::*;
+    assert_eq!(sections("1"), [(Major, "1")]);
+    assert_eq!(
+        sections("1.2.3-1"),
+        [
+            (Major, "1"),
+            (Minor, "2"),
+            (Incremental, "3"),
+            (Qualifier, "1")
+        ]
+    );
+    assert_eq!(
+        sections("10.2-alpha-1"),
+        [(Major, "10"), (Minor, "2"), (Qualifier, "alpha-1")]
+    );
+    assert_eq!(
+        sections("1.1.2.beta1"),
+        [
+            (Major, "1"),
+            (Minor, "1"),
+            (Incremental, "2"),
+            (Qualifier, "beta1")
+        ]
+    );
+    assert_eq!(sections("1-"), [(Major, "1"), (Qualifier, "")]);
+    assert_eq!(sections("1.0RC2"), [(Qualifier, "1.0RC2")]);
+    assert_eq!(sections(""), [(Qualifier, "")]);
+
+    let version = ArtifactVersion::try_new("2.0-SNAPSHOT").unwrap();
+    assert_eq!(version.span(Qualifier), Some(4..12));
+    assert_eq!(version.span(Incremental), None);
+
+    for version in &[
+        ArtifactVersionBuilder::new()
+            .major(1)
+            .incremental(20)
+            .build(3),
+        ArtifactVersionBuilder::new()
+            .major(1)
+            .minor(2)
+            .qualifier("beta-1"),
+        ArtifactVersionBuilder::new().major(3),
+    ] {
+        let built = version.clone().finish();
+        let parsed = ArtifactVersion::new(built.as_str());
+        assert_eq!(
+            built.sections().collect::<Vec<_>>(),
+            parsed.sections().collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn test_eq_ignoring_qualifier() {
+    let rc = ArtifactVersion::new("1.2.3-rc1");
+    assert!(rc.eq_ignoring_qualifier(&ArtifactVersion::new("1.2.3")));
+    assert!(rc.eq_ignoring_qualifier(&ArtifactVersion::new("1.2.3-SNAPSHOT")));
+    assert!(!rc.eq_ignoring_qualifier(&ArtifactVersion::new("1.2.4-rc1")));
+    assert_eq!(
+        rc.cmp_ignoring_qualifier(&ArtifactVersion::new("1.2.3-SNAPSHOT")),
+        Ordering::Equal
+    );
+    assert_eq!(
+        rc.cmp_ignoring_qualifier(&ArtifactVersion::new("1.2.4-rc1")),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn test_ignoring_snapshot() {
+    let snapshot = ArtifactVersion::new("1.2.3-SNAPSHOT");
+    let release = ArtifactVersion::new("1.2.3");
+    assert!(snapshot.eq_ignoring_snapshot(&release));
+    assert_eq!(snapshot.cmp_ignoring_snapshot(&release), Ordering::Equal);
+    assert_ne!(snapshot, release);
+}
+
+#[test]
+fn test_same_major_minor() {
+    let version = ArtifactVersion::new("1.2.3");
+    assert!(version.same_major(&ArtifactVersion::new("1")));
+    assert!(version.same_minor(&ArtifactVersion::new("1.2-SNAPSHOT")));
+    assert!(!version.same_minor(&ArtifactVersion::new("1.3.0")));
+}
+
+#[test]
+fn test_strict_equality() {
+    let version = ArtifactVersion::new("1.0");
+    assert!(version.eq_strict(&ArtifactVersion::new("1.0")));
+    assert!(!version.eq_strict(&ArtifactVersion::new("1.0.0")));
+    assert!(version.is_respelling_of(&ArtifactVersion::new("1.0.0")));
+    assert!(!version.is_respelling_of(&ArtifactVersion::new("1.0")));
+}
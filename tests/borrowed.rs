@@ -0,0 +1,130 @@
+use mvn_version::{ComparableVersion, ComparableVersionRef, ParseOptions};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+const VERSIONS: &[&str] = &[
+    "",
+    "0",
+    "1",
+    "1.0",
+    "1.0.0-0",
+    "1-SNAPSHOT",
+    "1.0-alpha-1",
+    "1.0A1",
+    "1.0-b2",
+    "1.0-CR2",
+    "1.0-rc-2",
+    "1-RELEASE",
+    "1-final",
+    "1-sp1",
+    "1.0-Foo",
+    "1.0-FOO",
+    "1.0-foo-1",
+    "1.0-fOO-1",
+    "1.0-BAR",
+    "1.0-zzz",
+    "Hoxton.SR3",
+    "hoxton.sr3",
+    "2.0.1-klm",
+    "1.2.3-10000000000",
+    "1.123456789012345678901234567890",
+    "1--2",
+    "1.0.Ünicode",
+    "1.0-ÜNICODE",
+];
+
+fn hash<T: Hash>(t: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    t.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_matches_owned() {
+    for a in VERSIONS {
+        let borrowed_a = ComparableVersionRef::new(a);
+        let owned_a = ComparableVersion::new(a);
+
+        assert_eq!(borrowed_a, owned_a, "{:?}", a);
+        assert_eq!(hash(&borrowed_a), hash(&owned_a), "{:?}", a);
+        assert_eq!(borrowed_a.as_str(), *a);
+        assert_eq!(borrowed_a.to_string(), *a);
+
+        for b in VERSIONS {
+            let borrowed_b = ComparableVersionRef::new(b);
+            let owned_b = ComparableVersion::new(b);
+
+            let expected = owned_a.cmp(&owned_b);
+            assert_eq!(borrowed_a.cmp(&borrowed_b), expected, "{:?} {:?}", a, b);
+            assert_eq!(
+                borrowed_a.partial_cmp(&owned_b),
+                Some(expected),
+                "{:?} {:?}",
+                a,
+                b
+            );
+            assert_eq!(
+                owned_a.partial_cmp(&borrowed_b),
+                Some(expected),
+                "{:?} {:?}",
+                a,
+                b
+            );
+            assert_eq!(borrowed_a == borrowed_b, owned_a == owned_b);
+        }
+    }
+}
+
+#[test]
+fn test_matches_case_sensitive() {
+    // Upper case qualifiers in borrowed versions compare like owned ones, which are lowercased
+    let options = ParseOptions::new().case_sensitive(true);
+    for (a, b) in [
+        ("1.0-FOO", "1.0-Foo"),
+        ("1.0-foo", "1.0-FOO"),
+        ("1.0-Foo", "1.0-bar"),
+    ] {
+        let sensitive = ComparableVersion::with_options(b, &options).unwrap();
+        let expected = ComparableVersion::new(a).cmp(&sensitive);
+        assert_eq!(
+            ComparableVersionRef::new(a).partial_cmp(&sensitive),
+            Some(expected),
+            "{:?} {:?}",
+            a,
+            b
+        );
+        assert_eq!(
+            ComparableVersionRef::new(a) == sensitive,
+            ComparableVersion::new(a) == sensitive
+        );
+    }
+}
+
+#[test]
+fn test_into_owned() {
+    for s in VERSIONS {
+        let owned = ComparableVersionRef::new(s).into_owned();
+        let parsed = ComparableVersion::new(s);
+
+        assert_eq!(owned, parsed);
+        assert_eq!(owned.as_str(), parsed.as_str());
+        assert_eq!(owned.canonical(), parsed.canonical());
+        assert_eq!(
+            ComparableVersion::from(ComparableVersionRef::from(*s)),
+            parsed
+        );
+    }
+}
+
+#[test]
+fn test_from_bytes() {
+    for s in VERSIONS {
+        let bytes = s.as_bytes().to_vec();
+        let borrowed = ComparableVersionRef::from_bytes(&bytes).unwrap();
+
+        assert_eq!(borrowed.as_str(), *s);
+        assert_eq!(borrowed.into_owned(), ComparableVersion::new(s));
+    }
+
+    assert!(ComparableVersionRef::from_bytes(b"1.0-\xff").is_err());
+}
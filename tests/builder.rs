@@ -0,0 +1,61 @@
+use mvn_version::{BigUint, ComparableVersion, ComparableVersionBuilder};
+
+fn check(builder: ComparableVersionBuilder, expected: &str) {
+    let version = builder.build();
+    assert_eq!(version.as_str(), expected);
+    assert_eq!(version, ComparableVersion::new(expected));
+    assert_eq!(
+        version.canonical(),
+        ComparableVersion::new(expected).canonical()
+    );
+}
+
+#[test]
+fn test_builder() {
+    let b = ComparableVersionBuilder::new;
+
+    check(b(), "");
+    check(b().number(1).number(0).number(0), "1.0.0");
+    check(b().number(1).segment().qualifier("SNAPSHOT"), "1-SNAPSHOT");
+    check(
+        b().number(1).segment().qualifier("cr").segment().number(2),
+        "1-cr-2",
+    );
+    check(b().qualifier("a").segment().number(1), "a-1");
+    check(b().number(1).qualifier("final").number(1), "1.final.1");
+    check(b().number(1).segment().segment().number(2), "1--2");
+    check(b().segment().number(1), "-1");
+    check(b().number(1).segment().number(0).segment(), "1-0-");
+    check(b().number(12_345_678_901), "12345678901");
+    check(
+        b().big_number(&"123456789012345678901234567890".parse::<BigUint>().unwrap()),
+        "123456789012345678901234567890",
+    );
+}
+
+#[test]
+fn test_builder_ordering() {
+    let release = ComparableVersionBuilder::new().number(1).number(0).build();
+    let rc = ComparableVersionBuilder::new()
+        .number(1)
+        .segment()
+        .qualifier("RC")
+        .segment()
+        .number(1)
+        .build();
+
+    assert!(rc < release);
+    assert!(rc > ComparableVersion::new("1.0-beta-2"));
+}
+
+#[test]
+#[should_panic]
+fn test_builder_rejects_digits() {
+    ComparableVersionBuilder::new().qualifier("rc1");
+}
+
+#[test]
+#[should_panic]
+fn test_builder_rejects_separators() {
+    ComparableVersionBuilder::new().qualifier("foo.bar");
+}
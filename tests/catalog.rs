@@ -0,0 +1,110 @@
+use mvn_version::{ComparableVersion, MavenCoordinates, VersionCatalog, VersionRange};
+
+fn bom(coords: &[&str]) -> VersionCatalog {
+    coords
+        .iter()
+        .map(|c| MavenCoordinates::new(c).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_lookup() {
+    let catalog = bom(&[
+        "com.example:foo:1.0",
+        "com.example:bar:2.0",
+        "org.example:foo:3.0",
+    ]);
+
+    assert_eq!(catalog.len(), 3);
+    assert!(!catalog.is_empty());
+    assert_eq!(catalog.layer_count(), 1);
+
+    let foo = catalog.get("com.example", "foo").unwrap();
+    assert!(foo.is_soft());
+    assert_eq!(
+        foo.recommended_version(),
+        Some(&ComparableVersion::new("1.0"))
+    );
+    assert_eq!(
+        catalog.get("org.example", "foo").unwrap().to_string(),
+        "3.0"
+    );
+    assert_eq!(catalog.get("com.example", "baz"), None);
+
+    let coords = MavenCoordinates::new("com.example:bar:jar:sources:9.9").unwrap();
+    assert_eq!(catalog.get_coordinates(&coords).unwrap().to_string(), "2.0");
+
+    let keys = catalog
+        .iter()
+        .map(|((g, a), v)| format!("{}:{}:{}", g, a, v))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        keys,
+        [
+            "com.example:bar:2.0",
+            "com.example:foo:1.0",
+            "org.example:foo:3.0"
+        ]
+    );
+}
+
+#[test]
+fn test_layering() {
+    let mut catalog = bom(&["com.example:foo:1.0", "com.example:bar:2.0"]);
+    assert_eq!(
+        catalog.insert("com.example", "foo", VersionRange::new("1.1").unwrap()),
+        Some(VersionRange::new("1.0").unwrap())
+    );
+
+    let mut overrides = VersionCatalog::new();
+    overrides.insert(
+        "com.example",
+        "foo",
+        VersionRange::new("[1.2,2.0)").unwrap(),
+    );
+    catalog.push_overrides(overrides);
+
+    catalog.push_layer();
+    catalog.insert("com.example", "baz", VersionRange::new("3.0").unwrap());
+
+    assert_eq!(catalog.layer_count(), 3);
+    assert_eq!(catalog.len(), 3);
+
+    let winner = catalog.winning_entry("com.example", "foo").unwrap();
+    assert_eq!(winner.layer(), 1);
+    assert_eq!(winner.version().to_string(), "[1.2,2.0)");
+
+    let entries = catalog
+        .entries("com.example", "foo")
+        .map(|e| (e.layer(), e.version().to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        entries,
+        [(1, "[1.2,2.0)".to_string()), (0, "1.1".to_string())]
+    );
+
+    assert_eq!(
+        catalog.winning_entry("com.example", "bar").unwrap().layer(),
+        0
+    );
+    assert_eq!(
+        catalog.winning_entry("com.example", "baz").unwrap().layer(),
+        2
+    );
+    assert_eq!(catalog.winning_entry("com.example", "qux"), None);
+
+    let versions = catalog
+        .iter()
+        .map(|(_, v)| v.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(versions, ["2.0", "3.0", "[1.2,2.0)"]);
+}
+
+#[test]
+fn test_empty() {
+    let catalog = VersionCatalog::default();
+    assert!(catalog.is_empty());
+    assert_eq!(catalog.len(), 0);
+    assert_eq!(catalog.layer_count(), 1);
+    assert_eq!(catalog.get("com.example", "foo"), None);
+}
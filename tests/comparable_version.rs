@@ -239,3 +239,464 @@ fn test_mng_6964() {
     assert_versions_ordered(b, c);
     assert_versions_ordered(a, b);
 }
+
+#[test]
+fn test_huge_numbers_equal() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let hash = |v: &ComparableVersion| {
+        let mut hasher = DefaultHasher::new();
+        v.hash(&mut hasher);
+        hasher.finish()
+    };
+
+    assert_versions_equal("1.12345678901234567890", "1.12345678901234567890");
+    assert_versions_equal("12345678901234567890-1", "12345678901234567890.0-1");
+
+    let a = ComparableVersion::new("1.12345678901234567890-1");
+    let b = ComparableVersion::new("1.12345678901234567890.0-1.0");
+    assert_eq!(hash(&a), hash(&b));
+}
+
+#[test]
+fn test_canonical_display() {
+    let version = ComparableVersion::new("1.0.0-CR2");
+    assert_eq!(version.canonical(), "1-rc-2");
+    assert_eq!(format!("{}", version), "1.0.0-CR2");
+    assert_eq!(format!("{:#}", version), "1-rc-2");
+
+    assert_eq!(format!("{:#}", version.clone()), "1-rc-2");
+    assert_eq!(
+        format!("{:#}", ComparableVersion::new("1.0A1")),
+        "1-alpha-1"
+    );
+    assert_eq!(format!("{:#}", ComparableVersion::new("")), "");
+}
+
+#[test]
+fn test_clone_across_threads() {
+    let versions = ["1.0-alpha-1", "1.0", "1.0-sp-1", "1.0.1"]
+        .iter()
+        .map(|v| ComparableVersion::new(v))
+        .collect::<Vec<_>>();
+
+    let handles = (0..4)
+        .map(|i| {
+            let versions = versions.clone();
+            std::thread::spawn(move || versions.into_iter().nth(i).unwrap())
+        })
+        .collect::<Vec<_>>();
+
+    let joined = handles
+        .into_iter()
+        .map(|h| h.join().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(joined, versions);
+    assert!(joined.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(joined[0].as_str(), "1.0-alpha-1");
+}
+
+#[test]
+fn test_as_str() {
+    fn borrowed<S: AsRef<str>>(s: &S) -> &str {
+        s.as_ref()
+    }
+
+    for s in &["", "1.0.0", "1.0-SNAPSHOT", "1.0A1", " 1 "] {
+        let version = ComparableVersion::new(s);
+        assert_eq!(version.as_str(), *s);
+        assert_eq!(borrowed(&version), *s);
+        assert_eq!(version.as_str(), version.to_string());
+    }
+}
+
+#[test]
+fn test_new_owned() {
+    for s in &["1.0A1", "1.0-SNAPSHOT", "", "1--2"] {
+        let owned = ComparableVersion::new_owned(s.to_string());
+        assert_eq!(owned, ComparableVersion::new(s));
+        assert_eq!(owned.as_str(), *s);
+        assert_eq!(owned.canonical(), ComparableVersion::new(s).canonical());
+        assert_eq!(ComparableVersion::from(s.to_string()).as_str(), *s);
+    }
+}
+
+#[test]
+fn test_compare_with_str() {
+    let version = ComparableVersion::new("1.2.0-beta-1");
+
+    assert!(version == "1.2-b1");
+    assert!(version == *"1.2.0.0-beta-1");
+    assert!(version != "1.2-beta-1-SNAPSHOT");
+    assert!(version < "1.2");
+    assert!(version > "1.2-alpha");
+    assert!(version <= "1.2-beta-1");
+    assert!(version >= *"1.1");
+
+    let filtered = ["1.0", "1.5-SNAPSHOT", "2.0-rc1", "2.0", "2.1"]
+        .iter()
+        .map(|v| ComparableVersion::new(v))
+        .filter(|v| *v >= "1.5" && *v < "2.0")
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(filtered, ["2.0-rc1"]);
+}
+
+#[test]
+fn test_default() {
+    let version = ComparableVersion::default();
+    assert_eq!(version.as_str(), "0");
+    assert_eq!(version, ComparableVersion::new(""));
+
+    let mut taken = ComparableVersion::new("1.0");
+    assert_eq!(std::mem::take(&mut taken).as_str(), "1.0");
+    assert_eq!(taken.as_str(), "0");
+}
+
+#[test]
+fn test_into_string() {
+    let original = String::from("1.0-RC1");
+    let ptr = original.as_ptr();
+
+    let version = ComparableVersion::new_owned(original);
+    let s: String = version.into();
+    assert_eq!(s, "1.0-RC1");
+    assert_eq!(s.as_ptr(), ptr);
+}
+
+#[test]
+fn test_canonical_key() {
+    let versions = [
+        "1",
+        "1.0",
+        "1.0.0",
+        "1-0",
+        "1-ga",
+        "1.final",
+        "1-1",
+        "1.0.1",
+        "1.ga.1",
+        "1..1",
+        "1--1",
+        "1-0-1",
+        "1-ga-1",
+        "1.0a1",
+        "1-alpha-1",
+        "1-a-1",
+        "1-sp",
+        "1-SP-0",
+        "1-cr1",
+        "1-rc-1",
+        "a",
+        "alpha",
+        "1-1234567890",
+        "1-001234567890",
+        "",
+    ];
+
+    for (a, b) in versions.iter().tuple_combinations() {
+        let a = ComparableVersion::new(a);
+        let b = ComparableVersion::new(b);
+        assert_eq!(
+            a == b,
+            a.canonical_key() == b.canonical_key(),
+            "{:?} {:?} {:?} {:?}",
+            a,
+            b,
+            a.canonical_key(),
+            b.canonical_key()
+        );
+    }
+}
+
+#[test]
+fn test_prerelease() {
+    let prereleases = [
+        "1.0-alpha",
+        "1.0a1",
+        "1.0-b2",
+        "1.0-M3",
+        "1.0-milestone-1",
+        "1.0-rc1",
+        "1.0-CR2",
+        "1.0-SNAPSHOT",
+        "1.0-alpha-1-foo",
+        "1-alpha.2",
+    ];
+    let releases = [
+        "1.0",
+        "1.0-ga",
+        "1.0.Final",
+        "1.0-RELEASE",
+        "1.0-sp1",
+        "1.0-foo",
+        "1.0-a",
+        "1.0-1",
+        "",
+    ];
+
+    for v in &prereleases {
+        let version = ComparableVersion::new(v);
+        assert!(version.is_prerelease(), "{}", v);
+        assert!(!version.is_release(), "{}", v);
+    }
+    for v in &releases {
+        let version = ComparableVersion::new(v);
+        assert!(version.is_release(), "{}", v);
+        assert!(!version.is_prerelease(), "{}", v);
+    }
+}
+
+#[test]
+fn test_starts_with() {
+    fn starts_with(version: &str, prefix: &str) -> bool {
+        ComparableVersion::new(version).starts_with(&ComparableVersion::new(prefix))
+    }
+
+    assert!(starts_with("1.2.3", "1.2"));
+    assert!(starts_with("1.2.3", "1.2.3"));
+    assert!(starts_with("1.2.3", "1"));
+    assert!(starts_with("1.2.3", ""));
+    assert!(starts_with("1.0.3", "1.0"));
+    assert!(starts_with("1.2-beta-1", "1.2"));
+    assert!(starts_with("1.2-beta-1-foo", "1.2-b1"));
+    assert!(starts_with("1.2-beta-1", "1.2-BETA"));
+    assert!(starts_with("1.2.0-beta", "1.2-beta"));
+    assert!(starts_with("", ""));
+
+    assert!(!starts_with("1.20", "1.2"));
+    assert!(!starts_with("1.2", "1.2.3"));
+    assert!(!starts_with("1.3.1", "1.2"));
+    assert!(!starts_with("1.2-beta-1", "1.2-alpha"));
+    assert!(!starts_with("1.2-beta-1", "1.2-b"));
+    assert!(!starts_with("1.2", "1.2-beta"));
+    assert!(!starts_with("", "1"));
+    assert!(!starts_with("1.2.3", "1.0"));
+    assert!(!starts_with("1.2.3", "1.2.0"));
+    assert!(!starts_with("1-1", "1-0"));
+    assert!(starts_with("1.0", "1.0.0"));
+    assert!(starts_with("1-beta", "1.0-beta"));
+}
+
+#[test]
+fn test_common_prefix() {
+    fn common_prefix(a: &str, b: &str) -> String {
+        let a = ComparableVersion::new(a);
+        let b = ComparableVersion::new(b);
+        let prefix = a.common_prefix(&b);
+
+        assert_eq!(prefix, b.common_prefix(&a));
+        assert!(a.starts_with(&prefix));
+        assert!(b.starts_with(&prefix));
+        prefix.to_string()
+    }
+
+    assert_eq!(common_prefix("1.2.3", "1.2.9-rc1"), "1.2");
+    assert_eq!(common_prefix("1.2.3", "1.2.3"), "1.2.3");
+    assert_eq!(common_prefix("1.2.3", "1.2"), "1.2");
+    assert_eq!(common_prefix("1.0-beta-1", "1.0-rc-1"), "1");
+    assert_eq!(common_prefix("1.0-beta-1", "1-BETA-2"), "1-beta");
+    assert_eq!(common_prefix("1.0.1", "1.0.2"), "1");
+    assert_eq!(common_prefix("1.2", "2.1"), "");
+    assert_eq!(common_prefix("", "1"), "");
+    assert_eq!(common_prefix("1.0A1", "1-alpha-1-SNAPSHOT"), "1-alpha-1");
+}
+
+#[test]
+fn test_eq_ignoring_qualifier() {
+    fn eq(a: &str, b: &str) -> bool {
+        let (a, b) = (ComparableVersion::new(a), ComparableVersion::new(b));
+        assert_eq!(a.eq_ignoring_qualifier(&b), b.eq_ignoring_qualifier(&a));
+        assert_eq!(
+            a.eq_ignoring_qualifier(&b),
+            a.cmp_ignoring_qualifier(&b) == Ordering::Equal
+        );
+        a.eq_ignoring_qualifier(&b)
+    }
+
+    assert!(eq("1.2.3-rc1", "1.2.3"));
+    assert!(eq("1.2.3-RC1", "1.2.3-SNAPSHOT"));
+    assert!(eq("1.2.0-beta-2", "1.2"));
+    assert!(eq("1.2.3", "1.2.3-4"));
+    assert!(eq("1.0.foo", "1"));
+    assert!(eq("2.0RC1", "2.0.0"));
+    assert!(eq("foo", "bar"));
+    assert!(eq(
+        "1.123456789012345678901234567890-a",
+        "1.123456789012345678901234567890"
+    ));
+    assert!(!eq("1.2.3-rc1", "1.2.4"));
+    assert!(!eq("1.2.3", "1.2"));
+    assert!(!eq("1-foo", "foo"));
+}
+
+#[test]
+fn test_cmp_ignoring_qualifier() {
+    let mut versions = [
+        "2.0-rc1",
+        "1.10",
+        "1.2.3-SNAPSHOT",
+        "1.2.3",
+        "foo",
+        "1.2",
+        "1.2.3-beta-1",
+        "2",
+        "1.2.0.1",
+    ]
+    .iter()
+    .map(|s| ComparableVersion::new(s))
+    .collect::<Vec<_>>();
+    versions.sort_by(ComparableVersion::cmp_ignoring_qualifier);
+
+    // The sort is stable, so versions with the same core stay in their original order
+    assert_eq!(
+        versions.iter().map(|v| v.as_str()).collect::<Vec<_>>(),
+        [
+            "foo",
+            "1.2",
+            "1.2.0.1",
+            "1.2.3-SNAPSHOT",
+            "1.2.3",
+            "1.2.3-beta-1",
+            "1.10",
+            "2.0-rc1",
+            "2"
+        ]
+    );
+
+    let big = ComparableVersion::new("1.123456789012345678901234567890-rc");
+    assert_eq!(
+        big.cmp_ignoring_qualifier(&ComparableVersion::new("1.999999999")),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn test_ignoring_snapshot() {
+    fn cmp(a: &str, b: &str) -> Ordering {
+        let (a, b) = (ComparableVersion::new(a), ComparableVersion::new(b));
+        let order = a.cmp_ignoring_snapshot(&b);
+        assert_eq!(b.cmp_ignoring_snapshot(&a), order.reverse());
+        assert_eq!(a.eq_ignoring_snapshot(&b), order == Ordering::Equal);
+        order
+    }
+
+    assert_eq!(cmp("1.0-SNAPSHOT", "1.0"), Ordering::Equal);
+    assert_eq!(cmp("1.0.snapshot", "1"), Ordering::Equal);
+    assert_eq!(cmp("1.0-20050205.060708-1", "1.0"), Ordering::Equal);
+    assert_eq!(
+        cmp("1.0-20050205.060708-1", "1.0-SNAPSHOT"),
+        Ordering::Equal
+    );
+    assert_eq!(cmp("1.0-beta-1-SNAPSHOT", "1.0-beta-1"), Ordering::Equal);
+    assert_eq!(cmp("1.0-SNAPSHOT", "1.0.1"), Ordering::Less);
+    assert_eq!(cmp("1.1-SNAPSHOT", "1.0"), Ordering::Greater);
+    assert_eq!(cmp("1.0-SNAPSHOT", "1.0-1"), Ordering::Less);
+    assert_eq!(cmp("1.0-SNAPSHOT-1", "1.0-1"), Ordering::Less);
+    assert_eq!(cmp("SNAPSHOT", ""), Ordering::Equal);
+
+    // The usual ordering is unaffected
+    assert!(ComparableVersion::new("1.0-SNAPSHOT") < ComparableVersion::new("1.0"));
+}
+
+#[test]
+fn test_strict_equality() {
+    fn check(a: &str, b: &str, strict: bool, respelling: bool) {
+        let (a, b) = (ComparableVersion::new(a), ComparableVersion::new(b));
+        assert_eq!(a.eq_strict(&b), strict, "{} {}", a, b);
+        assert_eq!(b.eq_strict(&a), strict, "{} {}", a, b);
+        assert_eq!(a.is_respelling_of(&b), respelling, "{} {}", a, b);
+        assert_eq!(b.is_respelling_of(&a), respelling, "{} {}", a, b);
+    }
+
+    check("1.0", "1.0", true, false);
+    check("1.0", "1.0.0", false, true);
+    check("1.0-RC1", "1.0-cr-1", false, true);
+    check("1.0-SNAPSHOT", "1.0-snapshot", false, true);
+    check("1-ga", "1", false, true);
+    check("1.0", "1.1", false, false);
+    check("", "", true, false);
+}
+
+#[test]
+fn test_huge_numbers() {
+    // Around the limits of u32 and u128, which matter without the `bigint` feature
+    let u128_max = u128::MAX.to_string();
+    let versions = [
+        "1.999999999".to_string(),
+        "1.1000000000".to_string(),
+        "1.4294967296".to_string(),
+        format!("1.{}", u128::MAX - 1),
+        format!("1.000{}", u128_max),
+        "1.340282366920938463463374607431768211456".to_string(),
+        "1.999999999999999999999999999999999999999".to_string(),
+        "1.1000000000000000000000000000000000000000".to_string(),
+        "1.1000000000000000000000000000000000000001".to_string(),
+    ];
+
+    for (i, a) in versions.iter().enumerate() {
+        for (j, b) in versions.iter().enumerate() {
+            let (a, b) = (ComparableVersion::new(a), ComparableVersion::new(b));
+            assert_eq!(a.cmp(&b), i.cmp(&j), "{} {}", a, b);
+        }
+    }
+
+    let version = ComparableVersion::new(&format!("1.000{}-rc", u128_max));
+    assert_eq!(version.canonical(), format!("1.{}-rc", u128_max));
+}
+
+#[test]
+fn test_from_bytes() {
+    for s in ["1.0", "1.0-RC1", "2.0.0-SNAPSHOT", "1.0-\u{e9}t\u{e9}", ""] {
+        let version = ComparableVersion::from_bytes(s.as_bytes()).unwrap();
+        assert_eq!(version.as_str(), s);
+        assert_eq!(version, ComparableVersion::new(s));
+        assert_eq!(ComparableVersion::from_bytes_lossy(s.as_bytes()), version);
+    }
+
+    let invalid = b"1.0-\xc3\x28";
+    let error = ComparableVersion::from_bytes(invalid).unwrap_err();
+    assert_eq!(error.valid_up_to(), 4);
+
+    let lossy = ComparableVersion::from_bytes_lossy(invalid);
+    assert_eq!(lossy.as_str(), "1.0-\u{fffd}(");
+    assert_eq!(lossy, ComparableVersion::new("1.0-\u{fffd}("));
+}
+
+#[test]
+fn test_hash_str() {
+    use mvn_version::{eq_str, hash_str};
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+
+    let hash = |s: &str| {
+        let mut hasher = DefaultHasher::new();
+        hash_str(s, &mut hasher);
+        hasher.finish()
+    };
+
+    for (a, b) in [
+        ("1", "1.0.0"),
+        ("1-RC1", "1.0-cr-1"),
+        ("1.0-Foo", "1-fOO"),
+        ("1-ga-1", "1-0-1"),
+        ("1a1", "1-alpha-1"),
+        ("", "0.final"),
+    ] {
+        assert!(eq_str(a, b), "{:?} {:?}", a, b);
+        assert_eq!(hash(a), hash(b), "{:?} {:?}", a, b);
+    }
+
+    for (a, b) in [
+        ("1.11", "11.1"),
+        ("1-1", "1.1"),
+        ("1--1", "1-1"),
+        ("1.foo", "1-foo"),
+        ("foo.bar", "foobar"),
+        ("1-SNAPSHOT", "1"),
+    ] {
+        assert!(!eq_str(a, b), "{:?} {:?}", a, b);
+        assert_ne!(hash(a), hash(b), "{:?} {:?}", a, b);
+    }
+}
@@ -0,0 +1,411 @@
+use mvn_version::{
+    group_by_ga, ComparableVersion, CoordinateField, CoordinatesParseErrorKind, MavenCoordinates,
+    SnapshotTimestamp,
+};
+use std::collections::HashSet;
+
+fn check_invalid(
+    coords: &str,
+    kind: CoordinatesParseErrorKind,
+    field: Option<CoordinateField>,
+    offset: usize,
+) {
+    let err = MavenCoordinates::new(coords).unwrap_err();
+    assert_eq!(err.kind(), kind, "{}", coords);
+    assert_eq!(err.field(), field, "{}", coords);
+    assert_eq!(err.offset(), offset, "{}", coords);
+}
+
+#[test]
+fn test_parse() {
+    let coords = MavenCoordinates::new("org.apache.maven:maven-core:3.9.6").unwrap();
+    assert_eq!(coords.group_id(), "org.apache.maven");
+    assert_eq!(coords.artifact_id(), "maven-core");
+    assert_eq!(coords.version(), &ComparableVersion::new("3.9.6"));
+    assert_eq!(coords.to_string(), "org.apache.maven:maven-core:3.9.6");
+    assert_eq!(
+        coords,
+        MavenCoordinates::from_parts(
+            "org.apache.maven",
+            "maven-core",
+            ComparableVersion::new("3.9.6")
+        )
+    );
+}
+
+#[test]
+fn test_invalid() {
+    use CoordinateField::*;
+    use CoordinatesParseErrorKind::*;
+
+    check_invalid("", EmptyField, Some(GroupId), 0);
+    check_invalid("com.example", MissingField, Some(ArtifactId), 11);
+    check_invalid("com.example:foo", MissingField, Some(Version), 15);
+    check_invalid(":foo:1.0", EmptyField, Some(GroupId), 0);
+    check_invalid("com.example::1.0", EmptyField, Some(ArtifactId), 12);
+    check_invalid("com.example:foo:", EmptyField, Some(Version), 16);
+    check_invalid("com.example:foo::1.0", EmptyField, Some(Packaging), 16);
+    check_invalid("com.example:foo:jar::1.0", EmptyField, Some(Classifier), 20);
+    check_invalid("com.example:foo:jar:1.0:", EmptyField, Some(Version), 24);
+    check_invalid(
+        "com.example:foo:jar:tests:1.0:",
+        EmptyField,
+        Some(Scope),
+        30,
+    );
+    check_invalid(
+        "com.example:foo:jar:tests:1.0:test:x",
+        TooManyFields,
+        None,
+        34,
+    );
+
+    assert_eq!(
+        MavenCoordinates::new("com.example:foo")
+            .unwrap_err()
+            .to_string(),
+        "missing field `version` at byte 15"
+    );
+    assert_eq!(
+        MavenCoordinates::new("a:b:c:d:e:f:g")
+            .unwrap_err()
+            .to_string(),
+        "too many fields at byte 11"
+    );
+}
+
+#[test]
+fn test_equality_and_ordering() {
+    let parse = |s: &str| s.parse::<MavenCoordinates>().unwrap();
+
+    assert_eq!(parse("com.example:foo:1.0"), parse("com.example:foo:1.0.0"));
+    assert_ne!(parse("com.example:foo:1.0"), parse("com.example:bar:1.0"));
+
+    let set = [
+        "com.example:foo:1",
+        "com.example:foo:1.0",
+        "com.example:foo:1-0",
+    ]
+    .iter()
+    .map(|s| parse(s))
+    .collect::<HashSet<_>>();
+    assert_eq!(set.len(), 1);
+
+    let mut list = [
+        parse("org.example:a:1.0"),
+        parse("com.example:foo:1.10"),
+        parse("com.example:bar:2.0"),
+        parse("com.example:foo:1.9"),
+        parse("com.example:foo:1.10-SNAPSHOT"),
+    ];
+    list.sort();
+    let sorted = list.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        sorted,
+        [
+            "com.example:bar:2.0",
+            "com.example:foo:1.9",
+            "com.example:foo:1.10-SNAPSHOT",
+            "com.example:foo:1.10",
+            "org.example:a:1.0",
+        ]
+    );
+}
+
+fn check_fields(
+    coords: &str,
+    packaging: Option<&str>,
+    classifier: Option<&str>,
+    version: &str,
+    scope: Option<&str>,
+) {
+    let parsed = MavenCoordinates::new(coords).unwrap();
+    assert_eq!(parsed.group_id(), "com.example", "{}", coords);
+    assert_eq!(parsed.artifact_id(), "foo", "{}", coords);
+    assert_eq!(parsed.packaging(), packaging, "{}", coords);
+    assert_eq!(parsed.classifier(), classifier, "{}", coords);
+    assert_eq!(parsed.version().as_str(), version, "{}", coords);
+    assert_eq!(parsed.scope(), scope, "{}", coords);
+    assert_eq!(parsed.to_string(), coords);
+}
+
+#[test]
+fn test_extended() {
+    check_fields("com.example:foo:1.0", None, None, "1.0", None);
+    check_fields("com.example:foo:1.0:test", None, None, "1.0", Some("test"));
+    check_fields("com.example:foo:pom:1.0", Some("pom"), None, "1.0", None);
+    check_fields(
+        "com.example:foo:jar:1.0:compile",
+        Some("jar"),
+        None,
+        "1.0",
+        Some("compile"),
+    );
+    check_fields(
+        "com.example:foo:jar:sources:1.0",
+        Some("jar"),
+        Some("sources"),
+        "1.0",
+        None,
+    );
+    check_fields(
+        "com.example:foo:jar:tests:1.0:test",
+        Some("jar"),
+        Some("tests"),
+        "1.0",
+        Some("test"),
+    );
+
+    let built = MavenCoordinates::from_parts("com.example", "foo", ComparableVersion::new("1.0"))
+        .with_classifier("sources")
+        .with_scope("provided");
+    assert_eq!(built.packaging(), Some("jar"));
+    assert_eq!(
+        built.to_string(),
+        "com.example:foo:jar:sources:1.0:provided"
+    );
+    assert_eq!(MavenCoordinates::new(&built.to_string()).unwrap(), built);
+    assert_eq!(
+        built.with_packaging("pom").to_string(),
+        "com.example:foo:pom:sources:1.0:provided"
+    );
+}
+
+#[test]
+fn test_repository_path() {
+    let path = |s: &str| MavenCoordinates::new(s).unwrap().repository_path();
+
+    assert_eq!(
+        path("com.example:foo:1.2.3"),
+        "com/example/foo/1.2.3/foo-1.2.3.jar"
+    );
+    assert_eq!(
+        path("org.apache.maven:maven:pom:3.9.6"),
+        "org/apache/maven/maven/3.9.6/maven-3.9.6.pom"
+    );
+    assert_eq!(
+        path("com.example:web:war:1.0"),
+        "com/example/web/1.0/web-1.0.war"
+    );
+    assert_eq!(
+        path("com.example:plugin:maven-plugin:1.0"),
+        "com/example/plugin/1.0/plugin-1.0.jar"
+    );
+    assert_eq!(
+        path("com.example:dist:zip:1.0"),
+        "com/example/dist/1.0/dist-1.0.zip"
+    );
+    assert_eq!(
+        path("com.example:lib:aar:1.0"),
+        "com/example/lib/1.0/lib-1.0.aar"
+    );
+    assert_eq!(
+        path("com.example:lib:bundle:1.0"),
+        "com/example/lib/1.0/lib-1.0.jar"
+    );
+    assert_eq!(
+        path("com.example:foo:jar:sources:1.0:provided"),
+        "com/example/foo/1.0/foo-1.0-sources.jar"
+    );
+    assert_eq!(
+        path("com.example:foo:test-jar:1.0"),
+        "com/example/foo/1.0/foo-1.0-tests.jar"
+    );
+    assert_eq!(
+        path("com.example:foo:test-jar:extra:1.0"),
+        "com/example/foo/1.0/foo-1.0-extra.jar"
+    );
+    // The version is kept as written rather than normalized
+    assert_eq!(
+        path("com.example:foo:1.0.0-Final"),
+        "com/example/foo/1.0.0-Final/foo-1.0.0-Final.jar"
+    );
+
+    let timestamp = SnapshotTimestamp::parse("20050205.060708").unwrap();
+    let snapshot = MavenCoordinates::new("com.example:foo:jar:tests:1.0-SNAPSHOT").unwrap();
+    assert_eq!(
+        snapshot.repository_path(),
+        "com/example/foo/1.0-SNAPSHOT/foo-1.0-SNAPSHOT-tests.jar"
+    );
+    assert_eq!(
+        snapshot.snapshot_repository_path(timestamp, 3),
+        "com/example/foo/1.0-SNAPSHOT/foo-1.0-20050205.060708-3-tests.jar"
+    );
+
+    let release = MavenCoordinates::new("com.example:foo:1.0").unwrap();
+    assert_eq!(
+        release.snapshot_repository_path(timestamp, 3),
+        release.repository_path()
+    );
+}
+
+#[test]
+fn test_group_by_ga() {
+    let coords = [
+        "org.example:a:2.0",
+        "com.example:foo:1.10",
+        "com.example:bar:2.0",
+        "com.example:foo:1.9",
+        "com.example:foo:1.10-SNAPSHOT",
+        "org.example:a:1.0",
+    ]
+    .iter()
+    .map(|c| MavenCoordinates::new(c).unwrap());
+
+    let groups = group_by_ga(coords)
+        .into_iter()
+        .map(|((g, a), coords)| {
+            let versions = coords
+                .iter()
+                .map(|c| c.version().to_string())
+                .collect::<Vec<_>>();
+            (format!("{}:{}", g, a), versions)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        groups,
+        [
+            ("com.example:bar".to_string(), vec!["2.0".to_string()]),
+            (
+                "com.example:foo".to_string(),
+                vec![
+                    "1.9".to_string(),
+                    "1.10-SNAPSHOT".to_string(),
+                    "1.10".to_string()
+                ]
+            ),
+            (
+                "org.example:a".to_string(),
+                vec!["1.0".to_string(), "2.0".to_string()]
+            ),
+        ]
+    );
+
+    assert!(group_by_ga(Vec::new()).is_empty());
+}
+
+#[test]
+fn test_file_name() {
+    let timestamp = SnapshotTimestamp::new(2024, 3, 1, 12, 0, 0).unwrap();
+    let name = |s: &str| MavenCoordinates::new(s).unwrap().file_name();
+    let snapshot_name = |s: &str| {
+        MavenCoordinates::new(s)
+            .unwrap()
+            .snapshot_file_name(timestamp, 2)
+    };
+
+    assert_eq!(name("com.example:foo:1.2.3"), "foo-1.2.3.jar");
+    assert_eq!(name("com.example:foo:pom:1.2.3"), "foo-1.2.3.pom");
+    assert_eq!(
+        name("com.example:foo:jar:javadoc:1.2.3"),
+        "foo-1.2.3-javadoc.jar"
+    );
+    assert_eq!(
+        name("com.example:foo:ejb-client:1.2.3"),
+        "foo-1.2.3-client.jar"
+    );
+
+    assert_eq!(
+        snapshot_name("com.example:foo:1.0-SNAPSHOT"),
+        "foo-1.0-20240301.120000-2.jar"
+    );
+    assert_eq!(
+        snapshot_name("com.example:foo:jar:sources:1.0-snapshot"),
+        "foo-1.0-20240301.120000-2-sources.jar"
+    );
+    assert_eq!(snapshot_name("com.example:foo:1.0"), "foo-1.0.jar");
+
+    // A unique version in the coordinates is used for the file name, while the directory is
+    // named after the non-unique version
+    let unique = MavenCoordinates::new("com.example:foo:1.0-20050205.060708-1").unwrap();
+    assert_eq!(unique.file_name(), "foo-1.0-20050205.060708-1.jar");
+    assert_eq!(
+        unique.repository_path(),
+        "com/example/foo/1.0-SNAPSHOT/foo-1.0-20050205.060708-1.jar"
+    );
+    assert_eq!(
+        unique.snapshot_file_name(timestamp, 2),
+        "foo-1.0-20240301.120000-2.jar"
+    );
+}
+
+#[test]
+fn test_release_and_snapshot() {
+    let to_release = |s: &str| MavenCoordinates::new(s).unwrap().to_release().to_string();
+    let to_snapshot = |s: &str| MavenCoordinates::new(s).unwrap().to_snapshot().to_string();
+
+    assert_eq!(
+        to_release("com.example:foo:1.0-SNAPSHOT"),
+        "com.example:foo:1.0"
+    );
+    assert_eq!(
+        to_release("com.example:foo:1.0-snapshot"),
+        "com.example:foo:1.0"
+    );
+    assert_eq!(
+        to_release("com.example:foo:jar:tests:2.0-beta-1-20050205.060708-3:test"),
+        "com.example:foo:jar:tests:2.0-beta-1:test"
+    );
+    assert_eq!(to_release("com.example:foo:1.0"), "com.example:foo:1.0");
+
+    assert_eq!(
+        to_snapshot("com.example:foo:1.1"),
+        "com.example:foo:1.1-SNAPSHOT"
+    );
+    assert_eq!(
+        to_snapshot("com.example:foo:pom:1.1-rc1"),
+        "com.example:foo:pom:1.1-rc1-SNAPSHOT"
+    );
+    assert_eq!(
+        to_snapshot("com.example:foo:1.1-SNAPSHOT"),
+        "com.example:foo:1.1-SNAPSHOT"
+    );
+    assert_eq!(
+        to_snapshot("com.example:foo:1.1-20050205.060708-3"),
+        "com.example:foo:1.1-SNAPSHOT"
+    );
+
+    let coords = MavenCoordinates::new("com.example:foo:1.0").unwrap();
+    assert_eq!(coords.to_snapshot().to_release(), coords);
+    assert!(coords.to_snapshot() < coords);
+}
+
+#[test]
+fn test_strict() {
+    use CoordinateField::*;
+    use CoordinatesParseErrorKind::*;
+
+    for coords in &[
+        "org.apache.maven:maven-core:3.9.6",
+        "com.example_1:foo.bar-baz_2:1.0",
+        "com.example:foo:jar:sources:1.0 with spaces:test",
+    ] {
+        assert!(MavenCoordinates::new_strict(coords).is_ok(), "{}", coords);
+    }
+
+    for &(coords, c, field, offset) in &[
+        ("com example:foo:1.0", ' ', GroupId, 3),
+        ("com.example:foo/bar:1.0", '/', ArtifactId, 15),
+        ("com.example:${artifactId}:1.0", '$', ArtifactId, 12),
+        ("com.exämple:foo:1.0", 'ä', GroupId, 6),
+        ("com.example:foö:1.0", 'ö', ArtifactId, 14),
+    ] {
+        let err = MavenCoordinates::new_strict(coords).unwrap_err();
+        assert_eq!(err.kind(), InvalidCharacter(c), "{}", coords);
+        assert_eq!(err.field(), Some(field), "{}", coords);
+        assert_eq!(err.offset(), offset, "{}", coords);
+        assert!(MavenCoordinates::new(coords).is_ok(), "{}", coords);
+    }
+
+    assert_eq!(
+        MavenCoordinates::new_strict("com.example:foo bar:1.0")
+            .unwrap_err()
+            .to_string(),
+        "invalid character ' ' in field `artifactId` at byte 15"
+    );
+
+    let built = MavenCoordinates::from_parts("", "foo", ComparableVersion::new("1.0"));
+    assert_eq!(built.validate().unwrap_err().kind(), EmptyField);
+    assert_eq!(built.validate().unwrap_err().field(), Some(GroupId));
+}
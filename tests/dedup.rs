@@ -0,0 +1,45 @@
+use mvn_version::{dedup_versions, ComparableVersion, Representative};
+
+fn dedup(versions: &[&str], representative: Representative) -> Vec<String> {
+    dedup_versions(
+        versions.iter().map(|v| ComparableVersion::new(v)),
+        representative,
+    )
+    .iter()
+    .map(|v| v.to_string())
+    .collect()
+}
+
+#[test]
+fn test_dedup_first() {
+    assert_eq!(
+        dedup(
+            &["1.0.0", "2.0", "1.0", "1-0", "1.0-ga", "2.0-rc1", "2.0.0"],
+            Representative::First
+        ),
+        ["1.0.0", "2.0", "2.0-rc1"]
+    );
+    assert_eq!(
+        dedup(
+            &["1.0-ALPHA1", "1.0-a1", "1.0-alpha-1"],
+            Representative::First
+        ),
+        ["1.0-ALPHA1"]
+    );
+    assert!(dedup(&[], Representative::First).is_empty());
+}
+
+#[test]
+fn test_dedup_canonical() {
+    assert_eq!(
+        dedup(
+            &["1.0.0", "2.0", "1.0", "1-0", "2.0-RC1", "2.0-cr1"],
+            Representative::Canonical
+        ),
+        ["1", "2", "2-rc-1"]
+    );
+    assert_eq!(
+        dedup(&["1.0-ALPHA1", "1.0-a1"], Representative::Canonical),
+        ["1-alpha-1"]
+    );
+}
@@ -0,0 +1,88 @@
+use mvn_version::{ComparableVersion, DynamicVersion};
+
+const AVAILABLE: &[&str] = &[
+    "1.0",
+    "1.9",
+    "1.10",
+    "1.10.1-SNAPSHOT",
+    "2.0-rc1",
+    "2.3.1",
+    "2.3.12",
+    "2.30",
+    "3.0-SNAPSHOT",
+    "10.0",
+];
+
+fn check_select(selector: &str, expected: Option<&str>) {
+    let available = AVAILABLE
+        .iter()
+        .map(|v| ComparableVersion::new(v))
+        .collect::<Vec<_>>();
+    let selector = DynamicVersion::new(selector).unwrap();
+    assert_eq!(
+        selector.select(&available).map(|v| v.as_str()),
+        expected,
+        "{:?}",
+        selector
+    );
+}
+
+#[test]
+fn test_prefix() {
+    check_select("1.+", Some("1.10.1-SNAPSHOT"));
+    check_select("1.9.+", None);
+    check_select("2.3.+", Some("2.3.12"));
+    check_select("2.3+", Some("2.30"));
+    check_select("1+", Some("10.0"));
+    check_select("+", Some("10.0"));
+    check_select("4.+", None);
+}
+
+#[test]
+fn test_latest() {
+    check_select("latest.release", Some("10.0"));
+    check_select("latest.integration", Some("10.0"));
+
+    let available = vec!["1.0", "1.1-SNAPSHOT"]
+        .into_iter()
+        .map(ComparableVersion::new)
+        .collect::<Vec<_>>();
+    let select = |s: &str| {
+        DynamicVersion::new(s)
+            .unwrap()
+            .select(&available)
+            .map(|v| v.as_str())
+    };
+    assert_eq!(select("latest.release"), Some("1.0"));
+    assert_eq!(select("latest.integration"), Some("1.1-SNAPSHOT"));
+}
+
+#[test]
+fn test_range_and_exact() {
+    check_select("[1.0,2.0)", Some("2.0-rc1"));
+    check_select("[1.0,2.0-alpha)", Some("1.10.1-SNAPSHOT"));
+    check_select("[2.0,3.0)", Some("3.0-SNAPSHOT"));
+    check_select("[2.0,2.30)", Some("2.3.12"));
+    check_select("(,1.0]", Some("1.0"));
+    check_select("2.3.12", Some("2.3.12"));
+    check_select("2.3.12.0", Some("2.3.12"));
+    check_select("2.3.2", None);
+
+    assert!(DynamicVersion::new("[2.0,1.0]").is_err());
+    assert!(!DynamicVersion::new("1.0").unwrap().is_dynamic());
+    assert!(DynamicVersion::new("1.+").unwrap().is_dynamic());
+}
+
+#[test]
+fn test_display() {
+    for s in &[
+        "1.+",
+        "+",
+        "latest.release",
+        "latest.integration",
+        "[1.0,2.0)",
+        "1.0",
+    ] {
+        assert_eq!(DynamicVersion::new(s).unwrap().to_string(), *s);
+    }
+}
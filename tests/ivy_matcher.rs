@@ -0,0 +1,89 @@
+use mvn_version::{ComparableVersion, IvyMatcher, IvyStatus, RangeParseErrorKind};
+
+const AVAILABLE: &[&str] = &[
+    "1.0",
+    "1.0.1",
+    "1.0.2-SNAPSHOT",
+    "1.5",
+    "2.0-rc1",
+    "2.0",
+    "2.1-SNAPSHOT",
+];
+
+fn check_select(matcher: &str, expected: Option<&str>) {
+    let available = AVAILABLE
+        .iter()
+        .map(|v| ComparableVersion::new(v))
+        .collect::<Vec<_>>();
+    let matcher = IvyMatcher::new(matcher).unwrap();
+    assert_eq!(
+        matcher.select(&available).map(|v| v.as_str()),
+        expected,
+        "{:?}",
+        matcher
+    );
+}
+
+#[test]
+fn test_ranges() {
+    check_select("[1.0,2.0]", Some("2.0"));
+    check_select("[1.0,2.0[", Some("2.0-rc1"));
+    check_select("]1.0,1.5[", Some("1.0.2-SNAPSHOT"));
+    check_select("]1.5,2.0-rc1]", Some("2.0-rc1"));
+    check_select("[2.0,)", Some("2.1-SNAPSHOT"));
+    check_select("(,1.0]", Some("1.0"));
+    check_select("(,1.0[", None);
+}
+
+#[test]
+fn test_sub_revision_and_latest() {
+    check_select("1.0.+", Some("1.0.2-SNAPSHOT"));
+    check_select("1.+", Some("1.5"));
+    check_select("3.+", None);
+
+    check_select("latest.integration", Some("2.1-SNAPSHOT"));
+    check_select("latest.milestone", Some("2.0"));
+    check_select("latest.release", Some("2.0"));
+
+    let status = |v: &str| IvyStatus::of(&ComparableVersion::new(v));
+    assert_eq!(status("1.0-SNAPSHOT"), IvyStatus::Integration);
+    assert_eq!(status("1.0-beta-1"), IvyStatus::Milestone);
+    assert_eq!(status("1.0-rc1"), IvyStatus::Milestone);
+    assert_eq!(status("1.0"), IvyStatus::Release);
+    assert_eq!(status("1.0-sp1"), IvyStatus::Release);
+
+    let available = vec!["1.0", "1.1-beta-1", "1.2-SNAPSHOT"]
+        .into_iter()
+        .map(ComparableVersion::new)
+        .collect::<Vec<_>>();
+    let select = |s: &str| {
+        IvyMatcher::new(s)
+            .unwrap()
+            .select(&available)
+            .map(|v| v.as_str())
+    };
+    assert_eq!(select("latest.integration"), Some("1.2-SNAPSHOT"));
+    assert_eq!(select("latest.milestone"), Some("1.1-beta-1"));
+    assert_eq!(select("latest.release"), Some("1.0"));
+}
+
+#[test]
+fn test_invalid_and_display() {
+    let kind = |s: &str| IvyMatcher::new(s).unwrap_err().kind();
+    assert_eq!(kind("[1.0,2.0"), RangeParseErrorKind::UnbalancedBracket);
+    assert_eq!(kind("[2.0,1.0]"), RangeParseErrorKind::BoundsOutOfOrder);
+    assert_eq!(kind("]1.0,1.0]"), RangeParseErrorKind::BoundsOutOfOrder);
+    assert_eq!(kind("[1.0,2.0,3.0]"), RangeParseErrorKind::TooManyBounds);
+
+    for s in &[
+        "[1.0,2.0[",
+        "]1.0,2.0]",
+        "[1.0,)",
+        "(,2.0]",
+        "1.0.+",
+        "latest.milestone",
+        "1.0",
+    ] {
+        assert_eq!(IvyMatcher::new(s).unwrap().to_string(), *s);
+    }
+}
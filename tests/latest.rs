@@ -0,0 +1,63 @@
+use mvn_version::{latest_and_release, ComparableVersion, MetaVersion};
+
+fn check(versions: &[&str], latest: Option<&str>, release: Option<&str>) {
+    let versions = versions
+        .iter()
+        .map(|v| ComparableVersion::new(v))
+        .collect::<Vec<_>>();
+    let (l, r) = latest_and_release(&versions);
+    assert_eq!(l.map(|v| v.as_str()), latest, "{:?}", versions);
+    assert_eq!(r.map(|v| v.as_str()), release, "{:?}", versions);
+}
+
+#[test]
+fn test_latest_and_release() {
+    check(&[], None, None);
+    check(&["1.0"], Some("1.0"), Some("1.0"));
+    check(&["1.0-SNAPSHOT"], Some("1.0-SNAPSHOT"), None);
+    check(
+        &["1.1", "1.0", "1.2-SNAPSHOT"],
+        Some("1.2-SNAPSHOT"),
+        Some("1.1"),
+    );
+    check(
+        &["1.10", "1.9", "1.10-SNAPSHOT"],
+        Some("1.10"),
+        Some("1.10"),
+    );
+    check(
+        &["1.0-alpha-1", "1.0-beta-1"],
+        Some("1.0-beta-1"),
+        Some("1.0-beta-1"),
+    );
+    // The first of several equal versions is kept
+    check(&["1.0", "1.0.0"], Some("1.0"), Some("1.0"));
+}
+
+#[test]
+fn test_meta_version() {
+    let available = ["1.0", "1.1", "1.2-SNAPSHOT", "1.0.0"]
+        .iter()
+        .map(|v| ComparableVersion::new(v))
+        .collect::<Vec<_>>();
+    let resolve = |s: &str| MetaVersion::new(s).resolve(&available).map(|v| v.as_str());
+
+    assert_eq!(resolve("LATEST"), Some("1.2-SNAPSHOT"));
+    assert_eq!(resolve("RELEASE"), Some("1.1"));
+    assert_eq!(resolve("1"), Some("1.0"));
+    assert_eq!(resolve("2.0"), None);
+    assert_eq!(resolve("latest"), None);
+
+    assert!(MetaVersion::new("LATEST").is_meta());
+    assert!(!MetaVersion::new("Release").is_meta());
+    assert_eq!(MetaVersion::new("RELEASE"), MetaVersion::Release);
+    assert_eq!(
+        "1.0".parse::<MetaVersion>().unwrap(),
+        MetaVersion::Version(ComparableVersion::new("1"))
+    );
+    assert_eq!(MetaVersion::Latest.to_string(), "LATEST");
+    assert_eq!(MetaVersion::new("1.0-rc1").to_string(), "1.0-rc1");
+
+    let snapshots = [ComparableVersion::new("1.0-SNAPSHOT")];
+    assert_eq!(MetaVersion::Release.resolve(&snapshots), None);
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "metadata")]
+
+use mvn_version::metadata::{Metadata, MetadataParseErrorKind};
+use mvn_version::ComparableVersion;
+
+#[test]
+fn test_artifact_metadata() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<metadata modelVersion="1.1.0">
+  <groupId>org.apache.maven</groupId>
+  <artifactId>maven-core</artifactId>
+  <versioning>
+    <latest>4.0.0-beta-3</latest>
+    <release>3.9.6</release>
+    <versions>
+      <version>3.9.5</version>
+      <version>3.9.6</version>
+      <version>4.0.0-beta-3</version>
+    </versions>
+    <lastUpdated>20240101120000</lastUpdated>
+  </versioning>
+</metadata>"#;
+
+    let metadata = Metadata::from_xml(xml).unwrap();
+    assert_eq!(metadata.group_id(), Some("org.apache.maven"));
+    assert_eq!(metadata.artifact_id(), Some("maven-core"));
+    assert_eq!(metadata.version(), None);
+    assert_eq!(metadata.latest().unwrap().as_str(), "4.0.0-beta-3");
+    assert_eq!(metadata.release(), Some(&ComparableVersion::new("3.9.6")));
+    assert_eq!(
+        metadata.versions(),
+        &["3.9.5", "3.9.6", "4.0.0-beta-3"]
+            .iter()
+            .map(|v| ComparableVersion::new(v))
+            .collect::<Vec<_>>()[..]
+    );
+    assert_eq!(metadata.last_updated(), Some("20240101120000"));
+    assert_eq!(metadata.snapshot(), None);
+    assert!(metadata.snapshot_artifacts().is_empty());
+}
+
+#[test]
+fn test_snapshot_metadata() {
+    let xml = r#"<metadata>
+  <groupId>com.example</groupId>
+  <artifactId>foo</artifactId>
+  <version>1.0-SNAPSHOT</version>
+  <versioning>
+    <snapshot>
+      <timestamp>20050205.060708</timestamp>
+      <buildNumber>7</buildNumber>
+    </snapshot>
+    <lastUpdated>20050205060708</lastUpdated>
+    <snapshotVersions>
+      <snapshotVersion>
+        <extension>jar</extension>
+        <value>1.0-20050205.060708-7</value>
+        <updated>20050205060708</updated>
+      </snapshotVersion>
+      <snapshotVersion>
+        <classifier>sources</classifier>
+        <extension>jar</extension>
+        <value>1.0-20050205.060708-7</value>
+        <updated>20050205060708</updated>
+      </snapshotVersion>
+    </snapshotVersions>
+  </versioning>
+</metadata>"#;
+
+    let metadata: Metadata = xml.parse().unwrap();
+    assert_eq!(metadata.version().unwrap().as_str(), "1.0-SNAPSHOT");
+
+    let snapshot = metadata.snapshot().unwrap();
+    assert_eq!(snapshot.timestamp(), Some("20050205.060708"));
+    assert_eq!(snapshot.build_number(), 7);
+    assert!(!snapshot.is_local_copy());
+
+    let artifacts = metadata.snapshot_artifacts();
+    assert_eq!(artifacts.len(), 2);
+    assert_eq!(artifacts[0].classifier(), None);
+    assert_eq!(artifacts[0].extension(), "jar");
+    assert_eq!(artifacts[0].version().as_str(), "1.0-20050205.060708-7");
+    assert_eq!(artifacts[0].updated(), Some("20050205060708"));
+    assert_eq!(artifacts[1].classifier(), Some("sources"));
+
+    let local = Metadata::from_xml(
+        "<metadata><versioning><snapshot><localCopy>true</localCopy></snapshot></versioning></metadata>",
+    )
+    .unwrap();
+    assert!(local.snapshot().unwrap().is_local_copy());
+    assert_eq!(local.snapshot().unwrap().timestamp(), None);
+    assert_eq!(local.snapshot().unwrap().build_number(), 0);
+}
+
+#[test]
+fn test_invalid() {
+    let err = Metadata::from_xml("<metadata>\n  <versioning>\n</metadata>").unwrap_err();
+    assert_eq!(err.kind(), MetadataParseErrorKind::MalformedXml);
+    assert_eq!(err.line(), 3);
+
+    let err = Metadata::from_xml("<project/>").unwrap_err();
+    assert_eq!(err.kind(), MetadataParseErrorKind::UnexpectedRootElement);
+    assert_eq!(err.to_string(), "root element is not <metadata> at 1:1");
+
+    let xml = "<metadata>\n  <versioning>\n    <snapshot><buildNumber>x</buildNumber></snapshot>\n  </versioning>\n</metadata>";
+    let err = Metadata::from_xml(xml).unwrap_err();
+    assert_eq!(err.kind(), MetadataParseErrorKind::InvalidBuildNumber);
+    assert_eq!((err.line(), err.column()), (3, 15));
+}
@@ -0,0 +1,37 @@
+use mvn_version::{ArtifactVersion, ComparableVersion, Newest};
+use std::collections::BinaryHeap;
+
+#[test]
+fn test_newest_order() {
+    let old = Newest(ComparableVersion::new("1.0"));
+    let new = Newest(ComparableVersion::new("1.1"));
+
+    assert!(new < old);
+    assert!(old > new);
+    assert_eq!(Newest(ComparableVersion::new("1.0.0")), old);
+
+    let mut versions = ["1.0-alpha-1", "1.0", "1.0-SNAPSHOT", "1.0-sp-1", "0.9"]
+        .iter()
+        .map(|v| Newest(ArtifactVersion::new(v)))
+        .collect::<Vec<_>>();
+    versions.sort();
+
+    let order = versions.iter().map(|v| v.as_str()).collect::<Vec<_>>();
+    assert_eq!(
+        order,
+        ["1.0-sp-1", "1.0", "1.0-SNAPSHOT", "1.0-alpha-1", "0.9"]
+    );
+}
+
+#[test]
+fn test_newest_heap() {
+    // A heap of Newest pops the oldest version first
+    let mut heap = ["2.0", "1.0", "3.0-rc1", "1.5"]
+        .iter()
+        .map(|v| Newest::from(ComparableVersion::new(v)))
+        .collect::<BinaryHeap<_>>();
+
+    assert_eq!(heap.pop().unwrap().to_string(), "1.0");
+    assert_eq!(heap.pop().unwrap().into_inner().as_str(), "1.5");
+    assert_eq!(heap.peek().unwrap().as_str(), "2.0");
+}
@@ -0,0 +1,138 @@
+//! Throws lots of awkward strings at the parsers, checking that they never panic and that the
+//! different views of a parsed version agree with each other.
+
+use itertools::Itertools;
+use mvn_version::{
+    cmp_str, eq_str, hash_str, ArtifactVersion, ComparableVersion, ComparableVersionRef,
+    ParseOptions, VersionRange,
+};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+
+// Pieces whose lowercase forms differ in length, combine with their neighbours, or are otherwise
+// known to trip up byte-oriented code
+const PIECES: &[&str] = &[
+    "0", "1", "9", ".", "-", "a", "A", "b", "m", "x", "İ", "ẞ", "Σ", "ς", "\u{307}", "\u{212a}",
+    "२", " ", "\0", "é", "🦀", "ga", "sp", "SNAPSHOT", "rc",
+];
+
+fn check(s: &str) {
+    let version = ComparableVersion::new(s);
+    assert_eq!(ComparableVersionRef::new(s), version, "{:?}", s);
+    assert_eq!(version.as_str(), s);
+    assert_eq!(
+        version.tokens().map(|t| t.as_str()).collect::<String>(),
+        s,
+        "{:?}",
+        s
+    );
+    assert_eq!(
+        ComparableVersionRef::new(s).into_owned().canonical(),
+        version.canonical()
+    );
+
+    let _ = version.explain_parse();
+    let _ = version.decompose();
+    let _ = version.separators().count();
+    let _ = version.base_version();
+    let _ = (version.major(), version.minor(), version.patch());
+
+    let artifact = ArtifactVersion::new(s);
+    if let Ok(strict) = ArtifactVersion::try_new(s) {
+        assert_eq!(strict, artifact, "{:?}", s);
+    }
+    for (_, span) in artifact.sections() {
+        assert!(s.get(span).is_some(), "{:?}", s);
+    }
+
+    for options in &[
+        ParseOptions::new().strict(true),
+        ParseOptions::new().unicode_digits(true),
+        ParseOptions::new()
+            .trim_whitespace(true)
+            .max_length(Some(4)),
+        ParseOptions::new()
+            .unicode_digits(true)
+            .max_segments(Some(2))
+            .max_digits(Some(1)),
+    ] {
+        match ComparableVersion::with_options(s, options) {
+            Ok(version) => assert!(s.contains(version.as_str()), "{:?}", s),
+            Err(error) => assert!(s.get(error.span()).is_some(), "{:?}", s),
+        }
+    }
+
+    let _ = VersionRange::new(s);
+    let _ = VersionRange::new(&format!("[{},)", s));
+}
+
+#[test]
+fn test_exhaustive() {
+    for len in 0..=3 {
+        for pieces in (0..len).map(|_| PIECES.iter()).multi_cartesian_product() {
+            check(&pieces.into_iter().copied().collect::<String>());
+        }
+    }
+}
+
+fn hash(s: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    hash_str(s, &mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_cmp_str() {
+    // The streaming comparison must agree with parsing for every pair of short strings
+    let strings = (0..=2)
+        .flat_map(|len| (0..len).map(|_| PIECES.iter()).multi_cartesian_product())
+        .map(|pieces| pieces.into_iter().copied().collect::<String>())
+        .collect::<Vec<_>>();
+    let versions = strings
+        .iter()
+        .map(|s| ComparableVersion::new(s))
+        .collect::<Vec<_>>();
+
+    let hashes = strings.iter().map(|s| hash(s)).collect::<Vec<_>>();
+
+    for ((a, v), h) in strings.iter().zip(&versions).zip(&hashes) {
+        for ((b, w), i) in strings.iter().zip(&versions).zip(&hashes) {
+            assert_eq!(cmp_str(a, b), v.cmp(w), "{:?} {:?}", a, b);
+            assert_eq!(eq_str(a, b), v == w, "{:?} {:?}", a, b);
+            if v == w {
+                assert_eq!(h, i, "{:?} {:?}", a, b);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_random() {
+    // xorshift64, so failures are reproducible
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    for _ in 0..5000 {
+        let len = next() % 16;
+        let s = (0..len)
+            .map(|_| PIECES[(next() % PIECES.len() as u64) as usize])
+            .collect::<String>();
+        check(&s);
+
+        let t = (0..next() % 16)
+            .map(|_| PIECES[(next() % PIECES.len() as u64) as usize])
+            .collect::<String>();
+        assert_eq!(
+            cmp_str(&s, &t),
+            ComparableVersion::new(&s).cmp(&ComparableVersion::new(&t)),
+            "{:?} {:?}",
+            s,
+            t
+        );
+    }
+}
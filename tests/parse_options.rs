@@ -0,0 +1,342 @@
+use mvn_version::{ComparableVersion, ParseOptions, QualifierTable, VersionParseErrorKind};
+use std::ops::Range;
+
+#[test]
+fn test_default_options() {
+    let options = ParseOptions::new();
+
+    for s in &["", " 1.0 ", "1.0-SNAPSHOT", "\t"] {
+        let version = ComparableVersion::with_options(s, &options).unwrap();
+        assert_eq!(version, ComparableVersion::new(s));
+        assert_eq!(version.as_str(), *s);
+    }
+}
+
+#[test]
+fn test_trim_whitespace() {
+    let options = ParseOptions::new().trim_whitespace(true);
+
+    let version = ComparableVersion::with_options(" \t1.0 RC1\r\n", &options).unwrap();
+    assert_eq!(version.as_str(), "1.0 RC1");
+    assert_eq!(
+        ComparableVersion::with_options("   ", &options)
+            .unwrap()
+            .as_str(),
+        ""
+    );
+}
+
+#[test]
+fn test_reject_whitespace() {
+    let options = ParseOptions::new().reject_whitespace(true);
+
+    let error = ComparableVersion::with_options(" 1.0", &options).unwrap_err();
+    assert_eq!(error.kind(), VersionParseErrorKind::Whitespace);
+    assert_eq!(error.span(), 0..1);
+
+    // Unlike strict mode, empty versions and control characters are fine
+    assert!(ComparableVersion::with_options("", &options).is_ok());
+    assert!(ComparableVersion::with_options("1.0\u{0}", &options).is_ok());
+
+    let options = options.trim_whitespace(true);
+    let version = ComparableVersion::with_options("\r\n 1.0-RC1 \t", &options).unwrap();
+    assert_eq!(version.as_str(), "1.0-RC1");
+
+    let error = ComparableVersion::with_options("  1.0 \u{a0}RC1", &options).unwrap_err();
+    assert_eq!(error.kind(), VersionParseErrorKind::Whitespace);
+    assert_eq!(error.span(), 5..8);
+}
+
+#[test]
+fn test_new_trimmed() {
+    for (s, trimmed) in [
+        ("1.0", "1.0"),
+        (" \t1.0-RC1\n", "1.0-RC1"),
+        (" 1 . 0 ", "1 . 0"),
+        ("  ", ""),
+    ] {
+        let version = ComparableVersion::new_trimmed(s);
+        assert_eq!(version.as_str(), trimmed);
+        assert_eq!(version, ComparableVersion::new(trimmed));
+    }
+}
+
+#[test]
+fn test_max_length() {
+    let options = ParseOptions::new().max_length(Some(3));
+
+    assert!(ComparableVersion::with_options("1.0", &options).is_ok());
+    let error = ComparableVersion::with_options("1.0.0", &options).unwrap_err();
+    assert_eq!(error.kind(), VersionParseErrorKind::TooLong);
+    assert_eq!(error.offset(), 3);
+    assert_eq!(error.to_string(), "version too long at byte 3");
+
+    // The limit applies after trimming, but offsets are into the untrimmed string
+    let options = options.trim_whitespace(true);
+    assert!(ComparableVersion::with_options("  1.0  ", &options).is_ok());
+    let error = ComparableVersion::with_options("  1.0.0", &options).unwrap_err();
+    assert_eq!(error.offset(), 5);
+
+    // The span starts at a character boundary
+    let error = ComparableVersion::with_options("1.Ü", &options).unwrap_err();
+    assert_eq!(error.span(), 2..4);
+}
+
+#[test]
+fn test_strict() {
+    use VersionParseErrorKind::*;
+
+    fn check_error(s: &str, kind: VersionParseErrorKind, span: std::ops::Range<usize>) {
+        let error = ComparableVersion::try_new_strict(s).unwrap_err();
+        assert_eq!(error.kind(), kind, "{:?}", s);
+        assert_eq!(error.span(), span, "{:?}", s);
+        assert_eq!(error.offset(), span.start, "{:?}", s);
+
+        // Lenient parsing still accepts it
+        assert!(ComparableVersion::with_options(s, &ParseOptions::new()).is_ok());
+    }
+
+    for s in &["1", "1.0-RC1", "1.0-Ünicode", "foo"] {
+        assert_eq!(
+            ComparableVersion::try_new_strict(s).unwrap(),
+            ComparableVersion::new(s)
+        );
+    }
+
+    check_error("", Empty, 0..0);
+    check_error(" 1.0", Whitespace, 0..1);
+    check_error("1.0 \u{a0}\t-RC1", Whitespace, 3..7);
+    check_error("1.0\n", Whitespace, 3..4);
+    check_error("1.0\u{0}", ControlCharacter('\u{0}'), 3..4);
+    check_error("1.\u{85}", Whitespace, 2..4);
+    check_error("1.\u{9f}", ControlCharacter('\u{9f}'), 2..4);
+
+    assert_eq!(
+        ComparableVersion::try_new_strict("1.0\u{7}")
+            .unwrap_err()
+            .to_string(),
+        "control character '\\u{7}' in version at byte 3"
+    );
+
+    // Trimmed whitespace doesn't count, but what's left must not be empty
+    let options = ParseOptions::new().strict(true).trim_whitespace(true);
+    assert_eq!(
+        ComparableVersion::with_options(" 1.0\n", &options)
+            .unwrap()
+            .as_str(),
+        "1.0"
+    );
+    let error = ComparableVersion::with_options("  \n", &options).unwrap_err();
+    assert_eq!(error.kind(), Empty);
+    assert_eq!(error.span(), 3..3);
+    let error = ComparableVersion::with_options(" 1 0", &options).unwrap_err();
+    assert_eq!(error.span(), 2..3);
+}
+
+#[test]
+fn test_unicode_digits() {
+    let options = ParseOptions::new().unicode_digits(true);
+    let parse = |s: &str| ComparableVersion::with_options(s, &options).unwrap();
+
+    // Devanagari, Arabic-Indic, fullwidth and mathematical digits
+    assert_eq!(parse("१.२.३"), ComparableVersion::new("1.2.3"));
+    assert_eq!(
+        parse("٢.٠-SNAPSHOT"),
+        ComparableVersion::new("2.0-SNAPSHOT")
+    );
+    assert_eq!(parse("１０.０"), ComparableVersion::new("10"));
+    assert_eq!(parse("𝟏.𝟗"), ComparableVersion::new("1.9"));
+    assert_eq!(parse("1.0-rc१"), ComparableVersion::new("1.0-rc-1"));
+    assert_eq!(
+        parse("१२३४५६७८९०१२"),
+        ComparableVersion::new("123456789012")
+    );
+    assert_eq!(parse("१.२").as_str(), "१.२");
+    assert!(parse("१.१०") > parse("१.९"));
+
+    // Other numeric characters aren't decimal digits
+    assert_eq!(parse("1.½"), ComparableVersion::new("1.½"));
+    assert_eq!(parse("Ⅻ"), ComparableVersion::new("Ⅻ"));
+
+    // Without the option they're qualifiers
+    let version = ComparableVersion::new("१.२");
+    assert_ne!(version, ComparableVersion::new("1.2"));
+    assert_eq!(version.canonical(), "१.२");
+
+    for s in &["1.0-SNAPSHOT", "1.0A1", "", "1--2"] {
+        assert_eq!(parse(s), ComparableVersion::new(s));
+        assert_eq!(parse(s).canonical(), ComparableVersion::new(s).canonical());
+    }
+}
+
+#[test]
+fn test_qualifier_table() {
+    let table = QualifierTable::new()
+        .alias("stable", "ga")
+        .alias("CR", "beta")
+        .alias("nightly", "snapshot");
+    assert!(!table.is_empty());
+    assert_eq!(table.get("cr"), Some("beta"));
+
+    let options = ParseOptions::new().qualifiers(table);
+    let parse = |s| ComparableVersion::with_options(s, &options).unwrap();
+
+    // Aliases can stand for Maven's own aliases, and override them
+    assert_eq!(parse("1.0-Stable"), ComparableVersion::new("1.0"));
+    assert_eq!(parse("1.0-cr1"), ComparableVersion::new("1.0-beta-1"));
+    assert_eq!(parse("1.0-rc1"), ComparableVersion::new("1.0-rc-1"));
+    assert!(parse("1.0-nightly").is_snapshot());
+
+    // The original string and tokens are left alone
+    let version = parse("1.0-stable");
+    assert_eq!(version.as_str(), "1.0-stable");
+    assert_eq!(version.canonical(), "1");
+    assert_eq!(
+        version.tokens().count(),
+        ComparableVersion::new("1.0-stable").tokens().count()
+    );
+
+    // Without a table, nothing changes
+    assert!(QualifierTable::new().is_empty());
+    assert_ne!(
+        ComparableVersion::with_options("1.0-stable", &ParseOptions::new()).unwrap(),
+        ComparableVersion::new("1.0")
+    );
+}
+
+#[test]
+fn test_case_sensitive() {
+    let options = ParseOptions::new()
+        .case_sensitive(true)
+        .qualifiers(QualifierTable::new().alias("Stable", "ga"));
+    let parse = |s| ComparableVersion::with_options(s, &options).unwrap();
+
+    assert_ne!(parse("1.0-FOO"), parse("1.0-foo"));
+    assert!(parse("1.0-FOO") < parse("1.0-Foo"));
+    assert!(parse("1.0-Foo") < parse("1.0-foo"));
+    assert_eq!(parse("1.0-Foo1"), parse("1.0-Foo-1"));
+    assert_eq!(parse("1-Foo.BAR").canonical(), "1-Foo.BAR");
+
+    // Known qualifiers and aliases are still case-insensitive
+    for (a, b) in &[
+        ("1.0-RC1", "1.0-rc-1"),
+        ("1.0-Cr1", "1.0-rc-1"),
+        ("1.0-A1", "1.0-alpha-1"),
+        ("1.0-SNAPSHOT", "1.0-snapshot"),
+        ("1.0-GA", "1"),
+        ("1.0-STABLE", "1"),
+    ] {
+        assert_eq!(parse(a), ComparableVersion::new(b), "{}", a);
+    }
+
+    // A lone letter is only an alias when followed by a number
+    assert_ne!(parse("1.0-A"), parse("1.0-a"));
+
+    // Off, qualifiers are lowercased as usual
+    let options = ParseOptions::new().case_sensitive(false);
+    assert_eq!(
+        ComparableVersion::with_options("1.0-FOO", &options).unwrap(),
+        ComparableVersion::new("1.0-foo")
+    );
+}
+
+#[test]
+fn test_unicode_case() {
+    // By default, only ASCII letters are folded
+    assert_eq!(
+        ComparableVersion::new("1.0-FOO"),
+        ComparableVersion::new("1.0-foo")
+    );
+    assert_ne!(
+        ComparableVersion::new("1.0-ÜNICODE"),
+        ComparableVersion::new("1.0-ünicode")
+    );
+    assert_eq!(
+        ComparableVersion::new("1.0-ÜNICODE").canonical(),
+        "1-Ünicode"
+    );
+    assert_eq!(ComparableVersion::new("1-İ").canonical(), "1-İ");
+    assert_eq!(
+        ComparableVersion::new("1-\u{212a}").canonical(),
+        "1-\u{212a}"
+    );
+
+    let options = ParseOptions::new().unicode_case(true);
+    let parse = |s| ComparableVersion::with_options(s, &options).unwrap();
+
+    assert_eq!(parse("1.0-ÜNICODE"), parse("1.0-ünicode"));
+    assert_eq!(parse("1-İ").canonical(), "1-i\u{307}");
+    // The Kelvin sign lowercases to an ASCII k, but it's still not an alias
+    assert_eq!(parse("1-\u{212a}").canonical(), "1-k");
+    assert_eq!(parse("1.0-ΣΑΣ").canonical(), "1-σας");
+    assert_eq!(parse("1.0-RC1"), ComparableVersion::new("1.0-rc-1"));
+
+    // Unknown qualifiers keep their case either way when parsing case-sensitively
+    let options = options.case_sensitive(true);
+    let parse = |s| ComparableVersion::with_options(s, &options).unwrap();
+    assert_eq!(parse("1.0-ÜNICODE").canonical(), "1-ÜNICODE");
+    assert_eq!(parse("1.0-CR1"), ComparableVersion::new("1.0-rc-1"));
+}
+
+#[test]
+fn test_limits() {
+    fn check(
+        s: &str,
+        options: &ParseOptions,
+        expected: Option<(VersionParseErrorKind, Range<usize>)>,
+    ) {
+        let result = ComparableVersion::with_options(s, options);
+        match expected {
+            None => assert_eq!(result.unwrap(), ComparableVersion::new(s.trim())),
+            Some((kind, span)) => {
+                let error = result.unwrap_err();
+                assert_eq!(error.kind(), kind, "{:?}", s);
+                assert_eq!(error.span(), span, "{:?}", s);
+            }
+        }
+    }
+
+    use VersionParseErrorKind::*;
+
+    let options = ParseOptions::new().max_segments(Some(3));
+    check("1-2-3", &options, None);
+    check("1.2.3.4.5.6", &options, None);
+    check("1a1", &options, None);
+    check("1.a.b.c", &options, None);
+    check("1-2-3-4-5", &options, Some((TooManySegments, 6..9)));
+    check("1-2a3", &options, Some((TooManySegments, 4..5)));
+    check("1--2-", &options, Some((TooManySegments, 5..5)));
+    check("1---", &options, Some((TooManySegments, 4..4)));
+
+    let options = ParseOptions::new().max_digits(Some(3));
+    check("100.200-300", &options, None);
+    check("1.0123", &options, Some((TooManyDigits, 2..6)));
+    check("1-rc1234.5", &options, Some((TooManyDigits, 4..8)));
+    check("1-rc12345rc", &options, Some((TooManyDigits, 4..9)));
+    check("abcdefgh", &options, None);
+
+    // Only digits which the parser counts as such are limited
+    check("१२३४", &options, None);
+    let options = options.unicode_digits(true);
+    assert_eq!(
+        ComparableVersion::with_options("1.१२३४", &options)
+            .unwrap_err()
+            .span(),
+        2..14
+    );
+
+    // Offsets are into the untrimmed string
+    let options = ParseOptions::new()
+        .max_digits(Some(2))
+        .trim_whitespace(true);
+    check("  1.100 ", &options, Some((TooManyDigits, 4..7)));
+
+    let error = ComparableVersion::try_new_limited(&"1.".repeat(200)).unwrap_err();
+    assert_eq!(error.kind(), TooLong);
+    assert_eq!(error.to_string(), "version too long at byte 256");
+    let error = ComparableVersion::try_new_limited(&"1-".repeat(100)).unwrap_err();
+    assert_eq!(error.kind(), TooManySegments);
+    assert_eq!(error.to_string(), "too many segments in version at byte 64");
+    assert!(ComparableVersion::try_new_limited(&"9".repeat(64)).is_ok());
+    assert!(ComparableVersion::try_new_limited("1.0-SNAPSHOT").is_ok());
+}
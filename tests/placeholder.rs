@@ -0,0 +1,87 @@
+use mvn_version::{has_placeholders, interpolate, ComparableVersion, PlaceholderErrorKind};
+use std::collections::HashMap;
+
+fn properties() -> HashMap<&'static str, &'static str> {
+    let mut properties = HashMap::new();
+    properties.insert("spring.version", "5.3.${spring.patch}");
+    properties.insert("spring.patch", "31");
+    properties.insert("revision", "1.2.0");
+    properties.insert("changelist", "-SNAPSHOT");
+    properties.insert("empty", "");
+    properties.insert("a", "${b}");
+    properties.insert("b", "x${a}");
+    properties.insert("self", "${self}");
+    properties
+}
+
+fn check(s: &str, expected: &str) {
+    let properties = properties();
+    assert_eq!(
+        interpolate(s, |p| properties.get(p)).unwrap(),
+        expected,
+        "{}",
+        s
+    );
+}
+
+fn check_invalid(s: &str, kind: PlaceholderErrorKind, property: Option<&str>, offset: usize) {
+    let properties = properties();
+    let err = interpolate(s, |p| properties.get(p)).unwrap_err();
+    assert_eq!(err.kind(), kind, "{}", s);
+    assert_eq!(err.property(), property, "{}", s);
+    assert_eq!(err.offset(), offset, "{}", s);
+}
+
+#[test]
+fn test_has_placeholders() {
+    assert!(has_placeholders("${project.version}"));
+    assert!(has_placeholders("1.0-${changelist}"));
+    assert!(has_placeholders("${}"));
+    assert!(!has_placeholders("1.0"));
+    assert!(!has_placeholders("$1.0"));
+    assert!(!has_placeholders("${revision"));
+    assert!(!has_placeholders("}${"));
+}
+
+#[test]
+fn test_interpolate() {
+    check("1.0", "1.0");
+    check("${revision}", "1.2.0");
+    check("${revision}${changelist}", "1.2.0-SNAPSHOT");
+    check("v${spring.version}-RELEASE", "v5.3.31-RELEASE");
+    check("1.0${empty}", "1.0");
+    check("$revision}", "$revision}");
+}
+
+#[test]
+fn test_invalid() {
+    use PlaceholderErrorKind::*;
+
+    check_invalid("${undefined}", UndefinedProperty, Some("undefined"), 0);
+    check_invalid("1.0-${}", UndefinedProperty, Some(""), 4);
+    check_invalid("1.0-${revision", UnterminatedPlaceholder, None, 4);
+    check_invalid("1.0-${self}", RecursiveProperty, Some("self"), 4);
+    check_invalid("${revision}-${a}", RecursiveProperty, Some("a"), 12);
+
+    let err = interpolate("1.0-${x}", |_| None::<&str>).unwrap_err();
+    assert_eq!(err.to_string(), "undefined property `x` at byte 4");
+}
+
+#[test]
+fn test_interpolated_version() {
+    let properties = properties();
+
+    let version =
+        ComparableVersion::interpolated("${revision}${changelist}", |p| properties.get(p)).unwrap();
+    assert_eq!(version.as_str(), "1.2.0-SNAPSHOT");
+    assert_eq!(version, ComparableVersion::new("1.2-SNAPSHOT"));
+
+    let mut owned = HashMap::new();
+    owned.insert("v".to_string(), "2.0".to_string());
+    assert_eq!(
+        ComparableVersion::interpolated("${v}", |p| owned.get(p)).unwrap(),
+        ComparableVersion::new("2")
+    );
+
+    assert!(ComparableVersion::interpolated("${missing}", |p| properties.get(p)).is_err());
+}
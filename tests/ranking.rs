@@ -0,0 +1,77 @@
+use itertools::Itertools;
+use mvn_version::{ComparableVersion, QualifierRanking};
+
+fn check_ordered(ranking: &QualifierRanking, versions: &[&str]) {
+    for ((i, a), (j, b)) in versions.iter().enumerate().tuple_combinations() {
+        let a = ComparableVersion::new(a);
+        let b = ComparableVersion::new(b);
+        assert_eq!(a.cmp_with_ranking(&b, ranking), i.cmp(&j), "{} {}", a, b);
+        assert_eq!(b.cmp_with_ranking(&a, ranking), j.cmp(&i), "{} {}", b, a);
+    }
+}
+
+#[test]
+fn test_default_ranking() {
+    let versions = [
+        "1-alpha-1",
+        "1-beta",
+        "1-m2",
+        "1-CR1",
+        "1-SNAPSHOT",
+        "1",
+        "1-sp",
+        "1-abc",
+        "1-xyz",
+        "1-1",
+        "1.1",
+    ];
+    check_ordered(&QualifierRanking::new(), &versions);
+
+    // It agrees with the usual comparison, down to the pickier cases
+    let all = [
+        "1.0", "1-ga", "1.0.0-rc", "1.foo", "1-foo-1", "1.sp.1", "1-1-sp", "1.0.a1",
+    ];
+    for (a, b) in all.iter().cartesian_product(all.iter()) {
+        let a = ComparableVersion::new(a);
+        let b = ComparableVersion::new(b);
+        assert_eq!(
+            a.cmp_with_ranking(&b, &QualifierRanking::new()),
+            a.cmp(&b),
+            "{} {}",
+            a,
+            b
+        );
+    }
+}
+
+#[test]
+fn test_custom_ranking() {
+    let ranking = QualifierRanking::new()
+        .insert_before("ea", "CR")
+        .insert_before("preview", "nonexistent");
+    assert_eq!(ranking.rank("ea"), Some(3));
+    assert_eq!(ranking.rank("rc"), Some(4));
+    assert_eq!(ranking.rank("preview"), Some(8));
+    check_ordered(
+        &ranking,
+        &[
+            "17-m1",
+            "17-ea",
+            "17-ea-2",
+            "17-rc",
+            "17",
+            "17-sp",
+            "17-preview",
+            "17-abc",
+        ],
+    );
+
+    // Moving a known qualifier
+    let ranking = QualifierRanking::new().insert_before("snapshot", "alpha");
+    check_ordered(&ranking, &["2-SNAPSHOT", "2-alpha", "2-rc", "2"]);
+
+    // Leaving known qualifiers out
+    let ranking = QualifierRanking::from_order(["dev", "ga", "sp"]);
+    assert_eq!(ranking.rank(""), Some(1));
+    check_ordered(&ranking, &["3-dev", "3", "3-sp", "3-alpha", "3-snapshot"]);
+}
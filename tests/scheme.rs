@@ -0,0 +1,222 @@
+use mvn_version::{ArtifactVersion, ComparableVersion, VersionScheme};
+use std::cmp::Ordering;
+
+fn check_ordered(scheme: VersionScheme, versions: &[&str]) {
+    for (i, a) in versions.iter().enumerate() {
+        for (j, b) in versions.iter().enumerate() {
+            let expected = i.cmp(&j);
+            assert_eq!(scheme.compare(a, b), expected, "{:?} {} {}", scheme, a, b);
+            assert_eq!(
+                ArtifactVersion::new(a).cmp_with_scheme(&ArtifactVersion::new(b), scheme),
+                expected,
+                "{:?} {} {}",
+                scheme,
+                a,
+                b
+            );
+            assert_eq!(
+                ComparableVersion::new(a).cmp_with_scheme(&ComparableVersion::new(b), scheme),
+                expected,
+                "{:?} {} {}",
+                scheme,
+                a,
+                b
+            );
+        }
+    }
+}
+
+#[test]
+fn test_maven2() {
+    check_ordered(
+        VersionScheme::Maven2,
+        &[
+            "1.0-RC1",
+            "1.0-alpha-1-SNAPSHOT",
+            // The longer of two qualifiers starting the same way is older
+            "1.0-alpha-10",
+            "1.0-alpha-1",
+            "1.0-alpha-9",
+            "1.0-beta-1",
+            "1.0-rc1",
+            "1.0",
+            "1.0-1",
+            "1.0-2",
+            "1.0.1",
+            "1.1",
+            "2.0",
+        ],
+    );
+
+    // Unparseable versions are all qualifier, and sort before proper ones
+    assert_eq!(VersionScheme::Maven2.compare("1.0RC2", "1"), Ordering::Less);
+    assert_eq!(
+        VersionScheme::Maven2.compare("1.0", "1.0.0"),
+        Ordering::Equal
+    );
+    assert_eq!(
+        VersionScheme::Maven2.compare("1.0-SNAPSHOT", "1.0-snapshot"),
+        Ordering::Less
+    );
+
+    // Maven 2 reads numbers as an i32, and makes those too large for it qualifiers
+    check_ordered(
+        VersionScheme::Maven2,
+        &[
+            "2147483648.0",
+            "1.2.3-2147483648",
+            "1.2.3",
+            "1.2.3-2147483647",
+            "2147483647.0",
+        ],
+    );
+    assert_eq!(
+        VersionScheme::Maven2.compare("1.2.3-3000000000", "1.2.3"),
+        Ordering::Less
+    );
+    assert_eq!(
+        VersionScheme::Maven2.compare("1.2147483648", "1.0"),
+        Ordering::Less
+    );
+    assert_eq!(
+        VersionScheme::Maven2.compare("1.2147483647", "1.0"),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn test_maven3() {
+    assert_eq!(VersionScheme::default(), VersionScheme::Maven3);
+    check_ordered(
+        VersionScheme::Maven3,
+        &[
+            "1.0-alpha-1-SNAPSHOT",
+            "1.0-alpha-1",
+            "1.0-alpha-9",
+            "1.0-alpha-10",
+            "1.0-beta-1",
+            "1.0-RC1",
+            "1.0",
+            "1.0-1",
+            "1.0.1",
+        ],
+    );
+}
+
+#[test]
+fn test_maven4() {
+    check_ordered(
+        VersionScheme::Maven4,
+        &[
+            "1.0-alpha-1-SNAPSHOT",
+            "1.0-alpha-1",
+            "1.0-alpha-10",
+            // Qualifiers directly followed by numbers come after those which aren't
+            "1.0-alpha1",
+            "1.0-beta-1",
+            "1.0.RC1",
+            "1.0-RC2",
+            "1.0",
+            "1.0-1",
+            "1.0.1",
+        ],
+    );
+
+    for x in &[
+        "abc",
+        "alpha",
+        "a",
+        "beta",
+        "b",
+        "def",
+        "milestone",
+        "m",
+        "RC",
+    ] {
+        let version = |separator, number| format!("{}{}{}", separator, x, number);
+        for prefix in &["1.0.0", "2.0", "2"] {
+            assert_eq!(
+                VersionScheme::Maven4.compare(
+                    &format!("{}{}", prefix, version(".", 1)),
+                    &format!("{}{}", prefix, version("-", 2))
+                ),
+                Ordering::Less,
+                "{} {}",
+                prefix,
+                x
+            );
+        }
+        assert_eq!(
+            VersionScheme::Maven4.compare(
+                &format!("2.0{}", version(".", 1)),
+                &format!("2{}", version(".", 1))
+            ),
+            Ordering::Equal,
+            "{}",
+            x
+        );
+    }
+
+    // A qualifier followed by a number comes after the bare qualifier
+    assert_eq!(
+        VersionScheme::Maven4.compare("1-rc1", "1-rc.5"),
+        Ordering::Greater
+    );
+    assert_eq!(
+        VersionScheme::Maven4.compare("1.0.0.RC1", "1.0.0"),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn test_generic() {
+    check_ordered(
+        VersionScheme::Generic,
+        &[
+            "1.0-min",
+            "1.0-alpha-1-SNAPSHOT",
+            "1.0-alpha-1",
+            "1.0-alpha-9",
+            "1.0-alpha-10",
+            "1.0-beta-1",
+            "1.0-RC1",
+            "1.0-SNAPSHOT",
+            "1.0",
+            "1.0-sp",
+            "1.0-foo",
+            "1.0.1",
+            "1.0-max",
+            "1.1",
+        ],
+    );
+
+    for (a, b) in &[
+        ("1-1", "1.1"),
+        ("1_1", "1.1"),
+        ("1a1", "1-alpha-1"),
+        ("1.0.0-GA", "1"),
+        ("1.0-final-0", "1"),
+        ("1.0-1", "1.0.1"),
+        ("1rc1", "1.rc.1"),
+        ("01.002", "1.2"),
+        ("1..2", "1.0.2"),
+    ] {
+        assert_eq!(
+            VersionScheme::Generic.compare(a, b),
+            Ordering::Equal,
+            "{} {}",
+            a,
+            b
+        );
+    }
+
+    // `min` and `max` are only special at the end
+    assert_eq!(
+        VersionScheme::Generic.compare("1.max.1", "1.1"),
+        Ordering::Less
+    );
+    assert_eq!(
+        VersionScheme::Generic.compare("1.1-MIN", "1.0.99"),
+        Ordering::Greater
+    );
+}
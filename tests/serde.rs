@@ -0,0 +1,220 @@
+#![cfg(feature = "serde")]
+
+use mvn_version::{ComparableVersion, MavenCoordinates, Restriction, VersionRange};
+use serde_json::json;
+
+#[test]
+fn test_range_as_string() {
+    let range = VersionRange::new("[1.0,2.0), [3.0,)").unwrap();
+    let json = serde_json::to_value(&range).unwrap();
+    assert_eq!(json, json!("[1.0,2.0),[3.0,)"));
+    assert_eq!(serde_json::from_value::<VersionRange>(json).unwrap(), range);
+
+    let soft = VersionRange::new("1.0").unwrap();
+    assert_eq!(serde_json::to_value(&soft).unwrap(), json!("1.0"));
+
+    assert!(serde_json::from_value::<VersionRange>(json!("[2.0,1.0]")).is_err());
+}
+
+#[test]
+fn test_restriction_as_string() {
+    let restriction: Restriction = "( 1.0 ,2.0]".parse().unwrap();
+    let json = serde_json::to_value(&restriction).unwrap();
+    assert_eq!(json, json!("(1.0,2.0]"));
+    assert_eq!(
+        serde_json::from_value::<Restriction>(json).unwrap(),
+        restriction
+    );
+
+    assert!(serde_json::from_value::<Restriction>(json!("1.0")).is_err());
+    assert!(serde_json::from_value::<Restriction>(json!("[1.0],[2.0]")).is_err());
+}
+
+#[test]
+fn test_range_structured() {
+    use mvn_version::structured_range;
+
+    let range = VersionRange::new("[1.0,2.0),[3.0]").unwrap();
+    let json = structured_range::serialize(&range, serde_json::value::Serializer).unwrap();
+    assert_eq!(
+        json,
+        json!({
+            "recommended_version": null,
+            "restrictions": [
+                {
+                    "lower_bound": "1.0",
+                    "lower_bound_inclusive": true,
+                    "upper_bound": "2.0",
+                    "upper_bound_inclusive": false,
+                },
+                {
+                    "lower_bound": "3.0",
+                    "lower_bound_inclusive": true,
+                    "upper_bound": "3.0",
+                    "upper_bound_inclusive": true,
+                },
+            ],
+        })
+    );
+    assert_eq!(structured_range::deserialize(json).unwrap(), range);
+
+    let soft = VersionRange::new("1.0").unwrap();
+    let json = structured_range::serialize(&soft, serde_json::value::Serializer).unwrap();
+    assert_eq!(json["recommended_version"], json!("1.0"));
+    assert_eq!(structured_range::deserialize(json).unwrap(), soft);
+
+    // Restrictions inside the structured form may also be strings, and missing fields default
+    let mixed = json!({
+        "restrictions": ["[1.0,2.0)", { "lower_bound": "3.0", "lower_bound_inclusive": true }],
+    });
+    assert_eq!(
+        structured_range::deserialize(mixed).unwrap(),
+        VersionRange::new("[1.0,2.0),[3.0,)").unwrap()
+    );
+
+    // Both forms are accepted, but only by the structured deserializer
+    assert_eq!(
+        structured_range::deserialize(json!("[1.0,2.0)")).unwrap(),
+        VersionRange::new("[1.0,2.0)").unwrap()
+    );
+    let map = json!({ "restrictions": ["[1.0,2.0)"] });
+    assert!(serde_json::from_value::<VersionRange>(map).is_err());
+
+    let overlapping = json!({ "restrictions": ["[1.0,2.0]", "[1.5,3.0]"] });
+    assert!(structured_range::deserialize(overlapping).is_err());
+
+    // A recommended version only comes with the restriction of a soft requirement
+    let contradictory = json!({ "recommended_version": "1.0", "restrictions": ["[2.0,3.0)"] });
+    assert!(structured_range::deserialize(contradictory).is_err());
+    let soft = json!({ "recommended_version": "1.0" });
+    assert_eq!(
+        structured_range::deserialize(soft).unwrap(),
+        VersionRange::new("1.0").unwrap()
+    );
+}
+
+#[test]
+fn test_range_non_self_describing() {
+    for spec in &["[1.0,2.0),[3.0,)", "1.0", ""] {
+        let range = VersionRange::new(spec).unwrap();
+        let bytes = bincode::serialize(&range).unwrap();
+        assert_eq!(bincode::deserialize::<VersionRange>(&bytes).unwrap(), range);
+    }
+
+    let restriction: Restriction = "(1.0,2.0]".parse().unwrap();
+    let bytes = bincode::serialize(&restriction).unwrap();
+    assert_eq!(
+        bincode::deserialize::<Restriction>(&bytes).unwrap(),
+        restriction
+    );
+}
+
+#[test]
+fn test_coordinates_as_string() {
+    let coords = MavenCoordinates::new("com.example:foo:jar:sources:1.0:test").unwrap();
+    let json = serde_json::to_value(&coords).unwrap();
+    assert_eq!(json, json!("com.example:foo:jar:sources:1.0:test"));
+    assert_eq!(
+        serde_json::from_value::<MavenCoordinates>(json).unwrap(),
+        coords
+    );
+
+    let built = MavenCoordinates::from_parts("com.example", "foo", ComparableVersion::new("1.0"))
+        .with_classifier("sources");
+    let json = serde_json::to_value(&built).unwrap();
+    assert_eq!(json, json!("com.example:foo:jar:sources:1.0"));
+    assert_eq!(
+        serde_json::from_value::<MavenCoordinates>(json).unwrap(),
+        built
+    );
+
+    let lockfile = json!({ "com.example:foo": "com.example:foo:1.0" });
+    let parsed: std::collections::BTreeMap<String, MavenCoordinates> =
+        serde_json::from_value(lockfile).unwrap();
+    assert_eq!(parsed["com.example:foo"].version().as_str(), "1.0");
+
+    assert!(serde_json::from_value::<MavenCoordinates>(json!("com.example:foo")).is_err());
+}
+
+#[test]
+fn test_coordinates_non_self_describing() {
+    let coords = MavenCoordinates::new("com.example:foo:jar:sources:1.0:test").unwrap();
+    let bytes = bincode::serialize(&coords).unwrap();
+    assert_eq!(
+        bincode::deserialize::<MavenCoordinates>(&bytes).unwrap(),
+        coords
+    );
+}
+
+#[test]
+fn test_coordinates_structured() {
+    use mvn_version::structured_coordinates;
+
+    let coords = MavenCoordinates::new("com.example:foo:pom:1.0").unwrap();
+    let json = structured_coordinates::serialize(&coords, serde_json::value::Serializer).unwrap();
+    assert_eq!(
+        json,
+        json!({
+            "group_id": "com.example",
+            "artifact_id": "foo",
+            "version": "1.0",
+            "packaging": "pom",
+        })
+    );
+    assert_eq!(structured_coordinates::deserialize(json).unwrap(), coords);
+    assert_eq!(
+        structured_coordinates::deserialize(json!({
+            "group_id": "com.example",
+            "artifact_id": "foo",
+            "version": "1.0",
+            "packaging": "pom",
+        }))
+        .unwrap(),
+        coords
+    );
+
+    // Unlike the string form, the structured form can't be ambiguous
+    let scoped = structured_coordinates::deserialize(json!({
+        "group_id": "com.example",
+        "artifact_id": "foo",
+        "version": "1.0",
+        "scope": "custom",
+    }))
+    .unwrap();
+    assert_eq!(scoped.packaging(), None);
+    assert_eq!(scoped.scope(), Some("custom"));
+
+    let classified = structured_coordinates::deserialize(json!({
+        "group_id": "com.example",
+        "artifact_id": "foo",
+        "version": "1.0",
+        "classifier": "sources",
+    }))
+    .unwrap();
+    assert_eq!(classified.packaging(), Some("jar"));
+    assert_eq!(
+        MavenCoordinates::new(&classified.to_string()).unwrap(),
+        classified
+    );
+
+    // The default impl only accepts the string form
+    assert!(serde_json::from_value::<MavenCoordinates>(json!({
+        "group_id": "com.example",
+        "artifact_id": "foo",
+        "version": "1.0",
+    }))
+    .is_err());
+
+    for invalid in &[
+        json!({ "group_id": "com.example", "artifact_id": "foo" }),
+        json!({ "group_id": "com.example", "artifact_id": "", "version": "1.0" }),
+        json!({ "group_id": "g", "artifact_id": "a", "version": "1.0", "classifier": "" }),
+        json!({ "group_id": "g", "artifact_id": "a", "version": "1.0", "type": "jar" }),
+    ] {
+        assert!(
+            structured_coordinates::deserialize(invalid.clone()).is_err(),
+            "{}",
+            invalid
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use mvn_version::{ArtifactVersion, ComparableVersion, SnapshotTimestamp, SnapshotVersion};
+
+#[test]
+fn test_unique() {
+    let snapshot = SnapshotVersion::new("1.0-20050205.060708-1").unwrap();
+    assert!(snapshot.is_unique());
+    assert_eq!(snapshot.as_str(), "1.0-20050205.060708-1");
+    assert_eq!(snapshot.base_version(), "1.0-SNAPSHOT");
+    assert_eq!(snapshot.release_version(), "1.0");
+    assert_eq!(snapshot.build_number(), Some(1));
+
+    let timestamp = snapshot.timestamp().unwrap();
+    assert_eq!(
+        (timestamp.year(), timestamp.month(), timestamp.day()),
+        (2005, 2, 5)
+    );
+    assert_eq!(
+        (timestamp.hour(), timestamp.minute(), timestamp.second()),
+        (6, 7, 8)
+    );
+    assert_eq!(timestamp.to_string(), "20050205.060708");
+
+    let snapshot = SnapshotVersion::new("2.0-beta-1-20240101.235959-42").unwrap();
+    assert_eq!(snapshot.base_version(), "2.0-beta-1-SNAPSHOT");
+    assert_eq!(snapshot.build_number(), Some(42));
+}
+
+#[test]
+fn test_non_unique() {
+    for &(version, release) in &[
+        ("1.0-SNAPSHOT", "1.0"),
+        ("1.0-snapshot", "1.0"),
+        ("1.0SNAPSHOT", "1.0"),
+        ("SNAPSHOT", ""),
+    ] {
+        let snapshot = SnapshotVersion::new(version).unwrap();
+        assert!(!snapshot.is_unique(), "{}", version);
+        assert_eq!(snapshot.base_version(), version);
+        assert_eq!(snapshot.release_version(), release, "{}", version);
+        assert_eq!(snapshot.timestamp(), None);
+        assert_eq!(snapshot.build_number(), None);
+    }
+}
+
+#[test]
+fn test_not_snapshot() {
+    for version in &[
+        "1.0",
+        "",
+        "SNAP",
+        "1.0-SNAPSHOT-1",
+        "1.0-20050205.060708",
+        "1.0-20050205.060708-",
+        "1.0-20050205060708-1",
+        "1.0-2005020.060708-1",
+        "1.0-20051305.060708-1",
+        "1.0-20050205.240708-1",
+        "1.0-20050205.060708-x",
+        "1.0-20050205.060708-99999999999",
+        "ééééa",
+    ] {
+        assert_eq!(SnapshotVersion::new(version), None, "{}", version);
+    }
+}
+
+#[test]
+fn test_timestamp() {
+    assert_eq!(
+        SnapshotTimestamp::parse("20050205.060708"),
+        SnapshotTimestamp::new(2005, 2, 5, 6, 7, 8)
+    );
+    assert_eq!(SnapshotTimestamp::new(2005, 0, 5, 6, 7, 8), None);
+    assert_eq!(SnapshotTimestamp::new(2005, 2, 32, 6, 7, 8), None);
+    assert_eq!(SnapshotTimestamp::new(2005, 2, 5, 6, 60, 8), None);
+    assert_eq!(SnapshotTimestamp::parse("2005-02-05 06:07"), None);
+    assert!(
+        SnapshotTimestamp::parse("20050205.060708") < SnapshotTimestamp::parse("20050205.060709")
+    );
+}
+
+#[test]
+fn test_to_unique() {
+    let timestamp = SnapshotTimestamp::new(2024, 12, 31, 23, 59, 59).unwrap();
+
+    let unique = SnapshotVersion::new("1.0-SNAPSHOT")
+        .unwrap()
+        .to_unique(timestamp, 7);
+    assert_eq!(unique.as_str(), "1.0-20241231.235959-7");
+    assert_eq!(
+        unique,
+        SnapshotVersion::new("1.0-20241231.235959-7").unwrap()
+    );
+
+    let redeployed = unique.to_unique(SnapshotTimestamp::new(2025, 1, 1, 0, 0, 0).unwrap(), 8);
+    assert_eq!(redeployed.as_str(), "1.0-20250101.000000-8");
+    assert_eq!(redeployed.base_version(), "1.0-SNAPSHOT");
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono() {
+    use chrono::{FixedOffset, TimeZone};
+
+    let time = FixedOffset::east_opt(2 * 3600)
+        .unwrap()
+        .with_ymd_and_hms(2005, 2, 5, 8, 7, 8)
+        .unwrap();
+    assert_eq!(SnapshotTimestamp::from(time).to_string(), "20050205.060708");
+
+    assert!(SnapshotTimestamp::now() > SnapshotTimestamp::new(2020, 1, 1, 0, 0, 0).unwrap());
+}
+
+#[test]
+fn test_base_version() {
+    for &(version, base) in &[
+        ("1.0-20050205.060708-1", "1.0-SNAPSHOT"),
+        ("1.0.0-beta-2-20240101.000000-15", "1.0.0-beta-2-SNAPSHOT"),
+        ("1.0-SNAPSHOT", "1.0-SNAPSHOT"),
+        ("1.0-snapshot", "1.0-snapshot"),
+        ("1.0", "1.0"),
+        ("1.0-20050205.060708", "1.0-20050205.060708"),
+    ] {
+        let comparable = ComparableVersion::new(version).base_version();
+        assert_eq!(comparable.as_str(), base, "{}", version);
+        assert_eq!(comparable, ComparableVersion::new(base));
+
+        let artifact = ArtifactVersion::new(version).base_version();
+        assert_eq!(artifact.as_str(), base, "{}", version);
+        assert_eq!(artifact, ArtifactVersion::new(base));
+    }
+
+    let artifact = ArtifactVersion::new("1.2.3-20050205.060708-1").base_version();
+    assert_eq!(
+        (artifact.major(), artifact.minor(), artifact.incremental()),
+        (1, 2, 3)
+    );
+    assert_eq!(artifact.qualifier().as_deref(), Some("SNAPSHOT"));
+}
+
+#[test]
+fn test_is_snapshot() {
+    for version in &[
+        "1.0-SNAPSHOT",
+        "1.0-snapshot",
+        "1.0-Snapshot",
+        "1.0SNAPSHOT",
+        "1.0.SNAPSHOT",
+        "SNAPSHOT",
+        "1.0-alpha-1-SNAPSHOT",
+        "1.0-20050205.060708-1",
+        "2.0-beta-1-20240101.235959-42",
+    ] {
+        assert!(ComparableVersion::new(version).is_snapshot(), "{}", version);
+        assert!(ArtifactVersion::new(version).is_snapshot(), "{}", version);
+    }
+
+    for version in &[
+        "1.0",
+        "1.0-SNAPSHOT-1",
+        "1.0-SNAPSHOTS",
+        "1.0-SNAPSHOT.1",
+        "1.0-20050205.060708",
+        "1.0-20050205-1",
+    ] {
+        assert!(
+            !ComparableVersion::new(version).is_snapshot(),
+            "{}",
+            version
+        );
+        assert!(!ArtifactVersion::new(version).is_snapshot(), "{}", version);
+    }
+}
@@ -0,0 +1,438 @@
+use itertools::Itertools;
+use mvn_version::{
+    BigUint, ComparableVersion, Decomposition, PaddedItem, Padding, Qualifier, VersionItem,
+    VersionSegment,
+};
+use std::cmp::Ordering;
+
+fn structure(version: &str) -> Vec<Vec<String>> {
+    ComparableVersion::new(version)
+        .segments()
+        .map(|s| s.items().map(|i| i.to_string()).collect())
+        .collect()
+}
+
+#[test]
+fn test_segments() {
+    assert_eq!(structure("1.2.3"), [["1", "2", "3"]]);
+    assert_eq!(structure("1.0.0"), [["1"]]);
+    assert_eq!(structure("1.0-0"), [["1"]]);
+    assert_eq!(
+        structure("1.0-beta-1"),
+        [vec!["1"], vec!["beta"], vec!["1"]]
+    );
+    assert_eq!(structure("1.0A1"), [vec!["1"], vec!["alpha"], vec!["1"]]);
+    assert_eq!(structure("1.0-CR2"), [vec!["1"], vec!["rc"], vec!["2"]]);
+    assert_eq!(structure("1-ga-1"), [vec!["1"], vec![], vec!["1"]]);
+    assert_eq!(structure("1-final.1"), [vec!["1"], vec!["", "1"]]);
+    assert_eq!(structure("foo.bar"), [["foo", "bar"]]);
+    assert!(structure("").is_empty());
+    assert!(structure("0.0").is_empty());
+
+    let version = ComparableVersion::new("1--2");
+    let segments = version.segments().collect::<Vec<_>>();
+    assert_eq!(segments.len(), 3);
+    assert!(segments[1].is_empty());
+    assert_eq!(segments[2].len(), 1);
+    assert_eq!(segments[0].to_string(), "1");
+}
+
+#[test]
+fn test_items() {
+    let version = ComparableVersion::new("1.1234567890-SNAPSHOT");
+    let items = version
+        .segments()
+        .flat_map(|s| s.items())
+        .collect::<Vec<_>>();
+    let big = "1234567890".parse::<BigUint>().unwrap();
+
+    assert_eq!(
+        items,
+        [
+            VersionItem::Int(1),
+            VersionItem::BigInt(&big),
+            VersionItem::Qualifier(Qualifier::Snapshot),
+        ]
+    );
+    assert!(items[0].is_numeric());
+    assert!(items[1].is_numeric());
+    assert!(!items[2].is_numeric());
+    assert_eq!(version.segments().len(), 2);
+    assert_eq!(
+        version.segments().next_back().unwrap().to_string(),
+        "snapshot"
+    );
+}
+
+#[test]
+fn test_qualifiers() {
+    fn qualifiers(version: &ComparableVersion) -> Vec<Qualifier<'_>> {
+        version
+            .segments()
+            .flat_map(|s| s.items())
+            .filter_map(|i| match i {
+                VersionItem::Qualifier(q) => Some(q),
+                _ => None,
+            })
+            .collect()
+    }
+
+    assert_eq!(
+        qualifiers(&ComparableVersion::new("1-a1-b2-m3-cr4-SNAPSHOT")),
+        [
+            Qualifier::Alpha,
+            Qualifier::Beta,
+            Qualifier::Milestone,
+            Qualifier::Rc,
+            Qualifier::Snapshot,
+        ]
+    );
+    assert_eq!(
+        qualifiers(&ComparableVersion::new("1-alpha-beta-milestone-rc-sp")),
+        [
+            Qualifier::Alpha,
+            Qualifier::Beta,
+            Qualifier::Milestone,
+            Qualifier::Rc,
+            Qualifier::Sp,
+        ]
+    );
+    assert_eq!(
+        qualifiers(&ComparableVersion::new("1-RELEASE.1-Final.1-ga.1")),
+        [Qualifier::Release, Qualifier::Release, Qualifier::Release]
+    );
+    assert_eq!(
+        qualifiers(&ComparableVersion::new("1-a-b-Foo")),
+        [
+            Qualifier::Other("a"),
+            Qualifier::Other("b"),
+            Qualifier::Other("foo")
+        ]
+    );
+
+    assert_eq!(Qualifier::Release.as_str(), "");
+    assert_eq!(Qualifier::Rc.to_string(), "rc");
+    assert_eq!(Qualifier::Other("foo").to_string(), "foo");
+}
+
+#[test]
+fn test_numeric_parts() {
+    fn parts(version: &str) -> Vec<u64> {
+        ComparableVersion::new(version).numeric_parts().collect()
+    }
+
+    assert_eq!(parts("1.2.3"), [1, 2, 3]);
+    assert_eq!(parts("1.0.0"), [1]);
+    assert_eq!(parts("1.0.1"), [1, 0, 1]);
+    assert_eq!(parts("1.2-3"), [1, 2]);
+    assert_eq!(parts("1.2RC1"), [1, 2]);
+    assert_eq!(parts("1.foo.2"), [1]);
+    assert_eq!(parts("foo-1"), Vec::<u64>::new());
+    assert_eq!(parts(""), Vec::<u64>::new());
+    assert_eq!(parts("1.2.3.200705301630"), [1, 2, 3, 200705301630]);
+    assert_eq!(parts("1.123456789012345678901234567890.3"), [1]);
+}
+
+#[test]
+fn test_truncate() {
+    fn truncate(version: &str, n: usize) -> String {
+        ComparableVersion::new(version).truncate(n).to_string()
+    }
+
+    assert_eq!(truncate("2.3.4-beta-1", 2), "2.3");
+    assert_eq!(truncate("2.3.4-beta-1", 3), "2.3.4");
+    assert_eq!(truncate("2.3.4-beta-1", 1), "2");
+    assert_eq!(truncate("2.3.4-beta-1", 0), "");
+    assert_eq!(truncate("1.0", 3), "1.0.0");
+    assert_eq!(truncate("1.0RC1", 2), "1.0");
+    assert_eq!(truncate("01.002.3", 2), "1.2");
+    assert_eq!(truncate("SNAPSHOT", 2), "0.0");
+    assert_eq!(
+        truncate("1.123456789012345678901234.3", 3),
+        "1.123456789012345678901234.3"
+    );
+    assert_eq!(
+        truncate("1.123456789012345678901234.3-beta", 2),
+        "1.123456789012345678901234"
+    );
+
+    let version = ComparableVersion::new("5.4.3.Final");
+    assert!(version.truncate(2) < version);
+    assert_eq!(version.truncate(3), version);
+}
+
+#[test]
+fn test_qualifier_ranking() {
+    let ascending = [
+        Qualifier::Alpha,
+        Qualifier::Beta,
+        Qualifier::Milestone,
+        Qualifier::Rc,
+        Qualifier::Snapshot,
+        Qualifier::Release,
+        Qualifier::Sp,
+        Qualifier::Other("bar"),
+        Qualifier::Other("foo"),
+    ];
+
+    for (i, a) in ascending.iter().enumerate() {
+        for (j, b) in ascending.iter().enumerate() {
+            assert_eq!(a.cmp(b), i.cmp(&j), "{:?} {:?}", a, b);
+        }
+        assert_eq!(a.rank() as usize, i.min(7));
+        assert_eq!(a.is_prerelease(), i < 5);
+    }
+
+    assert_eq!(Qualifier::KNOWN, ascending[..7]);
+
+    // The ranking agrees with how versions compare
+    for (a, b) in ascending.iter().zip(ascending.iter().skip(1)) {
+        let a = ComparableVersion::new(&format!("1-{}", a));
+        let b = ComparableVersion::new(&format!("1-{}", b));
+        assert!(a < b, "{} {}", a, b);
+    }
+}
+
+#[test]
+fn test_major_minor_patch() {
+    fn mmp(version: &str) -> (Option<u64>, Option<u64>, Option<u64>) {
+        let version = ComparableVersion::new(version);
+        (version.major(), version.minor(), version.patch())
+    }
+
+    assert_eq!(mmp("1.2.3"), (Some(1), Some(2), Some(3)));
+    assert_eq!(mmp("1.2.3.4"), (Some(1), Some(2), Some(3)));
+    assert_eq!(mmp("1.2"), (Some(1), Some(2), Some(0)));
+    assert_eq!(mmp("1.0.0"), (Some(1), Some(0), Some(0)));
+    assert_eq!(mmp("1-SNAPSHOT"), (Some(1), Some(0), Some(0)));
+    assert_eq!(mmp("2.0RC1"), (Some(2), Some(0), Some(0)));
+    assert_eq!(mmp("0"), (Some(0), Some(0), Some(0)));
+    assert_eq!(mmp(""), (Some(0), Some(0), Some(0)));
+    assert_eq!(mmp("1.foo.3"), (Some(1), None, None));
+    assert_eq!(mmp("foo"), (None, None, None));
+    assert_eq!(
+        mmp("1.2.123456789012345678901234567890"),
+        (Some(1), Some(2), None)
+    );
+    assert_eq!(mmp("20240101"), (Some(20240101), Some(0), Some(0)));
+}
+
+#[test]
+fn test_counts() {
+    fn counts(version: &str) -> (usize, usize, bool) {
+        let version = ComparableVersion::new(version);
+        (
+            version.segment_count(),
+            version.item_count(),
+            version.is_empty(),
+        )
+    }
+
+    assert_eq!(counts(""), (0, 0, true));
+    assert_eq!(counts("0.0-0"), (0, 0, true));
+    assert_eq!(counts("1.0-ga"), (1, 1, false));
+    assert_eq!(counts("1.2.3"), (1, 3, false));
+    assert_eq!(counts("1.2-beta-3"), (3, 4, false));
+    assert_eq!(counts("1--2"), (3, 2, false));
+    assert_eq!(counts("20240101"), (1, 1, false));
+}
+
+#[test]
+fn test_qualifier_chain() {
+    fn chain(version: &ComparableVersion) -> Vec<(Qualifier<'_>, Option<u64>)> {
+        version.qualifiers().collect()
+    }
+
+    assert_eq!(chain(&ComparableVersion::new("1.2.3")), []);
+    assert_eq!(
+        chain(&ComparableVersion::new("1.0-alpha-1-SNAPSHOT")),
+        [(Qualifier::Alpha, Some(1)), (Qualifier::Snapshot, None)]
+    );
+    assert_eq!(
+        chain(&ComparableVersion::new("1.0RC2.foo")),
+        [(Qualifier::Rc, Some(2)), (Qualifier::Other("foo"), None)]
+    );
+    assert_eq!(
+        chain(&ComparableVersion::new("1.0-m.3-sp")),
+        [(Qualifier::Other("m"), Some(3)), (Qualifier::Sp, None)]
+    );
+    assert_eq!(
+        chain(&ComparableVersion::new("1.ga.1-b-c")),
+        [
+            (Qualifier::Release, Some(1)),
+            (Qualifier::Other("b"), None),
+            (Qualifier::Other("c"), None)
+        ]
+    );
+}
+
+#[test]
+fn test_decompose() {
+    fn decompose(version: &str) -> (Vec<u64>, Vec<String>, Option<u64>) {
+        let version = ComparableVersion::new(version);
+        let Decomposition {
+            core,
+            qualifiers,
+            build,
+        } = version.decompose();
+        let qualifiers = qualifiers
+            .iter()
+            .map(|(q, n)| match n {
+                Some(n) => format!("{}{}", q, n),
+                None => q.to_string(),
+            })
+            .collect();
+        (core, qualifiers, build)
+    }
+
+    assert_eq!(decompose("1.2.3"), (vec![1, 2, 3], vec![], None));
+    assert_eq!(decompose("1.2.3-4"), (vec![1, 2, 3], vec![], Some(4)));
+    assert_eq!(decompose("1.2.3.4"), (vec![1, 2, 3, 4], vec![], None));
+    assert_eq!(
+        decompose("1.0-alpha-1"),
+        (vec![1], vec!["alpha1".to_string()], None)
+    );
+    assert_eq!(
+        decompose("1.0-alpha-1-2"),
+        (vec![1], vec!["alpha1".to_string()], Some(2))
+    );
+    assert_eq!(
+        decompose("1.0-SNAPSHOT"),
+        (vec![1], vec!["snapshot".to_string()], None)
+    );
+    assert_eq!(
+        decompose("1.0-foo-2-SNAPSHOT"),
+        (
+            vec![1],
+            vec!["foo2".to_string(), "snapshot".to_string()],
+            None
+        )
+    );
+    assert_eq!(decompose("1-2-3"), (vec![1], vec![], Some(3)));
+    assert_eq!(decompose(""), (vec![], vec![], None));
+}
+
+#[test]
+fn test_explain_parse() {
+    fn explain(version: &str) -> String {
+        ComparableVersion::new(version).explain_parse()
+    }
+
+    assert_eq!(explain("1.0"), "[[1]]");
+    assert_eq!(explain("1.0-1"), "[[1], [1]]");
+    assert_eq!(explain("1.0-foo"), "[[1], [foo]]");
+    assert_eq!(explain("1.0foo"), "[[1], [foo]]");
+    assert_eq!(explain("1--2"), "[[1], [], [2]]");
+    assert_eq!(explain("1.final.1-CR2"), "[[1, \"\", 1], [rc], [2]]");
+    assert_eq!(explain(""), "[]");
+}
+
+#[test]
+fn test_same_major_minor() {
+    fn same(a: &str, b: &str) -> (bool, bool) {
+        let (a, b) = (ComparableVersion::new(a), ComparableVersion::new(b));
+        assert_eq!(a.same_major(&b), b.same_major(&a));
+        assert_eq!(a.same_minor(&b), b.same_minor(&a));
+        (a.same_major(&b), a.same_minor(&b))
+    }
+
+    assert_eq!(same("1", "1.0.3"), (true, true));
+    assert_eq!(same("1.2.3", "1.2.4-SNAPSHOT"), (true, true));
+    assert_eq!(same("1.2", "1.3"), (true, false));
+    assert_eq!(same("1-beta", "1.0.1"), (true, true));
+    assert_eq!(same("2.0RC1", "2.1"), (true, false));
+    assert_eq!(same("1", "2"), (false, false));
+    assert_eq!(same("1.foo", "1"), (true, false));
+    assert_eq!(same("foo", "foo"), (false, false));
+    assert_eq!(same("", "0.0.1"), (true, true));
+    assert_eq!(
+        same(
+            "123456789012345678901234567890.1",
+            "123456789012345678901234567890.1.5"
+        ),
+        (true, true)
+    );
+}
+
+#[test]
+fn test_padding() {
+    // A comparison built on nothing but the public segment API must agree with the real one
+    fn cmp_items(a: VersionItem, b: VersionItem) -> Ordering {
+        match (a, b) {
+            (VersionItem::Int(i), VersionItem::Int(j)) => i.cmp(&j),
+            (VersionItem::BigInt(i), VersionItem::BigInt(j)) => i.cmp(j),
+            (VersionItem::Qualifier(q), VersionItem::Qualifier(r)) => q.cmp(&r),
+            (VersionItem::Int(_), VersionItem::BigInt(_)) => Ordering::Less,
+            (VersionItem::BigInt(_), VersionItem::Int(_)) => Ordering::Greater,
+            (VersionItem::Qualifier(_), _) => Ordering::Less,
+            (_, VersionItem::Qualifier(_)) => Ordering::Greater,
+        }
+    }
+
+    fn cmp_segments(a: VersionSegment, b: VersionSegment) -> Ordering {
+        a.padded_iter()
+            .zip(b.padded_iter())
+            .take(a.len().max(b.len()))
+            .map(|pair| match pair {
+                (PaddedItem::Item(i), PaddedItem::Item(j)) => cmp_items(i, j),
+                (PaddedItem::Item(i), PaddedItem::Padding(p)) => i.cmp_padding(p),
+                (PaddedItem::Padding(p), PaddedItem::Item(j)) => j.cmp_padding(p).reverse(),
+                (PaddedItem::Padding(_), PaddedItem::Padding(_)) => unreachable!(),
+            })
+            .find(|order| *order != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }
+
+    fn cmp_versions(a: &ComparableVersion, b: &ComparableVersion) -> Ordering {
+        let null = |s: VersionSegment| {
+            s.items()
+                .map(|i| i.cmp_padding(Padding::Null))
+                .find(|order| *order != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        };
+
+        a.segments()
+            .zip_longest(b.segments())
+            .map(|pair| match pair {
+                itertools::EitherOrBoth::Both(s, t) => cmp_segments(s, t),
+                itertools::EitherOrBoth::Left(s) => null(s),
+                itertools::EitherOrBoth::Right(t) => null(t).reverse(),
+            })
+            .find(|order| *order != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }
+
+    let versions = [
+        "1",
+        "1-1",
+        "1.1",
+        "1-1-snapshot",
+        "1-1.snapshot",
+        "1-1-foo",
+        "1-1.1",
+        "1-1-1",
+        "1.snapshot",
+        "1-snapshot",
+        "1-sp",
+        "1.sp",
+        "1-foo-1",
+        "1-foo",
+        "1.0.0-0.1",
+        "2-rc-1",
+        "2.rc.1",
+        "2rc",
+        "1-99999999999999999999",
+        "1.99999999999999999999",
+        "",
+        "1-ga-1",
+    ]
+    .iter()
+    .map(|s| ComparableVersion::new(s))
+    .collect::<Vec<_>>();
+
+    for a in &versions {
+        for b in &versions {
+            assert_eq!(cmp_versions(a, b), a.cmp(b), "{} {}", a, b);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use mvn_version::{ComparableVersion, TokenKind};
+
+fn tokens(version: &str) -> Vec<(TokenKind, &str)> {
+    // The spans must line up with the original string
+    let parsed = ComparableVersion::new(version);
+    parsed
+        .tokens()
+        .map(|t| {
+            assert_eq!(&version[t.span()], t.as_str());
+            (t.kind(), &version[t.span()])
+        })
+        .collect()
+}
+
+#[test]
+fn test_tokens() {
+    use TokenKind::*;
+
+    assert!(tokens("").is_empty());
+    assert_eq!(
+        tokens("1.2.3-SNAPSHOT"),
+        [
+            (Number, "1"),
+            (Dot, "."),
+            (Number, "2"),
+            (Dot, "."),
+            (Number, "3"),
+            (Dash, "-"),
+            (Qualifier, "SNAPSHOT"),
+        ]
+    );
+    assert_eq!(
+        tokens("1..-final007"),
+        [
+            (Number, "1"),
+            (Dot, "."),
+            (Dot, "."),
+            (Dash, "-"),
+            (Qualifier, "final"),
+            (Number, "007"),
+        ]
+    );
+    assert_eq!(
+        tokens("-Ünïcode2"),
+        [(Dash, "-"), (Qualifier, "Ünïcode"), (Number, "2")]
+    );
+}
+
+#[test]
+fn test_tokens_lossless() {
+    for version in &[
+        "1.0",
+        "1.0.0-alpha-1",
+        "1.0A1",
+        "  1 . 0 ",
+        "1.0-İ.ẞ-x",
+        ".-.",
+    ] {
+        let parsed = ComparableVersion::new(version);
+        let joined = parsed.tokens().map(|t| t.as_str()).collect::<String>();
+        assert_eq!(&joined, version);
+
+        let mut end = 0;
+        for token in parsed.tokens() {
+            assert_eq!(token.span().start, end);
+            assert_eq!(
+                token.is_separator(),
+                token.as_str() == "." || token.as_str() == "-"
+            );
+            end = token.span().end;
+        }
+        assert_eq!(end, version.len());
+    }
+}
+
+#[test]
+fn test_separators() {
+    use mvn_version::Separator::{self, *};
+
+    fn separators(version: &str) -> Vec<Separator> {
+        ComparableVersion::new(version).separators().collect()
+    }
+
+    assert!(separators("").is_empty());
+    assert!(separators("1").is_empty());
+    assert_eq!(separators("1.2.3-SNAPSHOT"), [Dot, Dot, Dash]);
+    assert_eq!(separators("1.0RC1"), [Dot, Transition, Transition]);
+    assert_eq!(separators("1..-final007"), [Dot, Dot, Dash, Transition]);
+    assert_eq!(separators("-Ünïcode2"), [Dash, Transition]);
+
+    let joined = separators("1.0-b2")
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<String>();
+    assert_eq!(joined, ".-");
+}
+
+#[test]
+fn test_ascii_and_unicode_lexing_agree() {
+    // Prefixing a non-ASCII qualifier forces the whole string down the Unicode path
+    let prefix = "\u{e9}-";
+
+    for version in [
+        "1.2.3-SNAPSHOT",
+        "1..-final007",
+        "1.0RC1",
+        "-.1-",
+        "a1b2c3",
+        "1.0.0-alpha-10.beta",
+    ] {
+        let ascii = ComparableVersion::new(version);
+        let unicode = ComparableVersion::new(&format!("{}{}", prefix, version));
+        let shifted = unicode
+            .tokens()
+            .skip(2)
+            .map(|t| (t.kind(), t.as_str(), t.span().start - prefix.len()))
+            .collect::<Vec<_>>();
+        let expected = ascii
+            .tokens()
+            .map(|t| (t.kind(), t.as_str(), t.span().start))
+            .collect::<Vec<_>>();
+
+        assert_eq!(shifted, expected, "{}", version);
+    }
+}
@@ -0,0 +1,44 @@
+use mvn_version::{ComparableVersion, UnknownOrder, VersionOrUnknown};
+use std::cmp::Ordering;
+
+#[test]
+fn test_order() {
+    let unknown = VersionOrUnknown::Unknown;
+    let old = VersionOrUnknown::from("1.0");
+    let new = VersionOrUnknown::from("1.1");
+
+    assert!(unknown < old && old < new);
+    assert_eq!(VersionOrUnknown::from("1.0.0"), old);
+
+    assert_eq!(unknown.cmp_with(&old, UnknownOrder::First), Ordering::Less);
+    assert_eq!(
+        unknown.cmp_with(&old, UnknownOrder::Last),
+        Ordering::Greater
+    );
+    assert_eq!(
+        old.cmp_with(&unknown, UnknownOrder::First),
+        Ordering::Greater
+    );
+    assert_eq!(old.cmp_with(&unknown, UnknownOrder::Last), Ordering::Less);
+    assert_eq!(old.cmp_with(&new, UnknownOrder::Last), Ordering::Less);
+    assert_eq!(
+        unknown.cmp_with(&VersionOrUnknown::Unknown, UnknownOrder::Last),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn test_conversions() {
+    let known = VersionOrUnknown::from(Some(ComparableVersion::new("1.0")));
+    assert!(known.is_known());
+    assert_eq!(known.known().unwrap().as_str(), "1.0");
+    assert_eq!(
+        Option::<ComparableVersion>::from(known),
+        Some(ComparableVersion::new("1.0"))
+    );
+
+    let unknown = VersionOrUnknown::from(None);
+    assert!(!unknown.is_known());
+    assert_eq!(unknown, VersionOrUnknown::default());
+    assert_eq!(Option::<ComparableVersion>::from(unknown), None);
+}
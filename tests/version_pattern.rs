@@ -0,0 +1,72 @@
+use mvn_version::{ComparableVersion, VersionPattern};
+
+fn check_matches(pattern: &str, version: &str, expected: bool) {
+    let p = VersionPattern::new(pattern).unwrap();
+    assert_eq!(
+        p.matches(&ComparableVersion::new(version)),
+        expected,
+        "{:?} matches {:?}",
+        pattern,
+        version
+    );
+}
+
+#[test]
+fn test_numeric_prefix() {
+    check_matches("1.*", "1", true);
+    check_matches("1.*", "1.0", true);
+    check_matches("1.*", "1.9.3", true);
+    check_matches("1.*", "1-SNAPSHOT", true);
+    check_matches("1.*", "10.0", false);
+    check_matches("1.*", "2.0", false);
+
+    check_matches("1.2.*", "1.2", true);
+    check_matches("1.2.*", "1.2.0", true);
+    check_matches("1.2.*", "1.2.5-beta-1", true);
+    check_matches("1.2.*", "1.20", false);
+    check_matches("1.2.*", "1", false);
+    check_matches("1.2.*", "1-2", false);
+
+    check_matches("1.0.*", "1.0.4", true);
+    check_matches("1.0.*", "1", true);
+    check_matches("1.0.*", "1.5", false);
+    check_matches("01.*", "1.3", true);
+
+    check_matches("*", "anything", true);
+}
+
+#[test]
+fn test_suffix() {
+    check_matches("*-SNAPSHOT", "1.0-SNAPSHOT", true);
+    check_matches("*-SNAPSHOT", "1.0-alpha-1-snapshot", true);
+    check_matches("*-SNAPSHOT", "1.0", false);
+    check_matches("*-SNAPSHOT", "1.0-SNAPSHOT-1", false);
+    check_matches("*-beta-1", "2.0b1", true);
+    check_matches("1.*-SNAPSHOT", "1.4-SNAPSHOT", true);
+    check_matches("1.*-SNAPSHOT", "2.4-SNAPSHOT", false);
+    check_matches("1.*-SNAPSHOT", "1.4", false);
+}
+
+#[test]
+fn test_exact_and_invalid() {
+    check_matches("1.2", "1.2.0", true);
+    check_matches("1.2", "1.2.1", false);
+
+    let offset = |s: &str| VersionPattern::new(s).unwrap_err().offset();
+    assert_eq!(offset("1.*.*"), 4);
+    assert_eq!(offset("1*"), 1);
+    assert_eq!(offset("1.a.*"), 2);
+    assert_eq!(offset("1..*"), 2);
+    assert_eq!(offset("1.*.3"), 3);
+
+    assert_eq!(VersionPattern::new("1.2.*").unwrap().to_string(), "1.2.*");
+}
+
+#[test]
+fn test_equality() {
+    let pattern = |s| VersionPattern::new(s).unwrap();
+    assert_eq!(pattern("1.2.*"), pattern("1.2.*"));
+    assert_eq!(pattern("*-SNAPSHOT"), pattern("*-SNAPSHOT"));
+    assert_ne!(pattern("1.2.*"), pattern("1.3.*"));
+    assert_ne!(pattern("1.2.*"), pattern("1.2"));
+}
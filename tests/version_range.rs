@@ -0,0 +1,638 @@
+//! Partially lifted from Maven's test cases.
+
+use mvn_version::{
+    ArtifactVersion, ComparableVersion, RangeParseErrorKind, RejectList, Restriction, VersionRange,
+};
+
+fn check_contains(spec: &str, version: &str, expected: bool) {
+    let range = VersionRange::new(spec).unwrap();
+    assert_eq!(
+        range.contains(&ComparableVersion::new(version)),
+        expected,
+        "{:?} contains {:?}",
+        range,
+        version
+    );
+}
+
+fn check_invalid(spec: &str, kind: RangeParseErrorKind, offset: usize) {
+    let err = VersionRange::new(spec).unwrap_err();
+    assert_eq!(err.kind(), kind, "{:?}", spec);
+    assert_eq!(err.offset(), offset, "{:?}", spec);
+
+    // Strict parsing rejects a superset of what Maven does
+    assert!(VersionRange::new_strict(spec).is_err(), "{:?}", spec);
+}
+
+fn check_invalid_strict(spec: &str, kind: RangeParseErrorKind, offset: usize) {
+    let err = VersionRange::new_strict(spec).unwrap_err();
+    assert_eq!(err.kind(), kind, "{:?}", spec);
+    assert_eq!(err.offset(), offset, "{:?}", spec);
+}
+
+#[test]
+fn test_single_restriction() {
+    check_contains("1.0", "0.5", true);
+    check_contains("1.0", "1.0", true);
+    check_contains("1.0", "3.0", true);
+
+    check_contains("[1.0]", "1.0", true);
+    check_contains("[1.0]", "1.0.0", true);
+    check_contains("[1.0]", "1.1", false);
+
+    check_contains("[1.0,2.0)", "1.0", true);
+    check_contains("[1.0,2.0)", "1.5", true);
+    check_contains("[1.0,2.0)", "2.0", false);
+    check_contains("[1.0,2.0)", "2.0-SNAPSHOT", true);
+    check_contains("(1.0,2.0]", "1.0", false);
+    check_contains("(1.0,2.0]", "2.0", true);
+
+    check_contains("(,1.0]", "0.1", true);
+    check_contains("(,1.0]", "1.0", true);
+    check_contains("(,1.0]", "1.1", false);
+    check_contains("[1.0,)", "0.9", false);
+    check_contains("[1.0,)", "100", true);
+    check_contains("( 1.0 , 2.0 )", "1.5", true);
+}
+
+#[test]
+fn test_multiple_restrictions() {
+    let range = VersionRange::new("[1.0,1.5),(1.5,2.0]").unwrap();
+    assert_eq!(range.restrictions().len(), 2);
+
+    check_contains("[1.0,1.5),(1.5,2.0]", "1.0", true);
+    check_contains("[1.0,1.5),(1.5,2.0]", "1.4", true);
+    check_contains("[1.0,1.5),(1.5,2.0]", "1.5", false);
+    check_contains("[1.0,1.5),(1.5,2.0]", "1.5.0", false);
+    check_contains("[1.0,1.5),(1.5,2.0]", "1.5.1", true);
+    check_contains("[1.0,1.5),(1.5,2.0]", "2.0", true);
+    check_contains("[1.0,1.5),(1.5,2.0]", "2.1", false);
+
+    check_contains("(,1.0],[1.2,)", "1.1", false);
+    check_contains("(,1.0],[1.2,)", "0.9", true);
+    check_contains("(,1.0],[1.2,)", "3", true);
+    check_contains("[1.0], [1.2], [1.4]", "1.2", true);
+    check_contains("[1.0], [1.2], [1.4]", "1.3", false);
+}
+
+#[test]
+fn test_invalid_specs() {
+    use RangeParseErrorKind::*;
+
+    check_invalid("[1.0", UnbalancedBracket, 0);
+    check_invalid("[1.0,2.0],(1.0", UnbalancedBracket, 10);
+    check_invalid("(1.0)", SingleVersionNotInclusive, 0);
+    check_invalid("[2.0,1.0]", BoundsOutOfOrder, 0);
+    check_invalid("[0.5], [1.0,1.0)", BoundsOutOfOrder, 7);
+    check_invalid("[1.0,1.5],[1.2,2.0]", RangesOverlap, 10);
+    check_invalid("[1.5,2.0],[1.0,1.2]", RangesOverlap, 10);
+    check_invalid("[1.0,2.0],1.5", RecommendedVersionWithRanges, 10);
+    check_invalid("[1.0]]", RecommendedVersionWithRanges, 5);
+    check_invalid("[1.0,[2.0]", BoundsOutOfOrder, 0);
+
+    check_invalid_strict("[1.0,[2.0]", UnbalancedBracket, 5);
+    check_invalid_strict("1.0]", UnbalancedBracket, 3);
+    check_invalid_strict("[1.0]]", UnbalancedBracket, 5);
+    check_invalid_strict("[]", EmptyVersion, 0);
+    check_invalid_strict("[1.0,2.0,3.0]", TooManyBounds, 8);
+    check_invalid_strict("[1.0,),[2.0,3.0]", RangesOverlap, 7);
+    check_invalid_strict("1.5, [1.0,2.0]", RecommendedVersionWithRanges, 5);
+    check_invalid_strict("1.0,1.5", DuplicateRecommendedVersion, 4);
+}
+
+#[test]
+fn test_maven_accepts_what_strict_parsing_rejects() {
+    // A bracket inside a restriction is part of a bound, which sorts below any number
+    let range = VersionRange::new("[[1.0,2.0]").unwrap();
+    check_restriction(
+        &range.restrictions()[0],
+        Some("[1.0"),
+        true,
+        Some("2.0"),
+        true,
+    );
+
+    // A bare version may contain anything
+    for spec in &["1.0]", "1.0,1.5", "1.5, [1.0,2.0]", "1.0)"] {
+        let range = VersionRange::new(spec).unwrap();
+        assert_eq!(range.recommended_version().unwrap().as_str(), *spec);
+        assert_eq!(range.restrictions(), &[Restriction::EVERYTHING]);
+    }
+
+    // An empty single version
+    let range = VersionRange::new("[]").unwrap();
+    check_restriction(&range.restrictions()[0], Some(""), true, Some(""), true);
+    assert!(range.contains(&ComparableVersion::new("")));
+    assert!(!range.contains(&ComparableVersion::new("1.0")));
+
+    // Everything after the first comma is the upper bound
+    let range = VersionRange::new("[1.0,2.0,3.0]").unwrap();
+    check_restriction(
+        &range.restrictions()[0],
+        Some("1.0"),
+        true,
+        Some("2.0,3.0"),
+        true,
+    );
+
+    // Nothing is checked after a restriction which is unbounded from above
+    let range = VersionRange::new("[1.0,),[2.0,3.0]").unwrap();
+    assert_eq!(range.restrictions().len(), 2);
+    let range = VersionRange::new("(,),[0.5,1.0],[1.5,2.0]").unwrap();
+    assert_eq!(range.restrictions().len(), 3);
+    assert!(range.contains(&ComparableVersion::new("5.0")));
+
+    // Whatever Maven accepts still prints in a form which parses the same way
+    for spec in &[
+        "[[1.0,2.0]",
+        "1.0,1.5",
+        "1.5, [1.0,2.0]",
+        "[]",
+        "[1.0,2.0,3.0]",
+        "[1.0,),[2.0,3.0]",
+    ] {
+        let range = VersionRange::new(spec).unwrap();
+        assert_eq!(
+            VersionRange::new(&range.to_string()).unwrap(),
+            range,
+            "{}",
+            spec
+        );
+    }
+}
+
+#[test]
+fn test_error_display() {
+    let err = VersionRange::new("[1.0,1.5],[1.2,2.0]").unwrap_err();
+    assert_eq!(err.to_string(), "ranges overlap at byte 10");
+}
+
+#[test]
+fn test_display_round_trip() {
+    let specs = vec![
+        ("[1.0]", "[1.0]"),
+        ("[1.0,1.0]", "[1.0]"),
+        ("[ 1.0 , 2.0 )", "[1.0,2.0)"),
+        ("(,1.0],[1.2,)", "(,1.0],[1.2,)"),
+        ("[1.0,1.5) , (1.5,2.0]", "[1.0,1.5),(1.5,2.0]"),
+        ("[1.0], [1.2], [1.4]", "[1.0],[1.2],[1.4]"),
+        ("(,)", "(,)"),
+        ("1.0", "1.0"),
+        ("1.0-SNAPSHOT", "1.0-SNAPSHOT"),
+        ("[1.0-alpha-1,1.0-SNAPSHOT]", "[1.0-alpha-1,1.0-SNAPSHOT]"),
+        ("", ""),
+    ];
+
+    for (spec, canonical) in specs {
+        let range = VersionRange::new(spec).unwrap();
+        assert_eq!(range.to_string(), canonical, "{:?}", spec);
+
+        let reparsed = VersionRange::new(&range.to_string()).unwrap();
+        assert_eq!(reparsed, range, "{:?}", spec);
+        assert_eq!(reparsed.to_string(), canonical, "{:?}", spec);
+    }
+}
+
+#[test]
+fn test_soft_and_hard_requirements() {
+    let soft = VersionRange::new("1.0").unwrap();
+    assert!(soft.is_soft());
+    assert_eq!(
+        soft.recommended_version(),
+        Some(&ComparableVersion::new("1.0"))
+    );
+    assert!(soft.contains(&ComparableVersion::new("2.0")));
+
+    let hard = VersionRange::new("[1.0]").unwrap();
+    assert!(!hard.is_soft());
+    assert_eq!(hard.recommended_version(), None);
+    assert!(!hard.contains(&ComparableVersion::new("2.0")));
+
+    assert_ne!(soft, hard);
+    assert_ne!(soft, VersionRange::new("(,)").unwrap());
+    assert_eq!(soft, VersionRange::new("1.0.0").unwrap());
+
+    let padded = VersionRange::new(" 1.0 ").unwrap();
+    assert_eq!(padded.recommended_version().unwrap().as_str(), "1.0");
+    assert_eq!(padded.to_string(), "1.0");
+    assert_eq!(
+        VersionRange::new("  ").unwrap(),
+        VersionRange::new("").unwrap()
+    );
+}
+
+#[test]
+fn test_reject_list() {
+    let rules: RejectList = vec!["[2.0-beta9,2.17.0)", "2.17.2", "[3.0,)"]
+        .into_iter()
+        .map(|s| VersionRange::new(s).unwrap())
+        .collect();
+
+    let rule_for = |v: &str| {
+        rules
+            .matching_rule(&ComparableVersion::new(v))
+            .map(|(i, _)| i)
+    };
+
+    assert_eq!(rule_for("1.2.17"), None);
+    assert_eq!(rule_for("2.0-beta9"), Some(0));
+    assert_eq!(rule_for("2.14.1"), Some(0));
+    assert_eq!(rule_for("2.17.0"), None);
+    assert_eq!(rule_for("2.17.1"), None);
+    assert_eq!(rule_for("2.17.2"), Some(1));
+    assert_eq!(rule_for("2.17.2.0"), Some(1));
+    assert_eq!(rule_for("3.0-alpha1"), None);
+    assert_eq!(rule_for("3.1"), Some(2));
+
+    let candidates = vec!["1.2.17", "2.14.1", "2.17.1", "2.17.2", "2.18.0", "3.0"]
+        .into_iter()
+        .map(ComparableVersion::new)
+        .collect::<Vec<_>>();
+    let accepted = rules
+        .filter(&candidates)
+        .map(|v| v.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(accepted, vec!["1.2.17", "2.17.1", "2.18.0"]);
+}
+
+#[test]
+fn test_covering() {
+    let covering = |versions: &[&str]| {
+        VersionRange::covering(versions.iter().map(|v| ComparableVersion::new(v)))
+            .map(|r| r.to_string())
+    };
+
+    assert_eq!(covering(&[]), None);
+    assert_eq!(covering(&["1.0"]), Some("[1.0]".to_string()));
+    assert_eq!(covering(&["1.0", "1", "1.0.0"]), Some("[1.0]".to_string()));
+    assert_eq!(
+        covering(&["2.0", "1.0-SNAPSHOT", "1.5", "2.0-rc1"]),
+        Some("[1.0-SNAPSHOT,2.0]".to_string())
+    );
+
+    let versions = vec!["3.1", "2.9", "3.0-alpha-1"]
+        .into_iter()
+        .map(ComparableVersion::new)
+        .collect::<Vec<_>>();
+    let range = VersionRange::covering(&versions).unwrap();
+    assert!(versions.iter().all(|v| range.contains(v)));
+    assert!(!range.is_soft());
+}
+
+#[test]
+fn test_complement() {
+    let complement = |spec: &str| VersionRange::new(spec).unwrap().complement().to_string();
+
+    assert_eq!(complement("[1.0,2.0)"), "(,1.0),[2.0,)");
+    assert_eq!(complement("(1.0,2.0]"), "(,1.0],(2.0,)");
+    assert_eq!(complement("[1.0]"), "(,1.0),(1.0,)");
+    assert_eq!(complement("(,1.0]"), "(1.0,)");
+    assert_eq!(complement("[1.0,)"), "(,1.0)");
+    assert_eq!(complement("(,1.0],[1.2,)"), "(1.0,1.2)");
+    assert_eq!(complement("[1.0,1.5),[1.5,2.0]"), "(,1.0),(2.0,)");
+    assert_eq!(complement("[1.0,1.5),(1.5,2.0]"), "(,1.0),[1.5],(2.0,)");
+    assert_eq!(complement("(,)"), "");
+    assert_eq!(complement("1.0"), "");
+    assert_eq!(complement(""), "(,)");
+
+    for spec in &["[1.0,1.5),(1.5,2.0]", "(,1.0],[1.2,)", "[1.0]"] {
+        let range = VersionRange::new(spec).unwrap();
+        let complement = range.complement();
+        assert_eq!(complement.complement(), range);
+
+        for v in &[
+            "0.1", "1.0", "1.1", "1.2", "1.5", "1.5.1", "2.0", "2.0.1", "3",
+        ] {
+            let v = ComparableVersion::new(v);
+            assert_ne!(
+                range.contains(&v),
+                complement.contains(&v),
+                "{} {}",
+                spec,
+                v
+            );
+        }
+    }
+}
+
+#[test]
+fn test_from_semver() {
+    let semver = |s: &str| VersionRange::from_semver(s).unwrap().to_string();
+
+    assert_eq!(semver("^1.2.3"), "[1.2.3,2.0.0)");
+    assert_eq!(semver("^1.2"), "[1.2,2.0)");
+    assert_eq!(semver("^1"), "[1,2)");
+    assert_eq!(semver("^0.2.3"), "[0.2.3,0.3.0)");
+    assert_eq!(semver("^0.0.3"), "[0.0.3,0.0.4)");
+    assert_eq!(semver("^0.0"), "[0.0,0.1)");
+    assert_eq!(semver("^0"), "[0,1)");
+    assert_eq!(semver("^1.2.3-beta-1"), "[1.2.3-beta-1,2.0.0)");
+    assert_eq!(semver("^1.2.3.RELEASE"), "[1.2.3.RELEASE,2.0.0)");
+    assert_eq!(semver("^1.2.3RC1"), "[1.2.3RC1,2.0.0)");
+
+    assert_eq!(semver("~1.2.3"), "[1.2.3,1.3.0)");
+    assert_eq!(semver("~1.2"), "[1.2,1.3)");
+    assert_eq!(semver("~1"), "[1,2)");
+    assert_eq!(semver("~0.2.3"), "[0.2.3,0.3.0)");
+    assert_eq!(semver("~5.3.Final"), "[5.3.Final,5.4)");
+
+    let range = VersionRange::from_semver("^1.2.3").unwrap();
+    assert!(range.contains(&ComparableVersion::new("1.9.9")));
+    assert!(!range.contains(&ComparableVersion::new("1.2.2")));
+    assert!(!range.contains(&ComparableVersion::new("2.0.0")));
+
+    let err = |s: &str| {
+        let err = VersionRange::from_semver(s).unwrap_err();
+        assert_eq!(err.kind(), RangeParseErrorKind::InvalidRequirement);
+        err.offset()
+    };
+    assert_eq!(err("1.2.3"), 0);
+    assert_eq!(err("^"), 1);
+    assert_eq!(err("^1..2"), 3);
+    assert_eq!(err("^1.2."), 5);
+    assert_eq!(err("^1..RELEASE"), 3);
+    assert_eq!(err("^18446744073709551615"), 1);
+    assert_eq!(err("~1.18446744073709551615.3"), 3);
+    assert_eq!(
+        semver("^0.18446744073709551614"),
+        "[0.18446744073709551614,0.18446744073709551615)"
+    );
+    // Components which aren't bumped may be as large as they like
+    assert_eq!(
+        semver("~1.2.18446744073709551615"),
+        "[1.2.18446744073709551615,1.3.0)"
+    );
+    assert_eq!(err("~beta"), 1);
+}
+
+#[test]
+fn test_clamp() {
+    let clamp = |spec: &str, v: &str| {
+        VersionRange::new(spec)
+            .unwrap()
+            .clamp(&ComparableVersion::new(v))
+            .map(|v| v.to_string())
+    };
+
+    assert_eq!(clamp("[1.0,2.0)", "1.5").as_deref(), Some("1.5"));
+    assert_eq!(clamp("[1.0,2.0)", "0.1").as_deref(), Some("1.0"));
+    assert_eq!(clamp("[1.0,2.0)", "3.0").as_deref(), Some("2.0"));
+    assert_eq!(clamp("(1.0,2.0)", "1.0").as_deref(), Some("1.0"));
+    assert_eq!(clamp("(,2.0]", "2.1").as_deref(), Some("2.0"));
+    assert_eq!(clamp("[2.0,)", "1.9").as_deref(), Some("2.0"));
+    assert_eq!(clamp("[1.0,1.5),(1.5,2.0]", "1.5").as_deref(), Some("1.5"));
+    assert_eq!(clamp("(,1.0],[1.2,)", "1.1").as_deref(), Some("1.2"));
+    assert_eq!(clamp("1.0", "7.0").as_deref(), Some("7.0"));
+    assert_eq!(clamp("", "1.0"), None);
+}
+
+#[test]
+fn test_widen() {
+    let widen = |spec: &str, v: &str| {
+        let mut range = VersionRange::new(spec).unwrap();
+        let v = ComparableVersion::new(v);
+        range.widen(&v);
+        assert!(range.contains(&v), "{} {}", spec, v);
+        range.to_string()
+    };
+
+    assert_eq!(widen("[1.0,2.0)", "1.5"), "[1.0,2.0)");
+    assert_eq!(widen("[1.0,2.0)", "0.5"), "[0.5,2.0)");
+    assert_eq!(widen("[1.0,2.0)", "2.0"), "[1.0,2.0]");
+    assert_eq!(widen("(1.0,2.0)", "1.0"), "[1.0,2.0)");
+    assert_eq!(widen("[1.0,2.0)", "3.0"), "[1.0,3.0]");
+    assert_eq!(widen("(,1.0],[1.2,)", "1.1"), "(,1.1],[1.2,)");
+    assert_eq!(widen("[1.0,1.5),(1.5,2.0]", "1.5"), "[1.0,1.5],(1.5,2.0]");
+    assert_eq!(widen("[1.0],[3.0],[5.0]", "4.0"), "[1.0],[3.0,4.0],[5.0]");
+    assert_eq!(widen("1.0", "2.0"), "1.0");
+    assert_eq!(widen("", "2.0"), "[2.0]");
+}
+
+#[test]
+fn test_normalize() {
+    let normalize = |restrictions: &[&str]| {
+        let mut range = VersionRange::from_restrictions(
+            restrictions.iter().map(|r| r.parse().unwrap()).collect(),
+        );
+        range.normalize();
+        range.to_string()
+    };
+
+    assert_eq!(normalize(&["[1.0,2.0]", "[1.5,3.0)"]), "[1.0,3.0)");
+    assert_eq!(
+        normalize(&["[3.0,4.0]", "[1.0,2.0]"]),
+        "[1.0,2.0],[3.0,4.0]"
+    );
+    assert_eq!(normalize(&["[1.0,1.5)", "[1.5,2.0]"]), "[1.0,2.0]");
+    assert_eq!(normalize(&["[1.0,1.5]", "(1.5,2.0]"]), "[1.0,2.0]");
+    assert_eq!(
+        normalize(&["[1.0,1.5)", "(1.5,2.0]"]),
+        "[1.0,1.5),(1.5,2.0]"
+    );
+    assert_eq!(normalize(&["[1.0,5.0]", "[2.0,3.0]"]), "[1.0,5.0]");
+    assert_eq!(normalize(&["(1.0,2.0]", "[1.0,1.2]"]), "[1.0,2.0]");
+    assert_eq!(normalize(&["[2.0,)", "(,1.0]", "[0.5,2.5]"]), "(,)");
+    assert_eq!(normalize(&["[1.0,2.0)", "[1.0,2.0]"]), "[1.0,2.0]");
+    assert_eq!(normalize(&[]), "");
+
+    let mut empty = VersionRange::from_restrictions(vec![
+        Restriction::new(
+            Some(ComparableVersion::new("2.0")),
+            true,
+            Some(ComparableVersion::new("1.0")),
+            true,
+        ),
+        Restriction::new(
+            Some(ComparableVersion::new("1.0")),
+            false,
+            Some(ComparableVersion::new("1.0")),
+            true,
+        ),
+    ]);
+    empty.normalize();
+    assert_eq!(empty.restrictions(), &[]);
+
+    let mut a = VersionRange::new("[1.0,1.5),[1.5,2.0]").unwrap();
+    let mut b = VersionRange::new("[1.0,2.0]").unwrap();
+    assert_ne!(a, b);
+    a.normalize();
+    b.normalize();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_try_from_restrictions() {
+    let try_from = |restrictions: &[&str]| {
+        VersionRange::try_from_restrictions(
+            restrictions.iter().map(|r| r.parse().unwrap()).collect(),
+        )
+    };
+
+    for spec in &["[1.0,1.5),(1.5,2.0]", "(,1.0],[1.2,)", "[1.0]", ""] {
+        let range = VersionRange::new(spec).unwrap();
+        let rebuilt = VersionRange::try_from_restrictions(range.restrictions().to_vec()).unwrap();
+        assert_eq!(rebuilt, range, "{}", spec);
+    }
+
+    let err = |restrictions: &[&str]| {
+        let err = try_from(restrictions).unwrap_err();
+        (err.kind(), err.offset())
+    };
+
+    use RangeParseErrorKind::*;
+    assert_eq!(err(&["[1.0,2.0]", "[1.5,3.0]"]), (RangesOverlap, 10));
+    assert_eq!(err(&["[3.0,4.0]", "[1.0,2.0]"]), (RangesOverlap, 10));
+    assert_eq!(err(&["[1.0]", "(2.0,3.0)", "[0.5]"]), (RangesOverlap, 16));
+
+    let empty = Restriction::new(
+        Some(ComparableVersion::new("2.0")),
+        true,
+        Some(ComparableVersion::new("1.0")),
+        true,
+    );
+    let err = VersionRange::try_from_restrictions(vec![empty]).unwrap_err();
+    assert_eq!(err.kind(), BoundsOutOfOrder);
+    assert_eq!(err.offset(), 0);
+}
+
+fn check_restriction(
+    r: &Restriction,
+    lower: Option<&str>,
+    lower_inclusive: bool,
+    upper: Option<&str>,
+    upper_inclusive: bool,
+) {
+    assert_eq!(r.lower_bound().map(|v| v.as_str()), lower, "{}", r);
+    assert_eq!(r.is_lower_bound_inclusive(), lower_inclusive, "{}", r);
+    assert_eq!(r.upper_bound().map(|v| v.as_str()), upper, "{}", r);
+    assert_eq!(r.is_upper_bound_inclusive(), upper_inclusive, "{}", r);
+}
+
+#[test]
+fn test_maven_ranges() {
+    let range = VersionRange::new("(,1.0]").unwrap();
+    assert_eq!(range.restrictions().len(), 1);
+    check_restriction(&range.restrictions()[0], None, false, Some("1.0"), true);
+    assert_eq!(range.recommended_version(), None);
+    assert!(range.has_restrictions());
+
+    let range = VersionRange::new("1.0").unwrap();
+    assert_eq!(range.restrictions().len(), 1);
+    check_restriction(&range.restrictions()[0], None, false, None, false);
+    assert_eq!(range.recommended_version().unwrap().as_str(), "1.0");
+    assert!(!range.has_restrictions());
+
+    let range = VersionRange::new("[1.0]").unwrap();
+    check_restriction(
+        &range.restrictions()[0],
+        Some("1.0"),
+        true,
+        Some("1.0"),
+        true,
+    );
+
+    let range = VersionRange::new("[1.2,1.3]").unwrap();
+    check_restriction(
+        &range.restrictions()[0],
+        Some("1.2"),
+        true,
+        Some("1.3"),
+        true,
+    );
+
+    let range = VersionRange::new("[1.0,2.0)").unwrap();
+    check_restriction(
+        &range.restrictions()[0],
+        Some("1.0"),
+        true,
+        Some("2.0"),
+        false,
+    );
+
+    let range = VersionRange::new("[1.5,)").unwrap();
+    check_restriction(&range.restrictions()[0], Some("1.5"), true, None, false);
+
+    let range = VersionRange::new("(,1.0],[1.2,)").unwrap();
+    assert_eq!(range.restrictions().len(), 2);
+    check_restriction(&range.restrictions()[0], None, false, Some("1.0"), true);
+    check_restriction(&range.restrictions()[1], Some("1.2"), true, None, false);
+
+    let range = VersionRange::new("").unwrap();
+    assert!(range.restrictions().is_empty());
+    assert!(!range.has_restrictions());
+    assert!(!range.contains(&ComparableVersion::new("1.0")));
+
+    use RangeParseErrorKind::*;
+    for (spec, kind) in &[
+        ("(1.0)", SingleVersionNotInclusive),
+        ("[1.0)", SingleVersionNotInclusive),
+        ("(1.0]", SingleVersionNotInclusive),
+        ("(1.0,1.0]", BoundsOutOfOrder),
+        ("[1.0,1.0)", BoundsOutOfOrder),
+        ("(1.0,1.0)", BoundsOutOfOrder),
+        ("[1.1,1.0]", BoundsOutOfOrder),
+        ("[1.0,1.2),1.3", RecommendedVersionWithRanges),
+        ("[1.0,1.2),(1.1,1.3]", RangesOverlap),
+        ("[1.1,1.3),(1.0,1.2]", RangesOverlap),
+        ("(1.1,1.2],[1.0,1.1)", RangesOverlap),
+    ] {
+        assert_eq!(
+            VersionRange::new(spec).unwrap_err().kind(),
+            *kind,
+            "{}",
+            spec
+        );
+    }
+}
+
+#[test]
+fn test_from_version_and_match_version() {
+    let range = VersionRange::from_version(ComparableVersion::new("[1.0]"));
+    assert!(range.is_soft());
+    assert_eq!(range.to_string(), "[1.0]");
+    assert_ne!(range, VersionRange::new("[1.0]").unwrap());
+
+    let versions = vec!["1.0", "1.1", "1.2-SNAPSHOT", "2.0"]
+        .into_iter()
+        .map(ComparableVersion::new)
+        .collect::<Vec<_>>();
+    let matched = |spec: &str| {
+        VersionRange::new(spec)
+            .unwrap()
+            .match_version(&versions)
+            .map(|v| v.as_str())
+    };
+    assert_eq!(matched("[1.0,2.0)"), Some("1.2-SNAPSHOT"));
+    assert_eq!(matched("(,1.1]"), Some("1.1"));
+    assert_eq!(matched("[3.0,)"), None);
+    assert_eq!(matched("1.0"), Some("2.0"));
+}
+
+#[test]
+fn test_matches() {
+    let range = VersionRange::new("[1.0,1.2),(1.5,)").unwrap();
+
+    for (version, expected) in &[
+        ("0.9", false),
+        ("1.0", true),
+        ("1.1-SNAPSHOT", true),
+        ("1.2", false),
+        ("1.5", false),
+        ("1.5.1", true),
+        ("2.0RC1", true),
+    ] {
+        let comparable = ComparableVersion::new(version);
+        assert_eq!(comparable.matches(&range), *expected, "{}", version);
+        assert_eq!(comparable.matches(&range), range.contains(&comparable));
+        assert_eq!(
+            ArtifactVersion::new(version).matches(&range),
+            *expected,
+            "{}",
+            version
+        );
+    }
+
+    // A soft requirement is satisfied by everything
+    let soft = VersionRange::new("1.0").unwrap();
+    assert!(ComparableVersion::new("3.0").matches(&soft));
+}